@@ -0,0 +1,61 @@
+use std::{fmt, sync::Arc, time::SystemTime};
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+/// The source of the current time for the builtin function `now`, as returned by [`Context::now`](crate::Context::now).
+///
+/// The default clock reads the system time, while a fixed or custom clock makes evaluations that use `now` deterministic.
+///
+/// # Example
+///
+/// ```rust
+/// # use evalexpr::*;
+///
+/// let mut context = HashMapContext::<DefaultNumericTypes>::new();
+/// context.set_clock(Clock::fixed(
+///     chrono::DateTime::parse_from_rfc3339("2024-02-28T12:00:00Z").unwrap(),
+/// ));
+/// assert_eq!(eval_with_context("str::from(now() + days(1))", &context),
+///            Ok(Value::from("2024-02-29T12:00:00Z")));
+/// ```
+#[derive(Clone)]
+pub struct Clock {
+    now: Arc<dyn Fn() -> DateTime<FixedOffset> + Send + Sync>,
+}
+
+impl Clock {
+    /// Creates a clock that returns the result of the given function.
+    pub fn new<F>(now: F) -> Self
+    where
+        F: Fn() -> DateTime<FixedOffset> + Send + Sync + 'static,
+    {
+        Self { now: Arc::new(now) }
+    }
+
+    /// Creates a clock that reads the system time and returns it in UTC.
+    pub fn system() -> Self {
+        Self::new(|| DateTime::<Utc>::from(SystemTime::now()).fixed_offset())
+    }
+
+    /// Creates a clock that always returns the given time.
+    pub fn fixed(now: DateTime<FixedOffset>) -> Self {
+        Self::new(move || now)
+    }
+
+    /// Returns the current time of this clock.
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        (self.now)()
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Clock")
+    }
+}
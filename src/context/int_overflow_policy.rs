@@ -0,0 +1,128 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    error::EvalexprResultValue, function::Function, value::Value, Context,
+    ContextWithMutableFunctions, ContextWithMutableVariables, EvalexprError, EvalexprResult,
+    IntOverflowPolicy, RoundingMode,
+};
+
+/// A context that overrides the [`IntOverflowPolicy`] of another context, for example for a single evaluation.
+///
+/// Everything else is forwarded to the wrapped context, which is given as a reference or another pointer type.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(feature = "num_primitive"))] {
+/// # use evalexpr::*;
+///
+/// let context = HashMapContext::<DefaultNumericTypes>::new();
+/// let saturating = IntOverflowPolicyContext::new(&context, IntOverflowPolicy::Saturate);
+/// assert_eq!(eval_with_context("9223372036854775807 * 2", &saturating),
+///            Ok(Value::from_int(i64::MAX)));
+/// assert!(eval_with_context("9223372036854775807 * 2", &context).is_err());
+/// # }
+/// ```
+pub struct IntOverflowPolicyContext<C: Deref>
+where
+    C::Target: Context,
+{
+    context: C,
+    int_overflow_policy: IntOverflowPolicy,
+}
+
+impl<C: Deref> IntOverflowPolicyContext<C>
+where
+    C::Target: Context,
+{
+    /// Wraps the given context, overriding its integer overflow policy with the given one.
+    pub fn new(context: C, int_overflow_policy: IntOverflowPolicy) -> Self {
+        Self {
+            context,
+            int_overflow_policy,
+        }
+    }
+
+    /// Returns the wrapped context.
+    pub fn into_inner(self) -> C {
+        self.context
+    }
+}
+
+impl<C: Deref> Context for IntOverflowPolicyContext<C>
+where
+    C::Target: Context,
+{
+    type NumericTypes = <C::Target as Context>::NumericTypes;
+
+    fn get_value(&self, identifier: &str) -> Option<&Value<Self::NumericTypes>> {
+        self.context.get_value(identifier)
+    }
+
+    fn call_function(
+        &self,
+        identifier: &str,
+        argument: &Value<Self::NumericTypes>,
+    ) -> EvalexprResultValue<Self::NumericTypes> {
+        self.context.call_function(identifier, argument)
+    }
+
+    fn has_function(&self, identifier: &str) -> bool {
+        self.context.has_function(identifier)
+    }
+
+    fn are_builtin_functions_disabled(&self) -> bool {
+        self.context.are_builtin_functions_disabled()
+    }
+
+    /// Builtin functions have to be enabled or disabled in the wrapped context.
+    fn set_builtin_functions_disabled(
+        &mut self,
+        _disabled: bool,
+    ) -> EvalexprResult<(), Self::NumericTypes> {
+        Err(EvalexprError::ContextNotMutable)
+    }
+
+    fn loop_iteration_budget(&self) -> usize {
+        self.context.loop_iteration_budget()
+    }
+
+    fn rounding_mode(&self) -> RoundingMode {
+        self.context.rounding_mode()
+    }
+
+    fn int_overflow_policy(&self) -> IntOverflowPolicy {
+        self.int_overflow_policy
+    }
+
+    #[cfg(feature = "datetime")]
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        self.context.now()
+    }
+}
+
+impl<C: DerefMut> ContextWithMutableVariables for IntOverflowPolicyContext<C>
+where
+    C::Target: ContextWithMutableVariables,
+{
+    fn set_value(
+        &mut self,
+        identifier: String,
+        value: Value<Self::NumericTypes>,
+    ) -> EvalexprResult<(), Self::NumericTypes> {
+        self.context.set_value(identifier, value)
+    }
+}
+
+impl<C: DerefMut> ContextWithMutableFunctions for IntOverflowPolicyContext<C>
+where
+    C::Target: ContextWithMutableFunctions,
+{
+    fn set_function(
+        &mut self,
+        identifier: String,
+        function: Function<Self::NumericTypes>,
+    ) -> EvalexprResult<(), Self::NumericTypes> {
+        self.context.set_function(identifier, function)
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     error::EvalexprResultValue,
     function::Function,
     value::{
-        numeric_types::{DefaultNumericTypes, EvalexprNumericTypes},
+        numeric_types::{DefaultNumericTypes, EvalexprNumericTypes, IntOverflowPolicy, RoundingMode},
         value_type::ValueType,
         Value,
     },
@@ -18,7 +18,16 @@ use crate::{
 };
 
 
+#[cfg(feature = "datetime")]
+mod clock;
+mod int_overflow_policy;
 mod predefined;
+mod scoped;
+
+#[cfg(feature = "datetime")]
+pub use clock::Clock;
+pub use int_overflow_policy::IntOverflowPolicyContext;
+pub(crate) use scoped::ScopedContext;
 
 /// An immutable context.
 pub trait Context {
@@ -36,6 +45,16 @@ pub trait Context {
         argument: &Value<Self::NumericTypes>,
     ) -> EvalexprResultValue<Self::NumericTypes>;
 
+    /// Returns true if a function is linked to the given identifier.
+    ///
+    /// Functions of the context shadow builtin functions.
+    /// Since the builtin function `is_set` receives unset variables as its argument, this is checked before calling it.
+    ///
+    /// Defaults to false, so contexts that link functions should override it.
+    fn has_function(&self, _identifier: &str) -> bool {
+        false
+    }
+
     /// Checks if builtin functions are disabled.
     fn are_builtin_functions_disabled(&self) -> bool;
 
@@ -45,8 +64,42 @@ pub trait Context {
         &mut self,
         disabled: bool,
     ) -> EvalexprResult<(), Self::NumericTypes>;
+
+    /// Returns the maximum amount of loop iterations that a single evaluation with this context may perform in total.
+    /// If the budget is exhausted, the evaluation fails with `EvalexprError::LoopIterationBudgetExceeded`.
+    ///
+    /// Defaults to [`DEFAULT_LOOP_ITERATION_BUDGET`].
+    fn loop_iteration_budget(&self) -> usize {
+        DEFAULT_LOOP_ITERATION_BUDGET
+    }
+
+    /// Returns the rounding mode of divisions whose quotient cannot be represented exactly.
+    /// Only float types that round in software, like the decimals of `DecimalNumericTypes`, respect it.
+    ///
+    /// Defaults to [`RoundingMode::NearestEven`].
+    fn rounding_mode(&self) -> RoundingMode {
+        RoundingMode::default()
+    }
+
+    /// Returns what happens if the result of an integer operation does not fit into the integer type.
+    ///
+    /// Defaults to [`IntOverflowPolicy::Error`].
+    fn int_overflow_policy(&self) -> IntOverflowPolicy {
+        IntOverflowPolicy::default()
+    }
+
+    /// Returns the current time, as returned by the builtin function `now`.
+    ///
+    /// Defaults to the system time in UTC.
+    #[cfg(feature = "datetime")]
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        Clock::system().now()
+    }
 }
 
+/// The default amount of loop iterations that a single evaluation may perform.
+pub const DEFAULT_LOOP_ITERATION_BUDGET: usize = 1_000_000;
+
 /// A context that allows to assign to variables.
 pub trait ContextWithMutableVariables: Context {
     /// Sets the variable with the given identifier to the given value.
@@ -287,6 +340,28 @@ pub struct HashMapContext<NumericTypes: EvalexprNumericTypes = DefaultNumericTyp
 
     /// True if builtin functions are disabled.
     without_builtin_functions: bool,
+
+    /// The maximum amount of loop iterations of a single evaluation.
+    #[cfg_attr(feature = "serde", serde(default = "default_loop_iteration_budget"))]
+    loop_iteration_budget: usize,
+
+    /// The rounding mode of inexact divisions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    rounding_mode: RoundingMode,
+
+    /// The handling of integer overflows.
+    #[cfg_attr(feature = "serde", serde(default))]
+    int_overflow_policy: IntOverflowPolicy,
+
+    /// The source of the current time.
+    #[cfg(feature = "datetime")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clock: Clock,
+}
+
+#[cfg(feature = "serde")]
+fn default_loop_iteration_budget() -> usize {
+    DEFAULT_LOOP_ITERATION_BUDGET
 }
 
 impl<NumericTypes: EvalexprNumericTypes> HashMapContext<NumericTypes> {
@@ -337,6 +412,68 @@ impl<NumericTypes: EvalexprNumericTypes> HashMapContext<NumericTypes> {
         self.clear_variables();
         self.clear_functions();
     }
+
+    /// Sets the maximum amount of loop iterations that a single evaluation with this context may perform in total.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use evalexpr::*;
+    ///
+    /// let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    /// context.set_loop_iteration_budget(10);
+    /// assert_eq!(eval_with_context_mut("while true {}", &mut context),
+    ///            Err(EvalexprError::LoopIterationBudgetExceeded));
+    /// ```
+    pub fn set_loop_iteration_budget(&mut self, budget: usize) {
+        self.loop_iteration_budget = budget;
+    }
+
+    /// Sets the rounding mode of divisions whose quotient cannot be represented exactly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "decimal", not(feature = "num_primitive")))] {
+    /// # use evalexpr::*;
+    ///
+    /// let mut context = HashMapContext::<DecimalNumericTypes>::new();
+    /// context.set_rounding_mode(RoundingMode::TowardZero);
+    /// assert_eq!(eval_with_context("2.0 / 3", &context).unwrap().to_string(),
+    ///            "0.6666666666666666666666666666");
+    /// # }
+    /// ```
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Sets what happens if the result of an integer operation does not fit into the integer type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "num_primitive"))] {
+    /// # use evalexpr::*;
+    ///
+    /// let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    /// context.set_int_overflow_policy(IntOverflowPolicy::Wrap);
+    /// assert_eq!(eval_with_context("9223372036854775807 + 1", &context),
+    ///            Ok(Value::from_int(i64::MIN)));
+    /// context.set_int_overflow_policy(IntOverflowPolicy::PromoteToFloat);
+    /// assert_eq!(eval_with_context("9223372036854775807 + 1", &context),
+    ///            Ok(Value::from_float(9223372036854775808.0)));
+    /// # }
+    /// ```
+    pub fn set_int_overflow_policy(&mut self, int_overflow_policy: IntOverflowPolicy) {
+        self.int_overflow_policy = int_overflow_policy;
+    }
+
+    /// Sets the clock that the builtin function `now` reads the current time from.
+    /// See [`Clock`] for an example.
+    #[cfg(feature = "datetime")]
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
 }
 
 impl<NumericTypes: EvalexprNumericTypes> Context for HashMapContext<NumericTypes> {
@@ -360,6 +497,10 @@ impl<NumericTypes: EvalexprNumericTypes> Context for HashMapContext<NumericTypes
         }
     }
 
+    fn has_function(&self, identifier: &str) -> bool {
+        self.functions.contains_key(identifier)
+    }
+
     fn are_builtin_functions_disabled(&self) -> bool {
         self.without_builtin_functions
     }
@@ -371,6 +512,23 @@ impl<NumericTypes: EvalexprNumericTypes> Context for HashMapContext<NumericTypes
         self.without_builtin_functions = disabled;
         Ok(())
     }
+
+    fn loop_iteration_budget(&self) -> usize {
+        self.loop_iteration_budget
+    }
+
+    fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    fn int_overflow_policy(&self) -> IntOverflowPolicy {
+        self.int_overflow_policy
+    }
+
+    #[cfg(feature = "datetime")]
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        self.clock.now()
+    }
 }
 
 impl<NumericTypes: EvalexprNumericTypes> ContextWithMutableVariables
@@ -471,6 +629,11 @@ impl<NumericTypes: EvalexprNumericTypes> Default for HashMapContext<NumericTypes
             variables: Default::default(),
             functions: Default::default(),
             without_builtin_functions: false,
+            loop_iteration_budget: DEFAULT_LOOP_ITERATION_BUDGET,
+            rounding_mode: RoundingMode::default(),
+            int_overflow_policy: IntOverflowPolicy::default(),
+            #[cfg(feature = "datetime")]
+            clock: Clock::default(),
         }
     }
 }
@@ -495,8 +658,10 @@ macro_rules! context_map {
     ( ($ctx:expr) $k:expr => Function::new($($v:tt)*) ) =>
         { $crate::context_map!(($ctx) $k => Function::new($($v)*),) };
     
-    ( ($ctx:expr) $k:expr => $tt1:tt $tt2:tt $v:expr ) =>
-        { $crate::context_map!(($ctx) $k => $tt1 $tt2 $v,)  };
+    ( ($ctx:expr) $k:expr => as $tt:tt $v:expr ) =>
+        { $crate::context_map!(($ctx) $k => as $tt $v,)  };
+    ( ($ctx:expr) $k:expr => cast $tt:tt $v:expr ) =>
+        { $crate::context_map!(($ctx) $k => cast $tt $v,)  };
     ( ($ctx:expr) $k:expr => $tt:tt $v:expr ) =>
         { $crate::context_map!(($ctx) $k => $tt $v,)  };
 
@@ -536,14 +701,14 @@ macro_rules! context_map {
     // add an integer value, and chain the eventual error with the ones in the next values
     ( ($ctx:expr) $k:expr => cast int $v:expr , $($tt:tt)*) => {{
         $crate::Value::from_cast_int($v).and_then(|value|
-                $crate::ContextWithMutableVariables::set_value($ctx, $k.into(), )
+                $crate::ContextWithMutableVariables::set_value($ctx, $k.into(), value)
                     .and($crate::context_map!(($ctx) $($tt)*))
             )
     }};
     // add a float value, and chain the eventual error with the ones in the next values
     ( ($ctx:expr) $k:expr => cast float $v:expr , $($tt:tt)*) => {{
         $crate::Value::from_cast_float($v).and_then(|value|
-                $crate::ContextWithMutableVariables::set_value($ctx, $k.into(), )
+                $crate::ContextWithMutableVariables::set_value($ctx, $k.into(), value)
                     .and($crate::context_map!(($ctx) $($tt)*))
             )
     }};
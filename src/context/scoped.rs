@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    error::EvalexprResultValue, value::Value, Context, ContextWithMutableVariables, EvalexprError,
+    EvalexprResult, IntOverflowPolicy, RoundingMode,
+};
+
+/// A context that overlays the local variables bound with `let` over the context of an evaluation.
+///
+/// Local variables shadow the variables of the wrapped context without modifying it.
+/// Assigning to a local variable changes the innermost binding, and assigning to any other variable
+/// is forwarded to the wrapped context.
+pub(crate) struct ScopedContext<C: Deref>
+where
+    C::Target: Context,
+{
+    context: C,
+    scopes: Vec<HashMap<String, Value<<C::Target as Context>::NumericTypes>>>,
+}
+
+impl<C: Deref> ScopedContext<C>
+where
+    C::Target: Context,
+{
+    /// Wraps the given context with a single empty scope that lives for the whole evaluation.
+    pub(crate) fn new(context: C) -> Self {
+        Self {
+            context,
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    /// Opens a new innermost scope.
+    pub(crate) fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Closes the innermost scope, dropping all variables bound in it.
+    pub(crate) fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Binds the given value to the given identifier in the innermost scope,
+    /// shadowing any variable with the same identifier.
+    pub(crate) fn bind(
+        &mut self,
+        identifier: String,
+        value: Value<<C::Target as Context>::NumericTypes>,
+    ) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(identifier, value);
+        }
+    }
+}
+
+impl<C: Deref> Context for ScopedContext<C>
+where
+    C::Target: Context,
+{
+    type NumericTypes = <C::Target as Context>::NumericTypes;
+
+    fn get_value(&self, identifier: &str) -> Option<&Value<Self::NumericTypes>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(identifier))
+            .or_else(|| self.context.get_value(identifier))
+    }
+
+    fn call_function(
+        &self,
+        identifier: &str,
+        argument: &Value<Self::NumericTypes>,
+    ) -> EvalexprResultValue<Self::NumericTypes> {
+        self.context.call_function(identifier, argument)
+    }
+
+    fn has_function(&self, identifier: &str) -> bool {
+        self.context.has_function(identifier)
+    }
+
+    fn are_builtin_functions_disabled(&self) -> bool {
+        self.context.are_builtin_functions_disabled()
+    }
+
+    /// Builtin functions can't be enabled or disabled during an evaluation.
+    fn set_builtin_functions_disabled(
+        &mut self,
+        _disabled: bool,
+    ) -> EvalexprResult<(), Self::NumericTypes> {
+        Err(EvalexprError::ContextNotMutable)
+    }
+
+    fn loop_iteration_budget(&self) -> usize {
+        self.context.loop_iteration_budget()
+    }
+
+    fn rounding_mode(&self) -> RoundingMode {
+        self.context.rounding_mode()
+    }
+
+    fn int_overflow_policy(&self) -> IntOverflowPolicy {
+        self.context.int_overflow_policy()
+    }
+
+    #[cfg(feature = "datetime")]
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        self.context.now()
+    }
+}
+
+impl<C: DerefMut> ContextWithMutableVariables for ScopedContext<C>
+where
+    C::Target: ContextWithMutableVariables,
+{
+    fn set_value(
+        &mut self,
+        identifier: String,
+        value: Value<Self::NumericTypes>,
+    ) -> EvalexprResult<(), Self::NumericTypes> {
+        if let Some(local) = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(&identifier))
+        {
+            *local = value;
+            Ok(())
+        } else {
+            self.context.set_value(identifier, value)
+        }
+    }
+}
@@ -60,6 +60,14 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for EvalexprError<NumericT
                 expected_length.end(),
                 actual
             ),
+            ExpectedMinimumLengthTuple {
+                minimum_length,
+                actual,
+            } => write!(
+                f,
+                "Expected a Value::Tuple of at least length {}, but got {:?}.",
+                minimum_length, actual
+            ),
             ExpectedEmpty { actual } => write!(f, "Expected a Value::Empty, but got {:?}.", actual),
             AppendedToLeafNode => write!(f, "Tried to append a node to a leaf node."),
             PrecedenceViolation => write!(
@@ -86,7 +94,30 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for EvalexprError<NumericT
             ),
             UnmatchedLBrace => write!(f, "Found an unmatched opening parenthesis '('."),
             UnmatchedRBrace => write!(f, "Found an unmatched closing parenthesis ')'."),
+            UnmatchedQuestionMark => write!(f, "Found a conditional '?' without a matching ':'."),
+            UnmatchedColon => write!(f, "Found a ':' without a matching conditional '?'."),
+            UnmatchedLCurlyBrace => write!(f, "Found an unmatched opening curly brace '{{'."),
+            UnmatchedRCurlyBrace => write!(f, "Found an unmatched closing curly brace '}}'."),
+            MissingLoopBody => write!(f, "Found a loop without a body in curly braces '{{}}'."),
+            MalformedForLoopHeader => write!(
+                f,
+                "Found a for loop whose header is not of the form 'identifier in expression'."
+            ),
+            BreakOutsideOfLoop => write!(f, "Evaluated 'break' outside of a loop."),
+            ContinueOutsideOfLoop => write!(f, "Evaluated 'continue' outside of a loop."),
+            MalformedLetBinding => write!(
+                f,
+                "Found a let binding that does not assign to a variable or a tuple of variables."
+            ),
+            LoopIterationBudgetExceeded => {
+                write!(
+                    f,
+                    "The loops exceeded the loop iteration budget of the context."
+                )
+            },
             UnmatchedDoubleQuote => write!(f, "Found an unmatched double quote '\"'"),
+            UnmatchedSingleQuote => write!(f, "Found an unmatched single quote \"'\""),
+            UnmatchedBacktick => write!(f, "Found an unmatched backtick '`'"),
             MissingOperatorOutsideOfBrace { .. } => write!(
                 f,
                 "Found an opening parenthesis that is preceded by something that does not take \
@@ -125,11 +156,43 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for EvalexprError<NumericT
             ModulationError { dividend, divisor } => {
                 write!(f, "Error modulating {} % {}", dividend, divisor)
             },
+            ComparisonError { a, b } => write!(f, "Error comparing {} and {}", a, b),
+            ShiftError { value, shift } => {
+                write!(f, "Error shifting {} by {} bits", value, shift)
+            },
             InvalidRegex { regex, message } => write!(
                 f,
                 "Regular expression {:?} is invalid: {:?}",
                 regex, message
             ),
+            ArgumentOutOfRange {
+                actual,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "Expected an argument between {} and {}, but got {}.",
+                minimum, maximum, actual
+            ),
+            MathFunctionError { function, argument } => write!(
+                f,
+                "The result of {} of {} is undefined or cannot be represented",
+                function, argument
+            ),
+            #[cfg(feature = "units")]
+            IncompatibleUnits { a, b } => write!(
+                f,
+                "The units {} and {} are incompatible, because they measure different dimensions",
+                a, b
+            ),
+            #[cfg(feature = "units")]
+            UnknownUnit(string) => write!(f, "Unknown unit: {}", string),
+            #[cfg(feature = "interval")]
+            InvertedInterval { lo, hi } => write!(
+                f,
+                "The lower bound {} of an interval is larger than its upper bound {}",
+                lo, hi
+            ),
             ContextNotMutable => write!(f, "Cannot manipulate context"),
             BuiltinFunctionsCannotBeEnabled => {
                 write!(f, "This context does not allow enabling builtin functions")
@@ -138,6 +201,17 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for EvalexprError<NumericT
                 write!(f, "This context does not allow disabling builtin functions")
             },
             IllegalEscapeSequence(string) => write!(f, "Illegal escape sequence: {}", string),
+            IllegalFormatSpecification(string) => {
+                write!(f, "Illegal format specification: {}", string)
+            },
+            IllegalIdentifier(string) => write!(
+                f,
+                "Illegal identifier: {} (quote identifiers with backticks to use arbitrary names)",
+                string
+            ),
+            LiteralOutOfRange(string) => {
+                write!(f, "The number literal {} is out of range", string)
+            },
             OutOfBoundsAccess => write!(f, "Tried to access a tuple or string at an invalid index"),
             IntFromUsize { usize_int } => write!(
                 f,
@@ -153,6 +227,7 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for EvalexprError<NumericT
             CustomMessage(message) => write!(f, "Error: {}", message),
             FloatToNum{float, target_type} => write!(f, "Cannot convert {float} to {target_type}"),
             IntToNum {int, target_type} => write!(f, "Cannot convert {int} to {target_type}"),
+            StringParse {string, target_type} => write!(f, "Cannot parse {string:?} as {target_type}"),
             Wrapped {wrapped} => write!(f, "Encountered an error: {wrapped}"),
             IntCastError => write!(f, "Failed to cast an int to Value."),
             FloatCastError => write!(f, "Failed to cast a float to Value."),
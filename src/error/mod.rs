@@ -98,6 +98,14 @@ pub enum EvalexprError<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
         actual: Value<NumericTypes>,
     },
 
+    /// A tuple value of at least a certain length was expected.
+    ExpectedMinimumLengthTuple {
+        /// The minimum expected length.
+        minimum_length: usize,
+        /// The actual value.
+        actual: Value<NumericTypes>,
+    },
+
     /// An empty value was expected.
     ExpectedEmpty {
         /// The actual value.
@@ -142,9 +150,45 @@ pub enum EvalexprError<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
     /// A closing brace without a matching opening brace was found.
     UnmatchedRBrace,
 
+    /// A question mark of a conditional without a matching colon was found.
+    UnmatchedQuestionMark,
+
+    /// A colon without a matching question mark of a conditional was found.
+    UnmatchedColon,
+
+    /// An opening curly brace without a matching closing curly brace was found.
+    UnmatchedLCurlyBrace,
+
+    /// A closing curly brace without a matching opening curly brace was found.
+    UnmatchedRCurlyBrace,
+
+    /// A `while` or `for` loop without a body in curly braces was found.
+    MissingLoopBody,
+
+    /// A `for` loop whose header is not of the form `identifier in expression` was found.
+    MalformedForLoopHeader,
+
+    /// A `break` was evaluated outside of a loop.
+    BreakOutsideOfLoop,
+
+    /// A `continue` was evaluated outside of a loop.
+    ContinueOutsideOfLoop,
+
+    /// A `let` binding that does not assign to a variable or a tuple of variables was found.
+    MalformedLetBinding,
+
+    /// The loops of an evaluation performed more iterations than the loop iteration budget of the context allows.
+    LoopIterationBudgetExceeded,
+
     /// A double quote without a matching second double quote was found.
     UnmatchedDoubleQuote,
 
+    /// A single quote without a matching second single quote was found.
+    UnmatchedSingleQuote,
+
+    /// A backtick without a matching second backtick was found.
+    UnmatchedBacktick,
+
     /// Left of an opening brace or right of a closing brace is a token that does not expect the brace next to it.
     /// For example, writing `4(5)` would yield this error, as the `4` does not have any operands.
     MissingOperatorOutsideOfBrace,
@@ -205,6 +249,22 @@ pub enum EvalexprError<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
         divisor: Value<NumericTypes>,
     },
 
+    /// An ordering comparison did not succeed, because the values cannot be ordered.
+    ComparisonError {
+        /// The first argument of the comparison.
+        a: Value<NumericTypes>,
+        /// The second argument of the comparison.
+        b: Value<NumericTypes>,
+    },
+
+    /// A shift operation did not succeed, because the shift amount was negative or too large.
+    ShiftError {
+        /// The value that was shifted.
+        value: Value<NumericTypes>,
+        /// The amount of bits that the value was shifted by.
+        shift: Value<NumericTypes>,
+    },
+
     /// A regular expression could not be parsed
     InvalidRegex {
         /// The invalid regular expression
@@ -213,12 +273,64 @@ pub enum EvalexprError<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
         message: String,
     },
 
+    /// A numeric argument lies outside of the range accepted by a function.
+    ArgumentOutOfRange {
+        /// The actual argument.
+        actual: Value<NumericTypes>,
+        /// The smallest accepted value.
+        minimum: Value<NumericTypes>,
+        /// The largest accepted value.
+        maximum: Value<NumericTypes>,
+    },
+
+    /// The result of a math function is undefined or cannot be represented by the float type,
+    /// like the square root of a negative number if the float type has no NaN.
+    MathFunctionError {
+        /// The name of the math function, like `math::sqrt`.
+        function: String,
+        /// The argument of the math function, which is a tuple if the function takes multiple arguments.
+        argument: Value<NumericTypes>,
+    },
+
+    /// Quantities cannot be added, subtracted, compared or converted into each other, because their units measure different dimensions.
+    /// A plain number has the unit `1`, which has no dimension.
+    #[cfg(feature = "units")]
+    IncompatibleUnits {
+        /// The unit of the first quantity.
+        a: crate::value::units::Unit,
+        /// The unit of the second quantity, or the unit that the first quantity was converted to.
+        b: crate::value::units::Unit,
+    },
+
+    /// A unit of measure is malformed or contains an unknown symbol.
+    #[cfg(feature = "units")]
+    UnknownUnit(String),
+
+    /// The lower bound of an interval is larger than its upper bound.
+    #[cfg(feature = "interval")]
+    InvertedInterval {
+        /// The lower bound.
+        lo: Value<NumericTypes>,
+        /// The upper bound.
+        hi: Value<NumericTypes>,
+    },
+
     /// A modification was attempted on a `Context` that does not allow modifications.
     ContextNotMutable,
 
     /// An escape sequence within a string literal is illegal.
     IllegalEscapeSequence(String),
 
+    /// A format specification within a format string literal is illegal.
+    IllegalFormatSpecification(String),
+
+    /// A literal is neither a value nor a valid identifier.
+    /// Identifiers that do not follow the Unicode rules for identifiers need to be quoted with backticks.
+    IllegalIdentifier(String),
+
+    /// A number literal is too large or too small to be represented by the numeric types.
+    LiteralOutOfRange(String),
+
     /// This context does not allow enabling builtin functions.
     BuiltinFunctionsCannotBeEnabled,
 
@@ -267,6 +379,14 @@ pub enum EvalexprError<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
         /// The `target_type` of the conversion.
         target_type: &'static str
     },
+
+    /// Failed to parse a `string` as `target_type`
+    StringParse {
+        /// The `string` that was attempted to be parsed.
+        string: String,
+        /// The `target_type` of the parsing.
+        target_type: &'static str
+    },
     /// An error that is dynamically wrapped due to some reasons
     Wrapped {
         /// The wrapped error
@@ -400,6 +520,17 @@ impl<NumericTypes: EvalexprNumericTypes> EvalexprError<NumericTypes> {
         }
     }
 
+    /// Constructs `EvalexprError::ExpectedMinimumLengthTuple{minimum_length, actual}`.
+    pub fn expected_minimum_len_tuple(
+        minimum_length: usize,
+        actual: Value<NumericTypes>,
+    ) -> Self {
+        EvalexprError::ExpectedMinimumLengthTuple {
+            minimum_length,
+            actual,
+        }
+    }
+
     /// Constructs `EvalexprError::ExpectedEmpty{actual}`.
     pub fn expected_empty(actual: Value<NumericTypes>) -> Self {
         EvalexprError::ExpectedEmpty { actual }
@@ -417,16 +548,15 @@ impl<NumericTypes: EvalexprNumericTypes> EvalexprError<NumericTypes> {
             ValueType::Boolean => Self::expected_boolean(actual),
             ValueType::Tuple => Self::expected_tuple(actual),
             ValueType::Empty => Self::expected_empty(actual),
+            #[cfg(feature = "datetime")]
+            ValueType::DateTime | ValueType::Duration => {
+                Self::type_error(actual, vec![ValueType::from(expected)])
+            },
+            #[cfg(feature = "units")]
+            ValueType::Quantity => Self::type_error(actual, vec![ValueType::Quantity]),
         }
     }
 
-    pub(crate) fn unmatched_partial_token(
-        first: PartialToken<NumericTypes>,
-        second: Option<PartialToken<NumericTypes>>,
-    ) -> Self {
-        EvalexprError::UnmatchedPartialToken { first, second }
-    }
-
     pub(crate) fn addition_error(augend: Value<NumericTypes>, addend: Value<NumericTypes>) -> Self {
         EvalexprError::AdditionError { augend, addend }
     }
@@ -469,6 +599,36 @@ impl<NumericTypes: EvalexprNumericTypes> EvalexprError<NumericTypes> {
         EvalexprError::ModulationError { dividend, divisor }
     }
 
+    /// Constructs `EvalexprError::ComparisonError{a, b}`.
+    pub fn comparison_error(a: Value<NumericTypes>, b: Value<NumericTypes>) -> Self {
+        EvalexprError::ComparisonError { a, b }
+    }
+
+    pub(crate) fn shift_error(value: Value<NumericTypes>, shift: Value<NumericTypes>) -> Self {
+        EvalexprError::ShiftError { value, shift }
+    }
+
+    /// Constructs `EvalexprError::MathFunctionError{function, argument}`.
+    pub fn math_function_error(function: &str, argument: Value<NumericTypes>) -> Self {
+        EvalexprError::MathFunctionError {
+            function: function.to_string(),
+            argument,
+        }
+    }
+
+    /// Constructs `EvalexprError::ArgumentOutOfRange{actual, minimum, maximum}`.
+    pub fn argument_out_of_range(
+        actual: Value<NumericTypes>,
+        minimum: Value<NumericTypes>,
+        maximum: Value<NumericTypes>,
+    ) -> Self {
+        EvalexprError::ArgumentOutOfRange {
+            actual,
+            minimum,
+            maximum,
+        }
+    }
+
     /// Constructs `EvalexprError::InvalidRegex(regex)`
     pub fn invalid_regex(regex: String, message: String) -> Self {
         EvalexprError::InvalidRegex { regex, message }
@@ -1,12 +1,14 @@
 use cfg_if::cfg_if;
+use std::{any::type_name, cmp::Ordering};
 
 #[cfg(feature = "regex")]
 use regex::Regex;
 
 
 use crate::{
-    value::numeric_types::{EvalexprFloat, EvalexprInt, EvalexprNumericTypes},
-    EvalexprError, Function, Value, ValueType,
+    error::EvalexprResultValue,
+    value::numeric_types::{EvalexprFloat, EvalexprInt, EvalexprNumericTypes, RoundingMode},
+    Context, EvalexprError, EvalexprResult, Function, TupleType, Value, ValueType,
 };
 
 cfg_if! {
@@ -30,6 +32,13 @@ cfg_if! {
             };
         }
 
+        // The math functions of primitive floats result in NaN or infinity instead of failing.
+        macro_rules! checked_math {
+            ($func:ident, $checked_func:ident $(, $arity:tt)?) => {
+                simple_math!($func $(, $arity)?)
+            };
+        }
+
         macro_rules! int_function {
             ($func:ident) => {
                 Some(Function::new(|argument| {
@@ -47,6 +56,32 @@ cfg_if! {
             };
         }
 
+        macro_rules! checked_int_operation {
+            ($name:ident, $func:ident, $error:ident) => {
+                fn $name<NumericTypes: EvalexprNumericTypes>(
+                    a: &NumericTypes::Int,
+                    b: &NumericTypes::Int,
+                ) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+                    a.$func(b).ok_or_else(|| {
+                        EvalexprError::$error(Value::Int(*a), Value::Int(*b))
+                    })
+                }
+            };
+        }
+
+        checked_int_operation!(checked_int_add, checked_add, addition_error);
+        checked_int_operation!(checked_int_sub, checked_sub, subtraction_error);
+        checked_int_operation!(checked_int_mul, checked_mul, multiplication_error);
+        checked_int_operation!(checked_int_div, checked_div, division_error);
+
+        fn owned_int<NumericTypes: EvalexprNumericTypes>(int: &NumericTypes::Int) -> NumericTypes::Int {
+            *int
+        }
+
+        fn owned_float<NumericTypes: EvalexprNumericTypes>(float: &NumericTypes::Float) -> NumericTypes::Float {
+            *float
+        }
+
         fn float_is<NumericTypes: EvalexprNumericTypes>(
             func: fn(NumericTypes::Float) -> bool,
         ) -> Option<Function<NumericTypes>> {
@@ -71,6 +106,22 @@ cfg_if! {
             };
         }
 
+        macro_rules! checked_math {
+            ($func:ident, $checked_func:ident) => {
+                Some(Function::new(|argument: &Value<NumericTypes>| {
+                    let num = argument.as_number()?;
+                    Ok(Value::Float(num.$checked_func()?))
+                }))
+            };
+            ($func:ident, $checked_func:ident, 2) => {
+                Some(Function::new(|argument: &Value<NumericTypes>| {
+                    let tuple = argument.as_fixed_len_tuple(2)?;
+                    let (a, b) = (tuple[0].as_number()?, tuple[1].as_number()?);
+                    Ok(Value::Float(a.$checked_func(&b)?))
+                }))
+            };
+        }
+
         macro_rules! int_function {
             ($func:ident) => {
                 Some(Function::new(|argument| {
@@ -88,6 +139,30 @@ cfg_if! {
             };
         }
 
+        macro_rules! checked_int_operation {
+            ($name:ident, $func:ident, $error:ident) => {
+                fn $name<NumericTypes: EvalexprNumericTypes>(
+                    a: &NumericTypes::Int,
+                    b: &NumericTypes::Int,
+                ) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+                    a.$func(b)
+                }
+            };
+        }
+
+        checked_int_operation!(checked_int_add, checked_add, addition_error);
+        checked_int_operation!(checked_int_sub, checked_sub, subtraction_error);
+        checked_int_operation!(checked_int_mul, checked_mul, multiplication_error);
+        checked_int_operation!(checked_int_div, checked_div, division_error);
+
+        fn owned_int<NumericTypes: EvalexprNumericTypes>(int: &NumericTypes::Int) -> NumericTypes::Int {
+            int.clone()
+        }
+
+        fn owned_float<NumericTypes: EvalexprNumericTypes>(float: &NumericTypes::Float) -> NumericTypes::Float {
+            float.clone()
+        }
+
         fn float_is<NumericTypes: EvalexprNumericTypes>(
             func: fn(&NumericTypes::Float) -> bool,
         ) -> Option<Function<NumericTypes>> {
@@ -98,51 +173,662 @@ cfg_if! {
     }
 }
 
+/// Returns the numeric values a statistics function operates on.
+///
+/// A tuple is taken element-wise, a single number is treated as a tuple of length one and `Value::Empty` as the empty tuple.
+/// Fails if there are fewer than `minimum_length` values or if any of them is not a number.
+fn stat_values<NumericTypes: EvalexprNumericTypes>(
+    argument: &Value<NumericTypes>,
+    minimum_length: usize,
+) -> EvalexprResult<TupleType<NumericTypes>, NumericTypes> {
+    let values = match argument {
+        Value::Tuple(tuple) => tuple.clone(),
+        Value::Empty => TupleType::new(),
+        value => vec![value.clone()],
+    };
+
+    if values.len() < minimum_length {
+        return Err(EvalexprError::expected_minimum_len_tuple(
+            minimum_length,
+            argument.clone(),
+        ));
+    }
+    for value in &values {
+        if !value.is_number() {
+            return Err(EvalexprError::expected_number(value.clone()));
+        }
+    }
+
+    Ok(values)
+}
+
+/// Converts the values returned by [`stat_values`] into floats, sorted in ascending order.
+fn sorted_floats<NumericTypes: EvalexprNumericTypes>(
+    values: &[Value<NumericTypes>],
+) -> EvalexprResult<Vec<NumericTypes::Float>, NumericTypes> {
+    let mut floats = values
+        .iter()
+        .map(Value::as_number)
+        .collect::<EvalexprResult<Vec<_>, _>>()?;
+    floats.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    Ok(floats)
+}
+
+/// Converts a count into the float type.
+fn usize_as_float<NumericTypes: EvalexprNumericTypes>(
+    count: usize,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    Ok(NumericTypes::int_as_float(&NumericTypes::Int::from_usize(
+        count,
+    )?))
+}
+
+/// Compares two numbers, comparing integers exactly and everything else as floats.
+fn compare_numbers<NumericTypes: EvalexprNumericTypes>(
+    a: &Value<NumericTypes>,
+    b: &Value<NumericTypes>,
+) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+    if let (Value::Int(a), Value::Int(b)) = (a, b) {
+        Ok(Some(a.cmp(b)))
+    } else {
+        Ok(a.as_number()?.partial_cmp(&b.as_number()?))
+    }
+}
+
+/// Computes the arithmetic mean of the given numbers.
+fn stat_mean<NumericTypes: EvalexprNumericTypes>(
+    values: &[Value<NumericTypes>],
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    let mut sum = usize_as_float::<NumericTypes>(0)?;
+    for value in values {
+        sum = sum.checked_add(&value.as_number()?)?;
+    }
+    sum.checked_div(&usize_as_float(values.len())?, RoundingMode::default())
+}
+
+/// Computes the variance of the given numbers.
+///
+/// The sum of squared deviations is divided by `values.len() - degrees_of_freedom`,
+/// so `0` yields the population variance and `1` the sample variance.
+fn stat_variance<NumericTypes: EvalexprNumericTypes>(
+    values: &[Value<NumericTypes>],
+    degrees_of_freedom: usize,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    let mean = stat_mean(values)?;
+    let mut sum = usize_as_float::<NumericTypes>(0)?;
+    for value in values {
+        let deviation = value.as_number()?.checked_sub(&mean)?;
+        sum = sum.checked_add(&deviation.checked_mul(&deviation)?)?;
+    }
+    sum.checked_div(
+        &usize_as_float(values.len() - degrees_of_freedom)?,
+        RoundingMode::default(),
+    )
+}
+
+/// Computes the `q`-quantile of the given sorted numbers by linear interpolation between the closest ranks.
+fn quantile_of_sorted<NumericTypes: EvalexprNumericTypes>(
+    values: &[NumericTypes::Float],
+    q: NumericTypes::Float,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    let rank = q * usize_as_float(values.len() - 1)?;
+    let lower_rank = rank.floor();
+    let lower = NumericTypes::float_as_int(&lower_rank).into_usize()?;
+    let upper = (lower + 1).min(values.len() - 1);
+    let fraction = rank - lower_rank;
+    let lower_value = &values[lower];
+    lower_value.checked_add(&values[upper].checked_sub(lower_value)?.checked_mul(&fraction)?)
+}
+
+/// Evaluates a call of the form `(values, q)`, where `q` lies between `0` and `scale`, to the respective quantile of `values`.
+fn stat_quantile<NumericTypes: EvalexprNumericTypes>(
+    argument: &Value<NumericTypes>,
+    scale: usize,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    let arguments = argument.as_fixed_len_tuple(2)?;
+    let values = sorted_floats(&stat_values(&arguments[0], 1)?)?;
+    let q = arguments[1].as_number()?;
+    let max = usize_as_float::<NumericTypes>(scale)?;
+    if q < usize_as_float(0)? || q > max || q.is_nan() {
+        return Err(EvalexprError::argument_out_of_range(
+            arguments[1].clone(),
+            Value::Int(NumericTypes::Int::from_usize(0)?),
+            Value::Int(NumericTypes::Int::from_usize(scale)?),
+        ));
+    }
+
+    quantile_of_sorted(&values, q / max)
+}
+
+/// Returns the index of the first value that is ordered as `target` relative to all other values.
+fn stat_extremum_index<NumericTypes: EvalexprNumericTypes>(
+    argument: &Value<NumericTypes>,
+    target: Ordering,
+) -> EvalexprResult<Value<NumericTypes>, NumericTypes> {
+    let values = stat_values(argument, 1)?;
+    let mut index = 0;
+    for (i, value) in values.iter().enumerate().skip(1) {
+        if compare_numbers(value, &values[index])? == Some(target) {
+            index = i;
+        }
+    }
+    Ok(Value::Int(NumericTypes::Int::from_usize(index)?))
+}
+
+/// Returns the integer with the given `usize` value.
+fn int_from_usize<NumericTypes: EvalexprNumericTypes>(
+    int: usize,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    NumericTypes::Int::from_usize(int)
+}
 
+/// Returns an error if `int` is negative.
+fn expect_non_negative_int<NumericTypes: EvalexprNumericTypes>(
+    int: &NumericTypes::Int,
+) -> EvalexprResult<(), NumericTypes> {
+    if *int < int_from_usize(0)? {
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(owned_int::<NumericTypes>(int)),
+            Value::Int(int_from_usize(0)?),
+            Value::Int(NumericTypes::Int::max_value()),
+        ))
+    } else {
+        Ok(())
+    }
+}
 
+/// Computes the non-negative greatest common divisor of `a` and `b`.
+fn int_gcd<NumericTypes: EvalexprNumericTypes>(
+    a: &NumericTypes::Int,
+    b: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    let zero = int_from_usize(0)?;
+    let (mut a, mut b) = (owned_int::<NumericTypes>(a), owned_int::<NumericTypes>(b));
+    while b != zero {
+        let remainder = a.checked_rem_euclid(&b)?;
+        a = b;
+        b = remainder;
+    }
+    if a < zero {
+        checked_int_sub(&zero, &a)
+    } else {
+        Ok(a)
+    }
+}
 
+/// Computes the non-negative least common multiple of `a` and `b`.
+fn int_lcm<NumericTypes: EvalexprNumericTypes>(
+    a: &NumericTypes::Int,
+    b: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    let zero = int_from_usize(0)?;
+    if *a == zero || *b == zero {
+        return Ok(zero);
+    }
+    let lcm = checked_int_mul(&checked_int_div(a, &int_gcd(a, b)?)?, b)?;
+    if lcm < zero {
+        checked_int_sub(&zero, &lcm)
+    } else {
+        Ok(lcm)
+    }
+}
 
+/// Folds the integers in a tuple of at least two integers with `operation`.
+fn fold_ints<NumericTypes: EvalexprNumericTypes>(
+    argument: &Value<NumericTypes>,
+    operation: impl Fn(&NumericTypes::Int, &NumericTypes::Int) -> EvalexprResult<NumericTypes::Int, NumericTypes>,
+) -> EvalexprResult<Value<NumericTypes>, NumericTypes> {
+    let arguments = match argument {
+        Value::Tuple(tuple) if tuple.len() >= 2 => tuple,
+        _ => {
+            return Err(EvalexprError::expected_minimum_len_tuple(
+                2,
+                argument.clone(),
+            ))
+        },
+    };
+    let mut result = arguments[0].as_int()?;
+    for argument in &arguments[1..] {
+        result = operation(&result, &argument.as_int()?)?;
+    }
+    Ok(Value::Int(result))
+}
 
+/// Computes `n!`.
+pub(crate) fn int_factorial<NumericTypes: EvalexprNumericTypes>(
+    n: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    expect_non_negative_int(n)?;
+    let one = int_from_usize(1)?;
+    let mut result = owned_int::<NumericTypes>(&one);
+    let mut i = owned_int::<NumericTypes>(&one);
+    while i < *n {
+        i = checked_int_add(&i, &one)?;
+        result = checked_int_mul(&result, &i)?;
+    }
+    Ok(result)
+}
+
+/// Computes the binomial coefficient `n` choose `k`, which is zero if `k` is negative or larger than `n`.
+pub(crate) fn int_binomial<NumericTypes: EvalexprNumericTypes>(
+    n: &NumericTypes::Int,
+    k: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    expect_non_negative_int(n)?;
+    let zero = int_from_usize(0)?;
+    let one = int_from_usize(1)?;
+    if *k < zero || k > n {
+        return Ok(zero);
+    }
+
+    let complement = checked_int_sub(n, k)?;
+    let k = if complement < *k { complement } else { owned_int::<NumericTypes>(k) };
+    let offset = checked_int_sub(n, &k)?;
+    let mut result = owned_int::<NumericTypes>(&one);
+    let mut i = zero;
+    while i < k {
+        i = checked_int_add(&i, &one)?;
+        let numerator = checked_int_add(&offset, &i)?;
+        // `result * (offset + i)` is divisible by `i`.
+        if let Ok(product) = checked_int_mul::<NumericTypes>(&result, &numerator) {
+            result = checked_int_div(&product, &i)?;
+            continue;
+        }
+        // If the product overflows, dividing out the common factor of `result` and `i` first
+        // leaves a divisor that divides `offset + i` and avoids overflowing in the intermediate product.
+        let divisor = int_gcd(&result, &i)?;
+        let factor = checked_int_div(&numerator, &checked_int_div(&i, &divisor)?)?;
+        result = checked_int_mul(&checked_int_div(&result, &divisor)?, &factor)?;
+    }
+    Ok(result)
+}
+
+/// Returns true if `n` is a prime number.
+///
+/// Numbers that fit into a `u64` are tested deterministically with [`u64_is_prime`], and larger numbers with [`int_is_probable_prime`].
+fn int_is_prime<NumericTypes: EvalexprNumericTypes>(
+    n: &NumericTypes::Int,
+) -> EvalexprResult<bool, NumericTypes> {
+    if *n < int_from_usize(2)? {
+        return Ok(false);
+    }
+    match n.to_string().parse() {
+        Ok(n) => Ok(u64_is_prime(n)),
+        Err(_) => int_is_probable_prime::<NumericTypes>(n),
+    }
+}
+
+/// Returns true if `n`, which must be larger than 71, is a strong probable prime to the first twenty primes as bases.
+///
+/// This Miller-Rabin test is deterministic for all numbers below 3.3 * 10^24, as it includes the first thirteen primes as bases.
+/// Above, composite numbers constructed to pass the test for all twenty bases are considered prime.
+fn int_is_probable_prime<NumericTypes: EvalexprNumericTypes>(
+    n: &NumericTypes::Int,
+) -> EvalexprResult<bool, NumericTypes> {
+    const BASES: [usize; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    ];
+
+    let zero = int_from_usize(0)?;
+    let one = int_from_usize(1)?;
+    let two = int_from_usize(2)?;
+    for base in BASES {
+        if n.checked_rem_euclid(&int_from_usize(base)?)? == zero {
+            return Ok(false);
+        }
+    }
+
+    // Write `n - 1` as `odd * 2^twos`.
+    let n_minus_one = checked_int_sub(n, &one)?;
+    let mut odd = owned_int::<NumericTypes>(&n_minus_one);
+    let mut twos = 0;
+    while odd.checked_rem_euclid(&two)? == zero {
+        odd = checked_int_div(&odd, &two)?;
+        twos += 1;
+    }
+    'bases: for base in BASES {
+        let mut x = int_pow_mod::<NumericTypes>(&int_from_usize(base)?, &odd, n)?;
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..twos {
+            x = int_mul_mod::<NumericTypes>(&x, &x, n)?;
+            if x == n_minus_one {
+                continue 'bases;
+            }
+        }
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Computes `(a + b) % n` for `a` and `b` between zero and `n` without overflowing.
+fn int_add_mod<NumericTypes: EvalexprNumericTypes>(
+    a: &NumericTypes::Int,
+    b: &NumericTypes::Int,
+    n: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    let complement = checked_int_sub(n, b)?;
+    if *a >= complement {
+        checked_int_sub(a, &complement)
+    } else {
+        checked_int_add(a, b)
+    }
+}
+
+/// Computes `(a * b) % n` for `a` and `b` between zero and `n`,
+/// doubling and adding if the product does not fit into the integer type.
+fn int_mul_mod<NumericTypes: EvalexprNumericTypes>(
+    a: &NumericTypes::Int,
+    b: &NumericTypes::Int,
+    n: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    if let Ok(product) = checked_int_mul::<NumericTypes>(a, b) {
+        return product.checked_rem_euclid(n);
+    }
+
+    let zero = int_from_usize(0)?;
+    let two = int_from_usize(2)?;
+    let mut result = owned_int::<NumericTypes>(&zero);
+    let (mut a, mut b) = (owned_int::<NumericTypes>(a), owned_int::<NumericTypes>(b));
+    while b > zero {
+        if b.checked_rem_euclid(&two)? != zero {
+            result = int_add_mod::<NumericTypes>(&result, &a, n)?;
+        }
+        a = int_add_mod::<NumericTypes>(&a, &a, n)?;
+        b = checked_int_div(&b, &two)?;
+    }
+    Ok(result)
+}
+
+/// Computes `base^exponent % n` for `base` between zero and `n` by repeated squaring.
+fn int_pow_mod<NumericTypes: EvalexprNumericTypes>(
+    base: &NumericTypes::Int,
+    exponent: &NumericTypes::Int,
+    n: &NumericTypes::Int,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    let zero = int_from_usize(0)?;
+    let two = int_from_usize(2)?;
+    let mut result = int_from_usize(1)?;
+    let mut base = owned_int::<NumericTypes>(base);
+    let mut exponent = owned_int::<NumericTypes>(exponent);
+    while exponent > zero {
+        if exponent.checked_rem_euclid(&two)? != zero {
+            result = int_mul_mod::<NumericTypes>(&result, &base, n)?;
+        }
+        base = int_mul_mod::<NumericTypes>(&base, &base, n)?;
+        exponent = checked_int_div(&exponent, &two)?;
+    }
+    Ok(result)
+}
+
+/// Returns true if `n` is a prime number.
+///
+/// Uses the Miller-Rabin test with the first twelve primes as bases, which is deterministic for all 64-bit integers.
+fn u64_is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for base in BASES {
+        if n % base == 0 {
+            return n == base;
+        }
+    }
+
+    let mul_mod = |a: u64, b: u64| (a as u128 * b as u128 % n as u128) as u64;
+    let pow_mod = |mut base: u64, mut exponent: u64| {
+        let mut result = 1;
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = mul_mod(result, base);
+            }
+            base = mul_mod(base, base);
+            exponent /= 2;
+        }
+        result
+    };
+
+    // Write `n - 1` as `odd * 2^twos`.
+    let twos = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> twos;
+    'bases: for base in BASES {
+        let mut x = pow_mod(base, odd);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..twos {
+            x = mul_mod(x, x);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Computes `base^exponent` by repeated squaring.
+fn float_powi<NumericTypes: EvalexprNumericTypes>(
+    base: NumericTypes::Float,
+    mut exponent: usize,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    let mut base = base;
+    let mut result = usize_as_float::<NumericTypes>(1)?;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.checked_mul(&base)?;
+        }
+        base = base.checked_mul(&base)?;
+        exponent /= 2;
+    }
+    Ok(result)
+}
+
+/// Converts a float into an integer by truncating towards zero.
+///
+/// Fails if the float is NaN or if the truncated value does not fit into the integer type.
+fn float_into_int<NumericTypes: EvalexprNumericTypes>(
+    float: &NumericTypes::Float,
+) -> EvalexprResult<NumericTypes::Int, NumericTypes> {
+    let truncated = float.trunc();
+    let min = NumericTypes::int_as_float(&NumericTypes::Int::min_value());
+    let max = NumericTypes::int_as_float(&NumericTypes::Int::max_value());
+    // If the maximum integer is not exactly representable, it was rounded up to a float that is out of range.
+    let max_is_exact = max.checked_sub(&usize_as_float(1)?)? != max;
+
+    if truncated.is_nan() || truncated < min || truncated > max || (truncated == max && !max_is_exact) {
+        Err(EvalexprError::FloatToNum {
+            float: owned_float::<NumericTypes>(float),
+            target_type: type_name::<NumericTypes::Int>(),
+        })
+    } else {
+        Ok(NumericTypes::float_as_int(&truncated))
+    }
+}
+
+/// Parses a string as a value of type `T` via its [`FromStr`](std::str::FromStr) implementation.
+fn parse_str<T: std::str::FromStr, NumericTypes: EvalexprNumericTypes>(
+    string: &str,
+) -> EvalexprResult<T, NumericTypes> {
+    string.parse().map_err(|_| EvalexprError::StringParse {
+        string: string.to_string(),
+        target_type: type_name::<T>(),
+    })
+}
+
+/// Returns true if `tuple` contains `value`, which must be a string, a number or a boolean.
+pub(crate) fn tuple_contains<NumericTypes: EvalexprNumericTypes>(
+    tuple: &[Value<NumericTypes>],
+    value: &Value<NumericTypes>,
+) -> EvalexprResult<bool, NumericTypes> {
+    if let Value::String(_) | Value::Int(_) | Value::Float(_) | Value::Boolean(_) = value {
+        Ok(tuple.contains(value))
+    } else {
+        Err(EvalexprError::type_error(
+            value.clone(),
+            vec![
+                ValueType::String,
+                ValueType::Int,
+                ValueType::Float,
+                ValueType::Boolean,
+            ],
+        ))
+    }
+}
+
+/// A builtin function that is called by the operator tree instead of being created as a [`Function`],
+/// as it needs to access the context or to receive unset variables as its argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ContextBuiltinFunction {
+    /// `is_set(x)` checks if `x` is a variable that is set and not empty.
+    IsSet,
+    /// `defined(name)` checks if a variable with the given name is set.
+    Defined,
+    /// `now()` returns the current time of the context.
+    #[cfg(feature = "datetime")]
+    Now,
+}
+
+impl ContextBuiltinFunction {
+    /// Returns the builtin function with the given identifier, if it is called by the operator tree.
+    pub(crate) fn from_identifier(identifier: &str) -> Option<Self> {
+        match identifier {
+            "is_set" => Some(Self::IsSet),
+            "defined" => Some(Self::Defined),
+            #[cfg(feature = "datetime")]
+            "now" => Some(Self::Now),
+            _ => None,
+        }
+    }
+
+    /// Returns true if a variable that is not set is passed to this function as `Value::Empty`,
+    /// instead of failing to evaluate the argument.
+    pub(crate) fn receives_unset_variables(self) -> bool {
+        self == Self::IsSet
+    }
+
+    /// Calls this function with the given argument.
+    pub(crate) fn call<C: Context>(
+        self,
+        argument: &Value<C::NumericTypes>,
+        context: &C,
+    ) -> EvalexprResultValue<C::NumericTypes> {
+        match self {
+            Self::IsSet => Ok(Value::Boolean(!argument.is_empty())),
+            Self::Defined => Ok(Value::Boolean(
+                context.get_value(&argument.as_string()?).is_some(),
+            )),
+            #[cfg(feature = "datetime")]
+            Self::Now => {
+                argument.as_empty()?;
+                Ok(Value::DateTime(context.now()))
+            },
+        }
+    }
+}
 
 pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
     identifier: &str,
 ) -> Option<Function<NumericTypes>> {
+    if let Some(function) = NumericTypes::builtin_function(identifier) {
+        return Some(function);
+    }
+
+    #[cfg(feature = "datetime")]
+    if let Some(function) = super::datetime::builtin_function(identifier) {
+        return Some(function);
+    }
+
+    #[cfg(feature = "units")]
+    if let Some(function) = super::units::builtin_function(identifier) {
+        return Some(function);
+    }
+
     match identifier {
         // Log
-        "math::ln" => simple_math!(ln),
-        "math::log" => simple_math!(log, 2),
-        "math::log2" => simple_math!(log2),
-        "math::log10" => simple_math!(log10),
+        "math::ln" => checked_math!(ln, checked_ln),
+        "math::log" => checked_math!(log, checked_log, 2),
+        "math::log2" => checked_math!(log2, checked_log2),
+        "math::log10" => checked_math!(log10, checked_log10),
         // Exp
-        "math::exp" => simple_math!(exp),
-        "math::exp2" => simple_math!(exp2),
+        "math::exp" => checked_math!(exp, checked_exp),
+        "math::exp2" => checked_math!(exp2, checked_exp2),
         // Pow
-        "math::pow" => simple_math!(pow, 2),
+        "math::pow" => checked_math!(pow, checked_pow, 2),
         // Cos
         "math::cos" => simple_math!(cos),
-        "math::acos" => simple_math!(acos),
-        "math::cosh" => simple_math!(cosh),
-        "math::acosh" => simple_math!(acosh),
+        "math::acos" => checked_math!(acos, checked_acos),
+        "math::cosh" => checked_math!(cosh, checked_cosh),
+        "math::acosh" => checked_math!(acosh, checked_acosh),
         // Sin
         "math::sin" => simple_math!(sin),
-        "math::asin" => simple_math!(asin),
-        "math::sinh" => simple_math!(sinh),
+        "math::asin" => checked_math!(asin, checked_asin),
+        "math::sinh" => checked_math!(sinh, checked_sinh),
         "math::asinh" => simple_math!(asinh),
         // Tan
         "math::tan" => simple_math!(tan),
         "math::atan" => simple_math!(atan),
         "math::tanh" => simple_math!(tanh),
-        "math::atanh" => simple_math!(atanh),
+        "math::atanh" => checked_math!(atanh, checked_atanh),
         "math::atan2" => simple_math!(atan2, 2),
         // Root
-        "math::sqrt" => simple_math!(sqrt),
+        "math::sqrt" => checked_math!(sqrt, checked_sqrt),
         "math::cbrt" => simple_math!(cbrt),
         // Hypotenuse
-        "math::hypot" => simple_math!(hypot, 2),
+        "math::hypot" => checked_math!(hypot, checked_hypot, 2),
         // Rounding
         "floor" => simple_math!(floor),
         "round" => simple_math!(round),
         "ceil" => simple_math!(ceil),
+        "trunc" => simple_math!(trunc),
+        "fract" => simple_math!(fract),
+        "round_to" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let number = arguments[0].as_number()?;
+            let digits: NumericTypes::Int = arguments[1].as_int()?;
+            let zero = int_from_usize(0)?;
+            // A scale that does not fit into the float type exceeds its precision.
+            let scale = float_powi::<NumericTypes>(
+                usize_as_float(10)?,
+                if digits < zero {
+                    checked_int_sub(&zero, &digits)?
+                } else {
+                    owned_int::<NumericTypes>(&digits)
+                }
+                .into_usize()?,
+            )
+            .ok()
+            .filter(|scale| scale.is_finite());
+            if digits < zero {
+                match scale {
+                    Some(scale) => Ok(Value::Float(
+                        number
+                            .checked_div(&scale, RoundingMode::default())?
+                            .round()
+                            .checked_mul(&scale)?,
+                    )),
+                    // Every finite number is closer to zero than to the next multiple of the scale.
+                    None if number.is_finite() => Ok(Value::Float(usize_as_float(0)?)),
+                    None => Ok(Value::Float(number)),
+                }
+            } else {
+                let scaled = scale.and_then(|scale| {
+                    let scaled = number.checked_mul(&scale).ok()?;
+                    scaled.is_finite().then_some((scaled, scale))
+                });
+                match scaled {
+                    Some((scaled, scale)) => Ok(Value::Float(
+                        scaled
+                            .round()
+                            .checked_div(&scale, RoundingMode::default())?,
+                    )),
+                    // A number too large to be scaled has no digits at the given decimal place.
+                    None => Ok(Value::Float(number)),
+                }
+            }
+        })),
         // Float special values
         "math::is_nan" => float_is(NumericTypes::Float::is_nan),
         "math::is_finite" => float_is(NumericTypes::Float::is_finite),
@@ -162,6 +848,125 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
             },
             _ => Err(EvalexprError::expected_number(argument.clone())),
         })),
+        // Sign
+        "math::sign" => Some(Function::new(|argument| match argument {
+            Value::Int(int) => {
+                let zero = int_from_usize::<NumericTypes>(0)?;
+                Ok(Value::Int(match int.cmp(&zero) {
+                    Ordering::Less => checked_int_sub(&zero, &int_from_usize(1)?)?,
+                    Ordering::Equal => zero,
+                    Ordering::Greater => int_from_usize(1)?,
+                }))
+            },
+            Value::Float(float) => {
+                let zero = usize_as_float::<NumericTypes>(0)?;
+                Ok(Value::Float(if *float > zero {
+                    usize_as_float(1)?
+                } else if *float < zero {
+                    zero - usize_as_float(1)?
+                } else {
+                    owned_float::<NumericTypes>(float)
+                }))
+            },
+            _ => Err(EvalexprError::expected_number(argument.clone())),
+        })),
+        // Angles
+        "math::to_radians" => checked_math!(to_radians, to_radians),
+        "math::to_degrees" => checked_math!(to_degrees, to_degrees),
+        // Interpolation
+        "math::lerp" => Some(Function::new(|argument| {
+            let arguments: TupleType<NumericTypes> = argument.as_fixed_len_tuple(3)?;
+            let (a, b, t) = (
+                arguments[0].as_number()?,
+                arguments[1].as_number()?,
+                arguments[2].as_number()?,
+            );
+            Ok(Value::Float(a.checked_add(&b.checked_sub(&a)?.checked_mul(&t)?)?))
+        })),
+        // Euclidean division
+        "math::div_euclid" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            if let (Value::Int(a), Value::Int(b)) = (&arguments[0], &arguments[1]) {
+                return Ok(Value::Int(EvalexprInt::checked_div_euclid(a, b)?));
+            }
+            let (a, b) = (arguments[0].as_number()?, arguments[1].as_number()?);
+            let zero = usize_as_float::<NumericTypes>(0)?;
+            let quotient = a.checked_div(&b, RoundingMode::TowardZero)?.trunc();
+            Ok(Value::Float(if a.checked_rem(&b)? < zero {
+                if b > zero {
+                    quotient - usize_as_float(1)?
+                } else {
+                    quotient + usize_as_float(1)?
+                }
+            } else {
+                quotient
+            }))
+        })),
+        "math::rem_euclid" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            if let (Value::Int(a), Value::Int(b)) = (&arguments[0], &arguments[1]) {
+                return Ok(Value::Int(EvalexprInt::checked_rem_euclid(a, b)?));
+            }
+            let (a, b) = (arguments[0].as_number()?, arguments[1].as_number()?);
+            let zero = usize_as_float::<NumericTypes>(0)?;
+            let remainder = a.checked_rem(&b)?;
+            Ok(Value::Float(if remainder < zero {
+                if b < zero {
+                    remainder - b
+                } else {
+                    remainder + b
+                }
+            } else {
+                remainder
+            }))
+        })),
+        // Number theory
+        "math::gcd" => Some(Function::new(|argument| fold_ints(argument, int_gcd))),
+        "math::lcm" => Some(Function::new(|argument| fold_ints(argument, int_lcm))),
+        "math::factorial" => Some(Function::new(|argument| {
+            Ok(Value::Int(int_factorial(&argument.as_int()?)?))
+        })),
+        "math::binomial" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            Ok(Value::Int(int_binomial(
+                &arguments[0].as_int()?,
+                &arguments[1].as_int()?,
+            )?))
+        })),
+        "math::is_prime" => Some(Function::new(|argument| {
+            Ok(Value::Boolean(int_is_prime(&argument.as_int()?)?))
+        })),
+        // Conversion
+        "int" => Some(Function::new(|argument: &Value<NumericTypes>| match argument {
+            Value::Int(int) => Ok(Value::Int(owned_int::<NumericTypes>(int))),
+            Value::Float(float) => Ok(Value::Int(float_into_int(float)?)),
+            Value::Boolean(boolean) => Ok(Value::Int(int_from_usize(*boolean as usize)?)),
+            Value::String(string) => Ok(Value::Int(parse_str(string)?)),
+            _ => Err(EvalexprError::type_error(
+                argument.clone(),
+                vec![ValueType::String, ValueType::Float, ValueType::Int, ValueType::Boolean],
+            )),
+        })),
+        "float" => Some(Function::new(|argument: &Value<NumericTypes>| match argument {
+            Value::Int(int) => Ok(Value::Float(NumericTypes::int_as_float(int))),
+            Value::Float(float) => Ok(Value::Float(owned_float::<NumericTypes>(float))),
+            Value::Boolean(boolean) => Ok(Value::Float(usize_as_float(*boolean as usize)?)),
+            Value::String(string) => Ok(Value::Float(parse_str(string)?)),
+            _ => Err(EvalexprError::type_error(
+                argument.clone(),
+                vec![ValueType::String, ValueType::Float, ValueType::Int, ValueType::Boolean],
+            )),
+        })),
+        "bool" => Some(Function::new(|argument: &Value<NumericTypes>| match argument {
+            Value::Int(int) => Ok(Value::Boolean(*int != int_from_usize(0)?)),
+            Value::Float(float) => Ok(Value::Boolean(*float != usize_as_float(0)?)),
+            Value::Boolean(boolean) => Ok(Value::Boolean(*boolean)),
+            Value::String(string) => Ok(Value::Boolean(parse_str(string)?)),
+            _ => Err(EvalexprError::type_error(
+                argument.clone(),
+                vec![ValueType::String, ValueType::Float, ValueType::Int, ValueType::Boolean],
+            )),
+        })),
         // Other
         "typeof" => Some(Function::new(move |argument| {
             Ok(match argument {
@@ -171,67 +976,165 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
                 Value::Boolean(_) => "boolean",
                 Value::Tuple(_) => "tuple",
                 Value::Empty => "empty",
+                #[cfg(feature = "datetime")]
+                Value::DateTime(_) => "datetime",
+                #[cfg(feature = "datetime")]
+                Value::Duration(_) => "duration",
+                #[cfg(feature = "units")]
+                Value::Quantity(_) => "quantity",
             }
             .into())
         })),
         "min" => Some(Function::new(|argument| {
             let arguments = argument.as_tuple()?;
-            let mut min_int = NumericTypes::Int::max_value();
-            let mut min_float = NumericTypes::Float::infinity();
-            debug_assert!(min_float.is_infinite());
+            let mut min_int: Option<NumericTypes::Int> = None;
+            let mut min_float: Option<NumericTypes::Float> = None;
 
             for argument in arguments {
                 if let Value::Float(float) = argument {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            min_float = min_float.min(float);
-                        } else {
-                            min_float = min_float.min(&float);
+                    min_float = Some(match min_float {
+                        Some(min_float) => {
+                            cfg_if! {
+                                if #[cfg(feature = "num_primitive")] {
+                                    min_float.min(float)
+                                } else {
+                                    min_float.checked_min(&float)?
+                                }
+                            }
                         }
-                    }
+                        None => float,
+                    });
                 } else if let Value::Int(int) = argument {
-                    min_int = min_int.min(int);
+                    min_int = Some(match min_int {
+                        Some(min_int) => min_int.min(int),
+                        None => int,
+                    });
                 } else {
                     return Err(EvalexprError::expected_number(argument));
                 }
             }
 
-            if (NumericTypes::int_as_float(&min_int)) < min_float {
-                Ok(Value::Int(min_int))
-            } else {
-                Ok(Value::Float(min_float))
+            match (min_int, min_float) {
+                (Some(min_int), Some(min_float)) => {
+                    let int_as_float = NumericTypes::int_as_float(&min_int);
+                    match int_as_float.partial_cmp(&min_float) {
+                        Some(Ordering::Less) => Ok(Value::Int(min_int)),
+                        Some(_) => Ok(Value::Float(min_float)),
+                        // Numbers that cannot be ordered, like overlapping intervals, are combined as floats.
+                        None => {
+                            cfg_if! {
+                                if #[cfg(feature = "num_primitive")] {
+                                    Ok(Value::Float(int_as_float.min(min_float)))
+                                } else {
+                                    Ok(Value::Float(int_as_float.checked_min(&min_float)?))
+                                }
+                            }
+                        }
+                    }
+                }
+                (Some(min_int), None) => Ok(Value::Int(min_int)),
+                (None, min_float) => Ok(Value::Float(
+                    min_float.unwrap_or_else(NumericTypes::Float::infinity),
+                )),
             }
         })),
         "max" => Some(Function::new(|argument| {
             let arguments = argument.as_tuple()?;
-            let mut max_int = NumericTypes::Int::min_value();
-            let mut max_float = NumericTypes::Float::neg_infinity();
-            debug_assert!(max_float.is_infinite());
+            let mut max_int: Option<NumericTypes::Int> = None;
+            let mut max_float: Option<NumericTypes::Float> = None;
 
             for argument in arguments {
                 match argument {
                     Value::Float(float) => {
-                        cfg_if! {
-                            if #[cfg(feature = "num_primitive")] {
-                                max_float = max_float.max(float);
-                            } else {
-                                max_float = max_float.max(&float);
+                        max_float = Some(match max_float {
+                            Some(max_float) => {
+                                cfg_if! {
+                                    if #[cfg(feature = "num_primitive")] {
+                                        max_float.max(float)
+                                    } else {
+                                        max_float.checked_max(&float)?
+                                    }
+                                }
                             }
-                        }
+                            None => float,
+                        });
                     }
                     Value::Int(int) => {
-                        max_int = max_int.max(int);
+                        max_int = Some(match max_int {
+                            Some(max_int) => max_int.max(int),
+                            None => int,
+                        });
                     }
                     _ => return Err(EvalexprError::expected_number(argument))
                 }
             }
 
-            if (NumericTypes::int_as_float(&max_int)) > max_float {
-                Ok(Value::Int(max_int))
-            } else {
-                Ok(Value::Float(max_float))
+            match (max_int, max_float) {
+                (Some(max_int), Some(max_float)) => {
+                    let int_as_float = NumericTypes::int_as_float(&max_int);
+                    match int_as_float.partial_cmp(&max_float) {
+                        Some(Ordering::Greater) => Ok(Value::Int(max_int)),
+                        Some(_) => Ok(Value::Float(max_float)),
+                        // Numbers that cannot be ordered, like overlapping intervals, are combined as floats.
+                        None => {
+                            cfg_if! {
+                                if #[cfg(feature = "num_primitive")] {
+                                    Ok(Value::Float(int_as_float.max(max_float)))
+                                } else {
+                                    Ok(Value::Float(int_as_float.checked_max(&max_float)?))
+                                }
+                            }
+                        }
+                    }
+                }
+                (Some(max_int), None) => Ok(Value::Int(max_int)),
+                (None, max_float) => Ok(Value::Float(
+                    max_float.unwrap_or_else(NumericTypes::Float::neg_infinity),
+                )),
             }
         })),
+        "clamp" => Some(Function::new(|argument| {
+            let arguments: TupleType<NumericTypes> = argument.as_fixed_len_tuple(3)?;
+            if let (Value::Int(value), Value::Int(min), Value::Int(max)) =
+                (&arguments[0], &arguments[1], &arguments[2])
+            {
+                if min > max {
+                    return Err(EvalexprError::argument_out_of_range(
+                        arguments[2].clone(),
+                        arguments[1].clone(),
+                        Value::Int(NumericTypes::Int::max_value()),
+                    ));
+                }
+                let clamped = if value < min {
+                    min
+                } else if value > max {
+                    max
+                } else {
+                    value
+                };
+                return Ok(Value::Int(owned_int::<NumericTypes>(clamped)));
+            }
+
+            let (value, min, max) = (
+                arguments[0].as_number()?,
+                arguments[1].as_number()?,
+                arguments[2].as_number()?,
+            );
+            if min > max || min.is_nan() || max.is_nan() {
+                return Err(EvalexprError::argument_out_of_range(
+                    arguments[2].clone(),
+                    arguments[1].clone(),
+                    Value::Float(NumericTypes::Float::infinity()),
+                ));
+            }
+            Ok(Value::Float(if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            }))
+        })),
         "if" => Some(Function::new(|argument| {
             let mut arguments = argument.as_fixed_len_tuple(3)?;
             let result_index = if arguments[0].as_boolean()? { 1 } else { 2 };
@@ -240,19 +1143,7 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
         "contains" => Some(Function::new(move |argument| {
             let arguments = argument.as_fixed_len_tuple(2)?;
             if let (Value::Tuple(a), b) = (&arguments[0].clone(), &arguments[1].clone()) {
-                if let Value::String(_) | Value::Int(_) | Value::Float(_) | Value::Boolean(_) = b {
-                    Ok(a.contains(b).into())
-                } else {
-                    Err(EvalexprError::type_error(
-                        b.clone(),
-                        vec![
-                            ValueType::String,
-                            ValueType::Int,
-                            ValueType::Float,
-                            ValueType::Boolean,
-                        ],
-                    ))
-                }
+                Ok(tuple_contains(a, b)?.into())
             } else {
                 Err(EvalexprError::expected_tuple(arguments[0].clone()))
             }
@@ -263,24 +1154,8 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
                 if let Value::Tuple(b) = b {
                     let mut contains = false;
                     for value in b {
-                        if let Value::String(_)
-                        | Value::Int(_)
-                        | Value::Float(_)
-                        | Value::Boolean(_) = value
-                        {
-                            if a.contains(value) {
-                                contains = true;
-                            }
-                        } else {
-                            return Err(EvalexprError::type_error(
-                                value.clone(),
-                                vec![
-                                    ValueType::String,
-                                    ValueType::Int,
-                                    ValueType::Float,
-                                    ValueType::Boolean,
-                                ],
-                            ));
+                        if tuple_contains(a, value)? {
+                            contains = true;
                         }
                     }
                     Ok(contains.into())
@@ -303,6 +1178,56 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
                 ))
             }
         })),
+        // Statistics
+        "stat::mean" => Some(Function::new(|argument| {
+            Ok(Value::Float(stat_mean(&stat_values(argument, 1)?)?))
+        })),
+        "stat::median" => Some(Function::new(|argument| {
+            let values = sorted_floats(&stat_values(argument, 1)?)?;
+            let half = usize_as_float::<NumericTypes>(1)? / usize_as_float(2)?;
+            Ok(Value::Float(quantile_of_sorted(&values, half)?))
+        })),
+        "stat::mode" => Some(Function::new(|argument| {
+            let values = stat_values(argument, 1)?;
+            let mut mode = &values[0];
+            let mut mode_count = 0;
+            for value in &values {
+                let count = values.iter().filter(|other| *other == value).count();
+                if count > mode_count {
+                    mode = value;
+                    mode_count = count;
+                }
+            }
+            Ok(mode.clone())
+        })),
+        "stat::variance" => Some(Function::new(|argument| {
+            Ok(Value::Float(stat_variance(&stat_values(argument, 1)?, 0)?))
+        })),
+        "stat::sample_variance" => Some(Function::new(|argument| {
+            Ok(Value::Float(stat_variance(&stat_values(argument, 2)?, 1)?))
+        })),
+        "stat::stddev" => Some(Function::new(|argument| {
+            Ok(Value::Float(
+                stat_variance::<NumericTypes>(&stat_values(argument, 1)?, 0)?.sqrt(),
+            ))
+        })),
+        "stat::sample_stddev" => Some(Function::new(|argument| {
+            Ok(Value::Float(
+                stat_variance::<NumericTypes>(&stat_values(argument, 2)?, 1)?.sqrt(),
+            ))
+        })),
+        "stat::percentile" => Some(Function::new(|argument| {
+            Ok(Value::Float(stat_quantile(argument, 100)?))
+        })),
+        "stat::quantile" => Some(Function::new(|argument| {
+            Ok(Value::Float(stat_quantile(argument, 1)?))
+        })),
+        "stat::min_index" => Some(Function::new(|argument| {
+            stat_extremum_index(argument, Ordering::Less)
+        })),
+        "stat::max_index" => Some(Function::new(|argument| {
+            stat_extremum_index(argument, Ordering::Greater)
+        })),
         // String functions
         #[cfg(feature = "regex")]
         "str::regex_matches" => Some(Function::new(|argument| {
@@ -350,6 +1275,33 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
         "str::from" => Some(Function::new(|argument| {
             Ok(Value::String(argument.str_from()))
         })),
+        "str::parse_int" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            let (string, radix) = match argument {
+                Value::Tuple(_) => {
+                    let arguments = argument.as_fixed_len_tuple(2)?;
+                    (arguments[0].as_string()?, arguments[1].as_int()?)
+                },
+                _ => (argument.as_string()?, int_from_usize(10)?),
+            };
+            if radix < int_from_usize(2)? || radix > int_from_usize(36)? {
+                return Err(EvalexprError::argument_out_of_range(
+                    Value::Int(radix),
+                    Value::Int(int_from_usize(2)?),
+                    Value::Int(int_from_usize(36)?),
+                ));
+            }
+            // The radix was checked to be between 2 and 36 above, so it fits into a `u32`.
+            let radix = radix.into_usize()? as u32;
+            NumericTypes::Int::from_str_radix(&string, radix)
+                .map(Value::Int)
+                .map_err(|_| EvalexprError::StringParse {
+                    string,
+                    target_type: type_name::<NumericTypes::Int>(),
+                })
+        })),
+        "str::parse_float" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            Ok(Value::Float(parse_str(&argument.as_string()?)?))
+        })),
         "str::substring" => Some(Function::new(|argument| {
             let args = argument.as_ranged_len_tuple(2..=3)?;
             let subject = args[0].as_string()?;
@@ -379,8 +1331,18 @@ pub fn builtin_function<NumericTypes: EvalexprNumericTypes>(
         "bitor" => int_function!(bitor, 2),
         "bitxor" => int_function!(bitxor, 2),
         "bitnot" => int_function!(not),
-        "shl" => int_function!(shl, 2),
-        "shr" => int_function!(shr, 2),
+        "shl" => Some(Function::new(|argument| {
+            let tuple = argument.as_fixed_len_tuple(2)?;
+            let (a, b): (NumericTypes::Int, NumericTypes::Int) =
+                (tuple[0].as_int()?, tuple[1].as_int()?);
+            Ok(Value::Int(a.checked_shl(&b)?))
+        })),
+        "shr" => Some(Function::new(|argument| {
+            let tuple = argument.as_fixed_len_tuple(2)?;
+            let (a, b): (NumericTypes::Int, NumericTypes::Int) =
+                (tuple[0].as_int()?, tuple[1].as_int()?);
+            Ok(Value::Int(a.checked_shr(&b)?))
+        })),
         _ => None,
     }
 }
@@ -0,0 +1,199 @@
+use std::any::type_name;
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, Timelike,
+};
+
+use crate::{
+    value::{numeric_types::EvalexprNumericTypes, value_type::ValueType},
+    EvalexprError, EvalexprResult, Function, Value,
+};
+
+/// Parses an ISO 8601 date and time like `2024-01-31T08:30:00+01:00`.
+/// Without an offset the time is in UTC, and a date without a time is at midnight.
+fn parse_datetime(string: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(string)
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(string, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|datetime| datetime.and_utc().fixed_offset())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(string, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|datetime| datetime.and_utc().fixed_offset())
+        })
+}
+
+/// Parses an ISO 8601 duration like `P1W2DT3H4M5.5S`, optionally preceded by a minus sign.
+/// Years and months are not supported, because their length is not fixed.
+fn parse_duration(string: &str) -> Option<TimeDelta> {
+    let (negative, string) = match string.strip_prefix('-') {
+        Some(string) => (true, string),
+        None => (false, string),
+    };
+    let string = string.strip_prefix('P')?;
+    let (date, time) = match string.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, time),
+        Some(_) => return None,
+        None => (string, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut duration = TimeDelta::zero();
+    for (mut part, units) in [
+        (date, &[('W', 7 * 86_400), ('D', 86_400)][..]),
+        (time, &[('H', 3_600), ('M', 60), ('S', 1)][..]),
+    ] {
+        for &(designator, seconds) in units {
+            if let Some((number, rest)) = part.split_once(designator) {
+                duration = duration.checked_add(&parse_duration_component(number, seconds)?)?;
+                part = rest;
+            }
+        }
+        if !part.is_empty() {
+            return None;
+        }
+    }
+
+    Some(if negative { -duration } else { duration })
+}
+
+/// Parses the number of a component of an ISO 8601 duration, whose unit is the given amount of seconds.
+/// Only seconds may have a fraction.
+fn parse_duration_component(number: &str, seconds: i64) -> Option<TimeDelta> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let is_digits = |string: &str| string.bytes().all(|byte| byte.is_ascii_digit());
+    if whole.is_empty()
+        || !is_digits(whole)
+        || !is_digits(fraction)
+        || fraction.len() > 9
+        || (!fraction.is_empty() && seconds != 1)
+    {
+        return None;
+    }
+
+    let whole = TimeDelta::try_seconds(whole.parse::<i64>().ok()?.checked_mul(seconds)?)?;
+    let nanoseconds = format!("{:0<9}", fraction).parse().ok()?;
+    whole.checked_add(&TimeDelta::nanoseconds(nanoseconds))
+}
+
+/// Converts an `i64` into an integer.
+/// Integer types only share their decimal representation, so the conversion goes through it.
+fn int_from_i64<NumericTypes: EvalexprNumericTypes>(
+    int: i64,
+) -> EvalexprResult<Value<NumericTypes>, NumericTypes> {
+    int.to_string()
+        .parse()
+        .map(Value::Int)
+        .map_err(|_| EvalexprError::StringParse {
+            string: int.to_string(),
+            target_type: type_name::<NumericTypes::Int>(),
+        })
+}
+
+/// Returns a builtin function that creates a duration from an integer amount of a unit.
+fn duration_constructor<NumericTypes: EvalexprNumericTypes>(
+    constructor: fn(i64) -> Option<TimeDelta>,
+) -> Option<Function<NumericTypes>> {
+    Some(Function::new(move |argument: &Value<NumericTypes>| {
+        let int = argument.as_int()?;
+        // Like in `int_from_i64`, the integer is converted through its decimal representation.
+        match int.to_string().parse().ok().and_then(constructor) {
+            Some(duration) => Ok(Value::Duration(duration)),
+            None => Err(EvalexprError::IntToNum {
+                int,
+                target_type: type_name::<TimeDelta>(),
+            }),
+        }
+    }))
+}
+
+/// Returns a builtin function that returns a component of a date and time.
+fn datetime_component<NumericTypes: EvalexprNumericTypes>(
+    component: fn(&DateTime<FixedOffset>) -> i64,
+) -> Option<Function<NumericTypes>> {
+    Some(Function::new(move |argument: &Value<NumericTypes>| {
+        int_from_i64(component(&argument.as_datetime()?))
+    }))
+}
+
+/// Returns the builtin function with the given identifier that works with dates and durations.
+pub(crate) fn builtin_function<NumericTypes: EvalexprNumericTypes>(
+    identifier: &str,
+) -> Option<Function<NumericTypes>> {
+    match identifier {
+        // Parsing and formatting
+        "datetime" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            let string = argument.as_string()?;
+            parse_datetime(&string)
+                .map(Value::DateTime)
+                .ok_or(EvalexprError::StringParse {
+                    string,
+                    target_type: type_name::<DateTime<FixedOffset>>(),
+                })
+        })),
+        "duration" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            let string = argument.as_string()?;
+            parse_duration(&string)
+                .map(Value::Duration)
+                .ok_or(EvalexprError::StringParse {
+                    string,
+                    target_type: type_name::<TimeDelta>(),
+                })
+        })),
+        "datetime::format" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            let (datetime, pattern) = match argument {
+                Value::DateTime(_) | Value::Duration(_) => {
+                    return Ok(Value::String(argument.to_string()))
+                },
+                Value::Tuple(_) => {
+                    let arguments = argument.as_fixed_len_tuple(2)?;
+                    (arguments[0].as_datetime()?, arguments[1].as_string()?)
+                },
+                _ => return Err(expected_datetime_or_duration(argument)),
+            };
+            let items: Vec<Item> = StrftimeItems::new(&pattern).collect();
+            if items.contains(&Item::Error) {
+                return Err(EvalexprError::IllegalFormatSpecification(pattern));
+            }
+            Ok(Value::String(
+                datetime.format_with_items(items.iter()).to_string(),
+            ))
+        })),
+        // Durations
+        "weeks" => duration_constructor(TimeDelta::try_weeks),
+        "days" => duration_constructor(TimeDelta::try_days),
+        "hours" => duration_constructor(TimeDelta::try_hours),
+        "minutes" => duration_constructor(TimeDelta::try_minutes),
+        "seconds" => duration_constructor(TimeDelta::try_seconds),
+        "milliseconds" => duration_constructor(TimeDelta::try_milliseconds),
+        "total_seconds" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            int_from_i64(argument.as_duration()?.num_seconds())
+        })),
+        // Components
+        "year" => datetime_component(|datetime| datetime.year().into()),
+        "month" => datetime_component(|datetime| datetime.month().into()),
+        "day" => datetime_component(|datetime| datetime.day().into()),
+        "hour" => datetime_component(|datetime| datetime.hour().into()),
+        "minute" => datetime_component(|datetime| datetime.minute().into()),
+        "second" => datetime_component(|datetime| datetime.second().into()),
+        "weekday" => datetime_component(|datetime| datetime.weekday().number_from_monday().into()),
+        _ => None,
+    }
+}
+
+/// Returns an error that `value` is neither a date nor a duration.
+fn expected_datetime_or_duration<NumericTypes: EvalexprNumericTypes>(
+    value: &Value<NumericTypes>,
+) -> EvalexprError<NumericTypes> {
+    EvalexprError::type_error(
+        value.clone(),
+        vec![ValueType::DateTime, ValueType::Duration],
+    )
+}
@@ -9,6 +9,10 @@ use crate::{
 };
 
 pub(crate) mod builtin;
+#[cfg(feature = "datetime")]
+mod datetime;
+#[cfg(feature = "units")]
+mod units;
 
 /// A helper trait to enable cloning through `Fn` trait objects.
 trait ClonableFn<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
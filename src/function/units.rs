@@ -0,0 +1,30 @@
+use crate::{
+    value::{
+        numeric_types::EvalexprNumericTypes,
+        units::{Quantity, Unit},
+    },
+    EvalexprError, Function, Value,
+};
+
+/// Returns the builtin function with the given identifier that works with quantities.
+pub(crate) fn builtin_function<NumericTypes: EvalexprNumericTypes>(
+    identifier: &str,
+) -> Option<Function<NumericTypes>> {
+    match identifier {
+        "convert" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let unit = arguments[1].as_string()?;
+            let unit = Unit::parse(&unit).ok_or(EvalexprError::UnknownUnit(unit))?;
+            Quantity::from_value(&arguments[0])?
+                .convert(&unit)?
+                .into_value()
+        })),
+        "unit" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            Ok(Value::String(argument.as_quantity()?.unit().to_string()))
+        })),
+        "magnitude" => Some(Function::new(|argument: &Value<NumericTypes>| {
+            Ok(Value::Float(argument.as_quantity()?.into_parts().0))
+        })),
+        _ => None,
+    }
+}
@@ -129,14 +129,25 @@
 //! | % | 100 | Modulo (integer if both arguments are integers, otherwise float) |
 //! | + | 95 | Sum or String Concatenation |
 //! | - | 95 | Difference |
+//! | << | 90 | Bitwise shift left |
+//! | \>\> | 90 | Bitwise shift right |
+//! | .. | 82 | Range (tuple of integers, excluding the upper bound) |
+//! | ..= | 82 | Inclusive range (tuple of integers, including the upper bound) |
 //! | < | 80 | Lower than |
 //! | \> | 80 | Greater than |
 //! | <= | 80 | Lower than or equal |
 //! | \>= | 80 | Greater than or equal |
 //! | == | 80 | Equal |
 //! | != | 80 | Not equal |
+//! | in | 80 | Membership in a tuple or substring of a string |
+//! | not in | 80 | Negated membership |
+//! | & | 79 | Bitwise and |
+//! | xor | 78 | Bitwise exclusive or |
+//! | &#124; | 77 | Bitwise or |
 //! | && | 75 | Logical and |
 //! | &#124;&#124; | 70 | Logical or |
+//! | ?? | 60 | Null-coalescing (second argument if the first is empty or an unset variable) |
+//! | ? : | 55 | Conditional (ternary, `condition ? if_true : if_false`) |
 //! | = | 50 | Assignment |
 //! | += | 50 | Sum-Assignment or String-Concatenation-Assignment |
 //! | -= | 50 | Difference-Assignment |
@@ -146,6 +157,11 @@
 //! | ^= | 50 | Exponentiation-Assignment |
 //! | &&= | 50 | Logical-And-Assignment |
 //! | &#124;&#124;= | 50 | Logical-Or-Assignment |
+//! | &= | 50 | Bitwise-And-Assignment |
+//! | &#124;= | 50 | Bitwise-Or-Assignment |
+//! | xor= | 50 | Bitwise-Exclusive-Or-Assignment |
+//! | <<= | 50 | Bitwise-Shift-Left-Assignment |
+//! | \>\>= | 50 | Bitwise-Shift-Right-Assignment |
 //! | , | 40 | Aggregation |
 //! | ; | 0 | Expression Chaining |
 //!
@@ -155,6 +171,7 @@
 //! |----------|------------|-------------|
 //! | - | 110 | Negation |
 //! | ! | 110 | Logical not |
+//! | ~ | 110 | Bitwise not |
 //!
 //! Operators that take numeric_types as arguments can either take integers or floating point numeric_types.
 //! If one of the arguments is a floating point number, all others are converted to floating point numeric_types as well, and the resulting value is a floating point number as well.
@@ -170,6 +187,133 @@
 //! assert_eq!(eval("2^2"), Ok(Value::from_float(4.0)));
 //! ```
 //!
+//! The bitwise operators only accept integers.
+//! As in C, they bind looser than the comparison operators, so testing flags requires parentheses.
+//! Since `^` is the exponentiation operator, the bitwise exclusive or is written as `xor`.
+//! This makes `xor` a keyword, so it can no longer be used as a variable or function name.
+//! Shifting by a negative amount or by at least the number of bits of the integer type results in a `ShiftError`.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("6 & 3"), Ok(Value::from_int(2)));
+//! assert_eq!(eval("1 << 4 | 1"), Ok(Value::from_int(17)));
+//! assert_eq!(eval("6 xor 3"), Ok(Value::from_int(5)));
+//! assert_eq!(eval("flags = 5; (flags & 4) != 0"), Ok(Value::from(true)));
+//! ```
+//!
+//! #### The Membership Operators
+//!
+//! The `in` operator checks if a value is an element of a tuple, or if a string is a substring of another string.
+//! The range operators `a..b` and `a..=b` create the tuple of all integers from `a` up to `b`, excluding or including `b` respectively.
+//! When a range is written directly after `in`, membership is checked by comparing against its bounds, so large ranges are not materialized.
+//! Otherwise, each element of a created range consumes one iteration of the loop iteration budget described below.
+//! Elements are compared like in the `contains` builtin function, so a float is never contained in a range of integers.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("age = 30; country = \"DE\"; age in 18..=65 && country in (\"DE\", \"AT\")"),
+//!            Ok(Value::from(true)));
+//! assert_eq!(eval("\"ell\" in \"hello\""), Ok(Value::from(true)));
+//! assert_eq!(eval("4 not in 1..4"), Ok(Value::from(true)));
+//! assert_eq!(eval("1..4"), Ok(Value::from(vec![Value::from_int(1), Value::from_int(2), Value::from_int(3)])));
+//! ```
+//!
+//! #### Comparison Chains
+//!
+//! Ordering comparisons written one after another, like `0 < x <= 10`, are evaluated like in mathematics, as `0 < x && x <= 10`.
+//! Each operand is evaluated only once, and the evaluation stops at the first comparison that does not hold.
+//! Only the operators `<`, `<=`, `>` and `>=` form chains, so `a == b == c` still compares the boolean result of `a == b` with `c`, and parentheses like `(a < b) <= c` prevent chaining.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("x = 7; 0 < x <= 10"), Ok(Value::from(true)));
+//! assert_eq!(eval("x = 7; 0 < x <= 5"), Ok(Value::from(false)));
+//! assert_eq!(eval("\"a\" < \"b\" < \"c\""), Ok(Value::from(true)));
+//! ```
+//!
+//! #### The Null-Coalescing Operator
+//!
+//! The null-coalescing operator `a ?? b` evaluates to `a`, unless `a` is empty or a variable that is not set in the context.
+//! In that case, it evaluates to `b`, which is not evaluated otherwise.
+//! It is right-associative, so `a ?? b ?? c` falls back from `a` to `b` to `c`.
+//! Only a variable read directly on the left side may be unset, so `a + 1 ?? 0` still fails if `a` is not set.
+//! The builtin functions `is_set` and `defined` check for optional variables without falling back to a value.
+//! Like other builtin functions, they are shadowed by functions of the context with the same name, in which case the argument of `is_set` has to be set as usual.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! context.set_value("price".into(), Value::from_int(10)).unwrap(); // Do proper error handling here
+//! assert_eq!(eval_with_context("price * (quantity ?? 1)", &context), Ok(Value::from_int(10)));
+//! assert_eq!(eval_with_context("is_set(quantity)", &context), Ok(Value::from(false)));
+//! assert_eq!(eval_with_context("defined(\"price\")", &context), Ok(Value::from(true)));
+//! ```
+//!
+//! #### The Conditional Operator
+//!
+//! The conditional operator `condition ? if_true : if_false` evaluates to `if_true` if the condition is true, and to `if_false` otherwise.
+//! Unlike the `if` builtin function, it only evaluates the branch that is chosen.
+//! It binds weaker than all operators except assignments, aggregations and expression chaining, and nested conditionals need no parentheses.
+//! A single colon always separates the branches of a conditional, so it cannot be part of an unquoted identifier anymore: `a:b` is an `UnmatchedColon` error, and such names have to be quoted as `` `a:b` ``.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("x = 5; x < 0 ? -1 : x == 0 ? 0 : 1"), Ok(Value::from_int(1)));
+//! assert_eq!(eval("y = 2 > 1 ? \"yes\" : \"no\"; y"), Ok(Value::from("yes")));
+//! assert_eq!(eval("true ? 1 : 1 / 0"), Ok(Value::from_int(1)));
+//! ```
+//!
+//! #### Blocks and Loops
+//!
+//! Expressions in curly braces form a block, which evaluates to the value of its last expression like a parenthesized chain.
+//! The loop `while condition { ... }` evaluates its body as long as the condition is true,
+//! and `for x in tuple { ... }` evaluates its body once for each element of the tuple, binding the element to the variable `x`.
+//! Like a `let` binding, `x` is local to the loop, so it shadows a variable of the same name and may take elements of different types.
+//! A range like `for i in 0..n { ... }` is iterated without creating the tuple of all its elements.
+//! Inside a loop body, `break` leaves the innermost loop and `continue` skips to its next iteration.
+//! Loops evaluate to the empty value, and `for` loops require a mutable context.
+//! The words `while`, `for`, `break` and `continue` are keywords and cannot be used as identifiers.
+//!
+//! To guarantee that evaluation terminates, all loops of a single evaluation together may perform at most as many iterations as the loop iteration budget of the context allows.
+//! It defaults to one million and can be changed with `HashMapContext::set_loop_iteration_budget`.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("i = 0; sum = 0; while i < 5 { i += 1; sum += i }; sum"), Ok(Value::from_int(15)));
+//! assert_eq!(eval("sum = 0; for x in 1..=100 { x % 2 == 0 ? continue : (); sum += x }; sum"), Ok(Value::from_int(2500)));
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! context.set_loop_iteration_budget(1000);
+//! assert_eq!(eval_with_context_mut("while true {}", &mut context), Err(EvalexprError::LoopIterationBudgetExceeded));
+//! ```
+//!
+//! #### Local Variables
+//!
+//! The binding `let x = expression` assigns to a local variable `x` that lives only for the rest of the enclosing chain or block.
+//! Local variables shadow variables of the context with the same identifier without modifying them,
+//! so temporaries of a script do not leak into the context.
+//! Assigning to a local variable with `=` or a compound assignment operator changes the local variable,
+//! and assigning to any other variable writes to the context.
+//! Since they do not modify the context, `let` bindings can also be used with immutable contexts.
+//! The word `let` is a keyword and cannot be used as an identifier.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! assert_eq!(eval_with_context_mut("let tmp = 3; total = tmp * tmp", &mut context), Ok(Value::Empty));
+//! assert_eq!(context.get_value("total"), Some(&Value::from_int(9)));
+//! assert_eq!(context.get_value("tmp"), None);
+//! assert_eq!(eval_with_context_mut("let total = 1; { let total = 2; total } + total", &mut context), Ok(Value::from_int(3)));
+//! assert_eq!(context.get_value("total"), Some(&Value::from_int(9)));
+//! ```
+//!
 //! #### The Aggregation Operator
 //!
 //! The aggregation operator aggregates a set of values into a tuple.
@@ -231,6 +375,22 @@
 //! assert_eq!(eval_boolean("a = true; a &&= false; a"), Ok(false));
 //! ```
 //!
+//! A tuple of variables on the left side of an assignment is assigned element-wise, which also works with nested tuples and `let` bindings.
+//! The assigned value must be a tuple of the same length, otherwise an `ExpectedFixedLengthTuple` error is returned.
+//! As an exception to the precedence of the assignment operator over the aggregation operator, the tuples on both sides do not need parentheses.
+//! An assignment to a single variable like `a = 1, 2` still results in the tuple `(), 2`.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval_int("a = 1; b = 2; a, b = b, a; a"), Ok(2));
+//! assert_eq!(eval_int("(lo, hi) = (3, 7); hi - lo"), Ok(4));
+//! assert_eq!(eval_empty("a, b = 1, 2, 3"),
+//!            Err(EvalexprError::expected_fixed_len_tuple(2, Value::from(vec![
+//!                Value::from_int(1), Value::from_int(2), Value::from_int(3)
+//!            ]))));
+//! ```
+//!
 //! #### The Expression Chaining Operator
 //!
 //! The expression chaining operator works as one would expect from programming languages that use the semicolon to end statements, like `Rust`, `C` or `Java`.
@@ -350,10 +510,19 @@
 //! | `floor`              | 1               | Numeric                       | Returns the largest integer less than or equal to a number |
 //! | `round`              | 1               | Numeric                       | Returns the nearest integer to a number. Rounds half-way cases away from 0.0 |
 //! | `ceil`               | 1               | Numeric                       | Returns the smallest integer greater than or equal to a number |
+//! | `trunc`              | 1               | Numeric                       | Returns the integer part of a number, rounding towards zero |
+//! | `fract`              | 1               | Numeric                       | Returns the fractional part of a number, with the same sign as the number |
+//! | `round_to`           | 2               | Numeric, Int                  | Rounds a number to the given number of decimal digits. A negative digit count rounds to the left of the decimal point. Digit counts beyond the range of the float type leave the number unchanged, or round it to zero if negative |
+//! | `clamp`              | 3               | Numeric, Numeric, Numeric     | Restricts the first argument to the range given by the second and third argument, returning an integer if all arguments are integers |
 //! | `if`                 | 3               | Boolean, Any, Any             | If the first argument is true, returns the second argument, otherwise, returns the third  |
 //! | `contains`           | 2               | Tuple, any non-tuple          | Returns true if second argument exists in first tuple argument. |
 //! | `contains_any`       | 2               | Tuple, Tuple of any non-tuple | Returns true if one of the values in the second tuple argument exists in first tuple argument. |
+//! | `int`                | 1               | String, Numeric, Boolean      | Converts the argument to an integer. Floats are truncated towards zero, booleans become 0 or 1 and strings are parsed |
+//! | `float`              | 1               | String, Numeric, Boolean      | Converts the argument to a float. Booleans become 0.0 or 1.0 and strings are parsed |
+//! | `bool`               | 1               | String, Numeric, Boolean      | Converts the argument to a boolean. Numbers are true if they are not zero and strings must be "true" or "false" |
 //! | `typeof`             | 1               | Any                           | returns "string", "float", "int", "boolean", "tuple", or "empty" depending on the type of the argument  |
+//! | `is_set`             | 1               | Any                           | Returns true if the argument is not empty. The argument may be a variable that is not set, which counts as empty |
+//! | `defined`            | 1               | String                        | Returns true if a variable with the given name exists in the context, even if its value is empty |
 //! | `math::is_nan`       | 1               | Numeric                       | Returns true if the argument is the floating-point value NaN, false if it is another floating-point value, and throws an error if it is not a number  |
 //! | `math::is_finite`    | 1               | Numeric                       | Returns true if the argument is a finite floating-point number, false otherwise  |
 //! | `math::is_infinite`  | 1               | Numeric                       | Returns true if the argument is an infinite floating-point number, false otherwise  |
@@ -382,12 +551,36 @@
 //! | `math::cbrt`         | 1               | Numeric                       | Returns the cube root of a number |
 //! | `math::hypot`        | 2               | Numeric                       | Calculates the length of the hypotenuse of a right-angle triangle given legs of length given by the two arguments |
 //! | `math::abs`          | 1               | Numeric                       | Returns the absolute value of a number, returning an integer if the argument was an integer, and a float otherwise |
+//! | `math::sign`         | 1               | Numeric                       | Returns -1, 0 or 1 depending on the sign of the number, as an integer if the argument was an integer, and a float otherwise |
+//! | `math::to_radians`   | 1               | Numeric                       | Converts an angle from degrees to radians |
+//! | `math::to_degrees`   | 1               | Numeric                       | Converts an angle from radians to degrees |
+//! | `math::lerp`         | 3               | Numeric, Numeric, Numeric     | Linearly interpolates between the first two arguments, `a + (b - a) * t` |
+//! | `math::div_euclid`   | 2               | Numeric, Numeric              | Computes the quotient of the Euclidean division, returning an integer if both arguments are integers |
+//! | `math::rem_euclid`   | 2               | Numeric, Numeric              | Computes the non-negative remainder of the Euclidean division, returning an integer if both arguments are integers |
+//! | `math::gcd`          | >= 2            | Int                           | Returns the non-negative greatest common divisor of the arguments |
+//! | `math::lcm`          | >= 2            | Int                           | Returns the non-negative least common multiple of the arguments |
+//! | `math::factorial`    | 1               | Int                           | Returns the factorial of a non-negative integer |
+//! | `math::binomial`     | 2               | Int, Int                      | Returns the binomial coefficient `n` choose `k`, which is zero if `k` is negative or larger than `n` |
+//! | `math::is_prime`     | 1               | Int                           | Returns true if the argument is a prime number. Above 3.3 * 10^24, composite numbers constructed to pass its Miller-Rabin test are considered prime |
+//! | `stat::mean`         | >= 1            | Numeric                       | Returns the arithmetic mean of the arguments |
+//! | `stat::median`       | >= 1            | Numeric                       | Returns the median of the arguments, averaging the two middle values for an even amount |
+//! | `stat::mode`         | >= 1            | Numeric                       | Returns the most frequent argument. Ties are resolved in favour of the value that occurs first |
+//! | `stat::variance`     | >= 1            | Numeric                       | Returns the population variance of the arguments |
+//! | `stat::stddev`       | >= 1            | Numeric                       | Returns the population standard deviation of the arguments |
+//! | `stat::sample_variance` | >= 2         | Numeric                       | Returns the sample variance of the arguments |
+//! | `stat::sample_stddev` | >= 2           | Numeric                       | Returns the sample standard deviation of the arguments |
+//! | `stat::percentile`   | 2               | Tuple of Numeric, Numeric     | Returns the given percentile (between 0 and 100) of the values in the tuple, interpolating linearly between the closest ranks |
+//! | `stat::quantile`     | 2               | Tuple of Numeric, Numeric     | Returns the given quantile (between 0 and 1) of the values in the tuple, interpolating linearly between the closest ranks |
+//! | `stat::min_index`    | >= 1            | Numeric                       | Returns the index of the first occurrence of the minimum of the arguments |
+//! | `stat::max_index`    | >= 1            | Numeric                       | Returns the index of the first occurrence of the maximum of the arguments |
 //! | `str::regex_matches` | 2               | String, String                | Returns true if the first argument matches the regex in the second argument (Requires `regex_support` feature flag) |
 //! | `str::regex_replace` | 3               | String, String, String        | Returns the first argument with all matches of the regex in the second argument replaced by the third argument (Requires `regex_support` feature flag) |
 //! | `str::to_lowercase`  | 1               | String                        | Returns the lower-case version of the string |
 //! | `str::to_uppercase`  | 1               | String                        | Returns the upper-case version of the string |
 //! | `str::trim`          | 1               | String                        | Strips whitespace from the start and the end of the string |
 //! | `str::from`          | >= 0            | Any                           | Returns passed value as string |
+//! | `str::parse_int`     | 1, 2            | String, Int                   | Parses the first argument as an integer in the radix given by the second argument, which must be between 2 and 36. If the radix is omitted, it defaults to 10 |
+//! | `str::parse_float`   | 1               | String                        | Parses the argument as a float |
 //! | `str::substring`     | 3               | String, Int, Int              | Returns a substring of the first argument, starting at the second argument and ending at the third argument. If the last argument is omitted, the substring extends to the end of the string |
 //! | `bitand`             | 2               | Int                           | Computes the bitwise and of the given integers |
 //! | `bitor`              | 2               | Int                           | Computes the bitwise or of the given integers |
@@ -401,6 +594,13 @@
 //! If the maximum or minimum is an integer, then an integer is returned.
 //! Otherwise, a float is returned.
 //!
+//! The statistics functions in the `stat::` namespace take either the values themselves, as in `stat::mean(1, 2, 3)`, or a tuple of values, as in `stat::mean(values)`.
+//! They always return a float, except for `stat::mode`, which returns one of its arguments, and the index functions, which return an integer.
+//! Calling them with fewer values than required results in an `ExpectedMinimumLengthTuple` error.
+//!
+//! The conversion functions `int`, `float`, `bool`, `str::parse_int` and `str::parse_float` never silently produce a wrong value.
+//! A string that cannot be parsed results in a `StringParse` error, and a float that is NaN or out of the integer range results in a `FloatToNum` error when converted with `int`.
+//!
 //! The regex functions require the feature flag `regex_support`.
 //!
 //! ### Values
@@ -411,13 +611,50 @@
 //!
 //! | Value type | Example |
 //! |------------|---------|
-//! | `Value::String` | `"abc"`, `""`, `"a\"b\\c"` |
+//! | `Value::String` | `"abc"`, `""`, `"a\"b\\c"`, `'abc'`, `r"\d+"`, `r#"say "hi""#`, `"\u{1F600}"`, `f"{a} of {b:.2}"` |
 //! | `Value::Boolean` | `true`, `false` |
-//! | `Value::Int` | `3`, `-9`, `0`, `135412`, `0xfe02`, `-0x1e` |
-//! | `Value::Float` | `3.`, `.35`, `1.00`, `0.5`, `123.554`, `23e4`, `-2e-3`, `3.54e+2` |
+//! | `Value::Int` | `3`, `-9`, `0`, `135412`, `0xfe02`, `-0x1e`, `0b1010`, `0o17`, `1_000_000` |
+//! | `Value::Float` | `3.`, `.35`, `1.00`, `0.5`, `123.554`, `23e4`, `-2e-3`, `3.54e+2`, `6.02E23`, `1_000.5` |
 //! | `Value::Tuple` | `(3, 55.0, false, ())`, `(1, 2)` |
 //! | `Value::Empty` | `()` |
 //!
+//! Integer literals with the prefixes `0x`, `0b` and `0o` are read in hexadecimal, binary and octal notation respectively.
+//! The digits of number literals can be separated by underscores, as in Rust.
+//!
+//! String literals can be enclosed in double quotes `"` or single quotes `'`.
+//! Within them, the escape sequences `\"`, `\'` and `\\` stand for a double quote, a single quote and a backslash,
+//! and `\u{...}` stands for the unicode character with the code point of one to six hexadecimal digits in the curly braces.
+//! An illegal escape sequence results in an `IllegalEscapeSequence` error that contains the escape sequence up to the character that made it illegal.
+//! Raw strings like `r"\d+"` do not support escape sequences, such that backslashes can be written directly, as in Rust.
+//! To contain double quotes, raw strings can be delimited with any amount of hashes, like `r#"say "hi""#`.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("'say \"hi\"'"), Ok(Value::from("say \"hi\"")));
+//! assert_eq!(eval("\"\\u{41}\\u{1F600}\""), Ok(Value::from("A\u{1F600}")));
+//! assert_eq!(eval(r#"r"\d+" == "\\d+""#), Ok(Value::from(true)));
+//! assert_eq!(eval(r##"r#"say "hi""#"##), Ok(Value::from("say \"hi\"")));
+//! assert_eq!(eval(r#""\x""#), Err(EvalexprError::IllegalEscapeSequence("\\x".to_string())));
+//! ```
+//!
+//! A string literal that is directly preceded by `f` is a format string, like `f"{a} of {b:.2}"`.
+//! Expressions in curly braces are evaluated and interpolated into the string, where strings are inserted without quotes.
+//! A colon after an expression starts its format specification `[[fill]alignment][0][width][.precision]`,
+//! where the alignment is `<`, `^` or `>` for left, centered or right alignment.
+//! Numbers are aligned to the right and all other values to the left by default, and the fill character defaults to a space.
+//! The precision is the amount of decimal places of numbers and of the magnitude of quantities, or the maximum amount of characters of other values.
+//! A `0` before the width pads numbers with zeros after their sign, and the width and the precision may be at most 1024.
+//! Literal curly braces are written as `{{` and `}}`, and a conditional inside of an interpolated expression needs to be put in parentheses.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! assert_eq!(eval("a = 3; b = 2.0 / 3; f\"{a} of {b:.2}\""), Ok(Value::from("3 of 0.67")));
+//! assert_eq!(eval("f\"[{\"left\":<6}][{42:0>5}][{1:.1}][{-7:04}]\""), Ok(Value::from("[left  ][00042][1.0][-007]")));
+//! assert_eq!(eval("f\"{{{(true ? \"yes\" : \"no\")}}}\""), Ok(Value::from("{yes}")));
+//! ```
+//!
 //! Integers are internally represented as `i64`, and floating point numeric_types are represented as `f64`.
 //! Tuples are represented as `Vec<Value>` and empty values are not stored, but represented by Rust's unit type `()` where necessary.
 //!
@@ -447,10 +684,118 @@
 //!
 //! Values have a precedence of 200.
 //!
+//! ### Numeric Types
+//!
+//! The types of integers and floats are chosen with the type parameter of the [context](#contexts), which implements `EvalexprNumericTypes`.
+//! `DefaultNumericTypes` uses `i64` and `f64`, and integer arithmetic that overflows results in an error like `MultiplicationError`.
+//! Instead of an error, integer overflows can also wrap around, saturate or promote the operands to floats, as chosen with `HashMapContext::set_int_overflow_policy`, or for a single evaluation with `IntOverflowPolicyContext`.
+//! The crate ships the following alternatives:
+//!
+//! | Numeric types | Int | Float | Feature flag | Description |
+//! |---------------|-----|-------|--------------|-------------|
+//! | `I32F32NumericTypes` | `i32` | `f32` | - | 32-bit numbers, for targets where the data is 32-bit or 64-bit arithmetic is expensive |
+//! | `U64F64NumericTypes` | `u64` | `f64` | - | Unsigned integers. Negating a positive integer results in a `NegationError` |
+//! | `I128F64NumericTypes` | `i128` | `f64` | - | 128-bit integers, which overflow much later than `i64` |
+//! | `BigIntNumericTypes` | `BigInt` | `f64` | `bigint` | Arbitrary-precision integers that grow as needed. To bound the memory, shifting to the left by more than `BIGINT_MAXIMUM_LEFT_SHIFT` bits results in a `ShiftError`, products with more than `BIGINT_MAXIMUM_PRODUCT_BITS` bits result in a `MultiplicationError`, and arguments of `math::factorial` and `math::binomial` whose results could be larger, like `math::factorial(1000000)`, result in an `ArgumentOutOfRange` error. Not available together with `num_primitive` |
+//! | `DecimalNumericTypes` | `i64` | `Decimal` | `decimal` | Exact decimal floats with 28 significant digits, so `0.1 + 0.2 == 0.3`. Inexact quotients are rounded according to the `RoundingMode` of the context. Math functions whose results are undefined or too large, like `math::sqrt(-1.0)`, and literals that do not fit, like `1e30`, result in errors. Not available together with `num_primitive` |
+//! | `RationalNumericTypes` | `i64` | `Rational` | `rational` | Exact fractions of `i64`s. Dividing integers that are not divisible results in a fraction, so `1/3 + 1/6` evaluates to `1/2`. Fractions are displayed like `1/2`, or as decimals if a precision is given, like in `f"{x:.3}"`. Functions like `math::ln` are approximated, and result in an error if their result is undefined or out of the range of `i64`. Not available together with `num_primitive` |
+//! | `ComplexNumericTypes` | `i64` | `Complex` | `complex` | Complex numbers with imaginary literals like `3 + 4i`. The `math::*` functions return principal values, so `math::sqrt(-1)` evaluates to `1i`. π is written as `math::acos(-1)`, like in `math::exp(1i * math::acos(-1))`. Adds the builtin functions `re`, `im`, `arg`, `conj` and `abs`. Ordering complex numbers results in a `ComparisonError`, and dividing by zero in a `DivisionError`. Not available together with `num_primitive` |
+//! | `IntervalNumericTypes` | `i64` | `Interval` | `interval` | Closed intervals of `f64`s with outward rounding, so the result of an expression is guaranteed to contain the exact result for all values in its input intervals. Adds the builtin functions `interval`, `interval::around`, `interval::lo`, `interval::hi`, `interval::width` and `interval::midpoint`. Ordering overlapping intervals results in a `ComparisonError`. Not available together with `num_primitive` |
+//! | `DualNumericTypes` | `i64` | `Dual` | `dual` | Dual numbers for forward-mode automatic differentiation. Each float carries its gradient with respect to seed variables created with `Dual::variable`, so evaluating an expression also computes its partial derivatives. Floats are compared and displayed by their value. Not available together with `num_primitive` |
+//!
+//! ```rust
+//! # #[cfg(all(feature = "bigint", not(feature = "num_primitive")))] {
+//! use evalexpr::*;
+//!
+//! let context = HashMapContext::<BigIntNumericTypes>::new();
+//! assert_eq!(
+//!     eval_with_context("math::factorial(25)", &context).unwrap().to_string(),
+//!     "15511210043330985984000000"
+//! );
+//! # }
+//! ```
+//!
+//! ### Dates and Times
+//!
+//! With the feature flag `datetime`, values can also be points in time with an offset from UTC, as `Value::DateTime`, and signed amounts of time, as `Value::Duration`.
+//! They are based on the [chrono](https://docs.rs/chrono) crate, which is re-exported as `evalexpr::chrono`, and are created with the following builtin functions.
+//!
+//! | Identifier | Argument Amount | Argument Types | Description |
+//! |------------|-----------------|----------------|-------------|
+//! | `datetime` | 1 | String | Parses an ISO 8601 date and time like `2024-01-31T08:30:00+01:00`. Without an offset the time is in UTC, and a date without a time is at midnight |
+//! | `duration` | 1 | String | Parses an ISO 8601 duration like `P1W2DT3H4M5.5S`. Years and months are not supported, because their length is not fixed |
+//! | `datetime::format` | 1, 2 | DateTime or Duration, String | Formats a date and time or a duration in ISO 8601, or a date and time with the strftime pattern given as the second argument, like `"%d.%m.%Y"` |
+//! | `now` | 0 | Empty | Returns the current time of the clock of the context |
+//! | `weeks`, `days`, `hours`, `minutes`, `seconds`, `milliseconds` | 1 | Int | Returns a duration of the given amount of the unit |
+//! | `total_seconds` | 1 | Duration | Returns the amount of whole seconds of a duration |
+//! | `year`, `month`, `day`, `hour`, `minute`, `second` | 1 | DateTime | Returns the component of a date and time, in its own offset |
+//! | `weekday` | 1 | DateTime | Returns the day of the week, from 1 for Monday to 7 for Sunday |
+//!
+//! A duration can be added to or subtracted from a date and time, two dates and times can be subtracted to get the duration between them, and durations can be added and subtracted.
+//! Dates and times as well as durations can be compared with each other, and are displayed in ISO 8601 by `str::from`.
+//! The clock that `now` reads is chosen with `HashMapContext::set_clock`, such that evaluations can be made deterministic with `Clock::fixed`, and custom contexts implement `Context::now`.
+//!
+//! ```rust
+//! # #[cfg(feature = "datetime")] {
+//! use evalexpr::*;
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! context.set_clock(Clock::fixed(
+//!     chrono::DateTime::parse_from_rfc3339("2024-03-15T12:00:00Z").unwrap(),
+//! ));
+//! context.set_value("order_date".into(), eval("datetime(\"2024-02-20\")").unwrap()).unwrap();
+//!
+//! assert_eq!(eval_with_context("order_date + days(30) > now()", &context), Ok(Value::from(true)));
+//! assert_eq!(eval_with_context("str::from(now() - order_date)", &context), Ok(Value::from("PT2116800S")));
+//! assert_eq!(eval_with_context("weekday(order_date)", &context), Ok(Value::from_int(2)));
+//! # }
+//! ```
+//!
+//! ### Units of Measure
+//!
+//! With the feature flag `units`, floats can carry a unit of measure, as `Value::Quantity`, such that a formula like `distance / time` checks and combines the units of its arguments.
+//! A number directly followed by a unit symbol, like `5 m` or `3 s`, is a quantity, and longer units are written in square brackets, like `5[km/h]` or `9.81[m/s^2]`.
+//! Units consist of symbols joined with `*` and `/`, which may be raised to an integer power with `^`.
+//! A power directly after a unit symbol belongs to the unit, so `4 m^2` is four square metres, while `(4 m)^2` squares the quantity.
+//! The known symbols are the SI units `m`, `g`, `s`, `A`, `K`, `mol`, `cd`, `Hz`, `N`, `Pa`, `J`, `W`, `C`, `V` and `ohm` or `Ω`, as well as `L`, `Wh` and `bar`, all of which accept SI prefixes from `p` to `T`, like `km` or `kWh`.
+//! Without prefixes, `t`, `min`, `h`, `d`, `in`, `ft`, `yd`, `mi`, `nmi`, `gal`, `lb`, `oz`, `mph`, `kn` and `psi` are known as well.
+//! Since `in` is a keyword, inches are only written in square brackets, like `5[in]`.
+//! Temperatures are only supported in kelvin, because other temperature scales do not start at zero.
+//!
+//! Adding, subtracting or comparing quantities converts the second quantity to the unit of the first one, and fails with an `IncompatibleUnits` error if their units measure different dimensions, like in `5 m + 3 s`.
+//! Plain numbers have no dimension, so they can be multiplied with and divided by quantities, but not added to them.
+//! Multiplying, dividing and raising quantities to an integer power combines their units, and a result without dimension, like of `10 m / 4 m`, is a float.
+//!
+//! | Identifier | Argument Amount | Argument Types | Description |
+//! |------------|-----------------|----------------|-------------|
+//! | `convert` | 2 | Quantity, String | Converts a quantity to the given unit of the same dimension, like `convert(x, "mi")` |
+//! | `unit` | 1 | Quantity | Returns the unit of a quantity as string, like `"km/h"` |
+//! | `magnitude` | 1 | Quantity | Returns the amount of the unit of a quantity as float |
+//!
+//! Quantities are created in Rust with `Quantity::new` and a `Unit`, which is parsed from strings like `"km/h"`.
+//!
+//! ```rust
+//! # #[cfg(feature = "units")] {
+//! use evalexpr::*;
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! context.set_value("distance".into(), Quantity::new(42.0, "km".parse().unwrap()).into()).unwrap();
+//! context.set_value("time".into(), eval("3.5 h").unwrap()).unwrap();
+//!
+//! assert_eq!(eval_with_context("str::from(distance / time)", &context), Ok(Value::from("12 km/h")));
+//! assert_eq!(eval_with_context("convert(distance, \"mi\") < 26.2 mi", &context), Ok(Value::from(true)));
+//! assert!(matches!(eval_with_context("distance + time", &context),
+//!                  Err(EvalexprError::IncompatibleUnits { .. })));
+//! # }
+//! ```
+//!
 //! ### Variables
 //!
 //! This crate allows to compile parameterizable formulas by using variables.
-//! A variable is a literal in the formula, that does not contain whitespace or can be parsed as value.
+//! A variable is an identifier in the formula that cannot be parsed as value.
+//! Identifiers follow the Unicode rules for identifiers: they start with a letter or an underscore, followed by letters, digits and underscores, like `größe` or `_total_2`.
+//! Identifiers can be joined with `::` or `.`, like in the builtin function `math::ln` or in `order.total`.
+//! Literals that are neither a value nor a valid identifier, like `3a3` or `a.`, result in an `IllegalIdentifier` error.
 //! For working with variables, a [context](#contexts) is required.
 //! It stores the mappings from variables to their values.
 //!
@@ -469,6 +814,20 @@
 //! | `123` | no | Expression is interpreted as `Value::Int` |
 //! | `true` | no | Expression is interpreted as `Value::Bool` |
 //! | `.34` | no | Expression is interpreted as `Value::Float` |
+//! | `` `order total` `` | yes | Quoted identifier |
+//!
+//! To use any other name for a variable or function, like a column name `order total` or `price-eur`, quote it with backticks.
+//! Within backticks, identifiers may contain arbitrary characters, and escape sequences like `` \` `` work as in strings.
+//! Quoted identifiers are never interpreted as keywords, so `` `in` `` is a variable named `in`.
+//!
+//! ```rust
+//! use evalexpr::*;
+//!
+//! let mut context = HashMapContext::<DefaultNumericTypes>::new();
+//! context.set_value("order total".into(), Value::from_int(100)).unwrap();
+//! context.set_value("price-eur".into(), Value::from_int(20)).unwrap();
+//! assert_eq!(eval_with_context("`order total` - `price-eur`", &context), Ok(Value::from_int(80)));
+//! ```
 //!
 //! Variables have a precedence of 200.
 //!
@@ -581,7 +940,8 @@ pub use value::numeric_types::with_num::*;
 pub use crate::{
     context::{
         Context, ContextWithMutableFunctions, ContextWithMutableVariables, EmptyContext,
-        EmptyContextWithBuiltinFunctions, HashMapContext, IterateVariablesContext
+        EmptyContextWithBuiltinFunctions, HashMapContext, IntOverflowPolicyContext,
+        IterateVariablesContext, DEFAULT_LOOP_ITERATION_BUDGET
     },
     error::{EvalexprError, EvalexprResult},
     function::Function,
@@ -590,12 +950,45 @@ pub use crate::{
     token::PartialToken,
     tree::Node,
     value::{
-        numeric_types::{DefaultNumericTypes, EvalexprFloat, EvalexprInt, EvalexprNumericTypes},
+        format::{FormatAlignment, FormatSpec},
+        numeric_types::{
+            DefaultNumericTypes, EvalexprFloat, EvalexprInt, EvalexprNumericTypes,
+            I128F64NumericTypes, I32F32NumericTypes, IntOverflowPolicy, RoundingMode,
+            U64F64NumericTypes,
+        },
         value_type::ValueType,
         EmptyType, TupleType, Value, EMPTY_VALUE,
     },
 };
 
+#[cfg(all(feature = "bigint", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::{BigIntNumericTypes, BIGINT_MAXIMUM_LEFT_SHIFT, BIGINT_MAXIMUM_PRODUCT_BITS};
+
+#[cfg(all(feature = "decimal", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::DecimalNumericTypes;
+
+#[cfg(all(feature = "rational", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::{Rational, RationalNumericTypes};
+
+#[cfg(all(feature = "complex", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::{Complex, ComplexNumericTypes};
+
+#[cfg(all(feature = "interval", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::{Interval, IntervalNumericTypes};
+
+#[cfg(all(feature = "dual", not(feature = "num_primitive")))]
+pub use crate::value::numeric_types::{Dual, DualNumericTypes};
+
+#[cfg(feature = "datetime")]
+pub use crate::context::Clock;
+
+#[cfg(feature = "units")]
+pub use crate::value::units::{Quantity, Unit};
+
+/// The date and time library used by the feature `datetime`.
+#[cfg(feature = "datetime")]
+pub use chrono;
+
 #[cfg(feature = "num")]
 pub use crate::{
     context::ConvertibleContext,
@@ -603,9 +996,28 @@ pub use crate::{
 };
 
 /// The exports needed for num
-#[cfg(feature = "num")]
+#[cfg(any(
+    feature = "num",
+    feature = "bigint",
+    feature = "decimal",
+    feature = "rational",
+    feature = "complex"
+))]
 pub mod export {
+    #[cfg(feature = "num")]
     pub use num_traits as evalexpr_num;
+
+    #[cfg(feature = "bigint")]
+    pub use num_bigint as evalexpr_num_bigint;
+
+    #[cfg(feature = "decimal")]
+    pub use rust_decimal as evalexpr_rust_decimal;
+
+    #[cfg(feature = "rational")]
+    pub use num_rational as evalexpr_num_rational;
+
+    #[cfg(feature = "complex")]
+    pub use num_complex as evalexpr_num_complex;
 }
 
 mod context;
@@ -2,7 +2,11 @@
 
 use std::fmt::{Display, Error, Formatter};
 
-use crate::operator::*;
+use crate::{
+    operator::*,
+    token::write_identifier,
+    value::format::{write_format_string_interpolation, write_format_string_literal},
+};
 
 impl<NumericTypes: EvalexprNumericTypes> Display for Operator<NumericTypes> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
@@ -23,10 +27,34 @@ impl<NumericTypes: EvalexprNumericTypes> Display for Operator<NumericTypes> {
             Lt => write!(f, "<"),
             Geq => write!(f, ">="),
             Leq => write!(f, "<="),
+            ComparisonChain { operators } => {
+                for (index, operator) in operators.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", operator)?;
+                }
+                Ok(())
+            },
             And => write!(f, "&&"),
             Or => write!(f, "||"),
             Not => write!(f, "!"),
 
+            BitAnd => write!(f, "&"),
+            BitOr => write!(f, "|"),
+            BitXor => write!(f, " xor "),
+            BitNot => write!(f, "~"),
+            Shl => write!(f, "<<"),
+            Shr => write!(f, ">>"),
+
+            In => write!(f, " in "),
+            NotIn => write!(f, " not in "),
+            Range => write!(f, ".."),
+            RangeInclusive => write!(f, "..="),
+
+            Coalesce => write!(f, " ?? "),
+            Conditional => write!(f, " ? : "),
+
             Assign => write!(f, " = "),
             AddAssign => write!(f, " += "),
             SubAssign => write!(f, " -= "),
@@ -36,15 +64,36 @@ impl<NumericTypes: EvalexprNumericTypes> Display for Operator<NumericTypes> {
             ExpAssign => write!(f, " ^= "),
             AndAssign => write!(f, " &&= "),
             OrAssign => write!(f, " ||= "),
+            BitAndAssign => write!(f, " &= "),
+            BitOrAssign => write!(f, " |= "),
+            BitXorAssign => write!(f, " xor= "),
+            ShlAssign => write!(f, " <<= "),
+            ShrAssign => write!(f, " >>= "),
 
             Tuple => write!(f, ", "),
             Chain => write!(f, "; "),
+            Block => write!(f, "{{}}"),
 
-            Const { value } => write!(f, "{}", value),
-            VariableIdentifierWrite { identifier } | VariableIdentifierRead { identifier } => {
-                write!(f, "{}", identifier)
+            While => write!(f, "while "),
+            For => write!(f, "for "),
+            Break => write!(f, "break"),
+            Continue => write!(f, "continue"),
+            Let => write!(f, "let "),
+            FormatString { segments, specs } => {
+                write!(f, "f\"")?;
+                for (index, segment) in segments.iter().enumerate() {
+                    write_format_string_literal(f, segment)?;
+                    if let Some(spec) = specs.get(index) {
+                        write_format_string_interpolation(f, "", spec)?;
+                    }
+                }
+                write!(f, "\"")
             },
-            FunctionIdentifier { identifier } => write!(f, "{}", identifier),
+
+            Const { value } => write!(f, "{}", value),
+            VariableIdentifierWrite { identifier }
+            | VariableIdentifierRead { identifier }
+            | FunctionIdentifier { identifier } => write_identifier(f, identifier),
         }
     }
 }
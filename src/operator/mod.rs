@@ -1,14 +1,20 @@
 use cfg_if::cfg_if;
-use crate::function::builtin::builtin_function;
+use crate::function::builtin::{builtin_function, tuple_contains, ContextBuiltinFunction};
 
 use crate::value::numeric_types::{
-    DefaultNumericTypes, EvalexprNumericTypes,
+    DefaultNumericTypes, EvalexprInt, EvalexprNumericTypes, IntOverflowPolicy,
 };
-use crate::{context::Context, error::*, value::Value, ContextWithMutableVariables};
+use crate::{
+    context::Context, error::*, value::Value, ContextWithMutableVariables, FormatSpec, ValueType,
+};
+
+#[cfg(feature = "units")]
+use crate::value::units::Quantity;
 
 cfg_if! {
     if #[cfg(feature = "num_primitive")] {
         use num_traits::*;
+        use crate::value::numeric_types::EvalexprFloat;
     } else {
         use crate::value::numeric_types::*;
     }
@@ -50,6 +56,12 @@ pub enum Operator<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     Geq,
     /// A binary lower-than-or-equal comparator.
     Leq,
+    /// An n-ary chain of ordering comparators like `a < b <= c`.
+    /// It holds if each comparison between neighbouring arguments holds, and stops evaluating its arguments at the first one that does not.
+    ComparisonChain {
+        /// The comparators between neighbouring arguments, each one of `Gt`, `Lt`, `Geq` or `Leq`.
+        operators: Vec<Operator<NumericTypes>>,
+    },
     /// A binary logical and operator.
     And,
     /// A binary logical or operator.
@@ -57,6 +69,36 @@ pub enum Operator<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     /// A binary logical not operator.
     Not,
 
+    /// A binary bitwise and operator.
+    BitAnd,
+    /// A binary bitwise or operator.
+    BitOr,
+    /// A binary bitwise exclusive or operator.
+    BitXor,
+    /// A unary bitwise not operator.
+    BitNot,
+    /// A binary shift-left operator.
+    Shl,
+    /// A binary shift-right operator.
+    Shr,
+
+    /// A binary membership operator.
+    In,
+    /// A binary negated membership operator.
+    NotIn,
+    /// A binary operator that creates the tuple of integers from its first argument up to but excluding its second argument.
+    Range,
+    /// A binary operator that creates the tuple of integers from its first argument up to and including its second argument.
+    RangeInclusive,
+
+    /// A binary null-coalescing operator.
+    /// It evaluates to its first argument, unless that is empty or a variable that is not set, in which case it evaluates to its second argument.
+    Coalesce,
+    /// A ternary conditional operator.
+    /// It evaluates to its second argument if its first argument is true, and to its third argument otherwise.
+    /// Only the argument that is chosen is evaluated.
+    Conditional,
+
     /// A binary assignment operator.
     Assign,
     /// A binary add-assign operator.
@@ -75,11 +117,43 @@ pub enum Operator<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     AndAssign,
     /// A binary or-assign operator.
     OrAssign,
+    /// A binary bitwise-and-assign operator.
+    BitAndAssign,
+    /// A binary bitwise-or-assign operator.
+    BitOrAssign,
+    /// A binary bitwise-xor-assign operator.
+    BitXorAssign,
+    /// A binary shift-left-assign operator.
+    ShlAssign,
+    /// A binary shift-right-assign operator.
+    ShrAssign,
 
     /// An n-ary tuple constructor.
     Tuple,
     /// An n-ary subexpression chain.
     Chain,
+    /// A block of expressions in curly braces.
+    Block,
+
+    /// A `while` loop, whose arguments are the condition and the body block.
+    While,
+    /// A `for` loop, whose arguments are the header `identifier in expression` and the body block.
+    For,
+    /// A `break` out of the innermost loop.
+    Break,
+    /// A `continue` with the next iteration of the innermost loop.
+    Continue,
+
+    /// A `let` binding, whose argument is an assignment to the local variable.
+    Let,
+
+    /// A format string, whose arguments are the values interpolated into it.
+    FormatString {
+        /// The literal text before, between and after the interpolated values.
+        segments: Vec<String>,
+        /// The format specifications of the interpolated values.
+        specs: Vec<FormatSpec>,
+    },
 
     /// A constant value.
     Const {
@@ -132,18 +206,35 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
             Mul | Div | Mod => 100,
             Exp => 120,
 
-            Eq | Neq | Gt | Lt | Geq | Leq => 80,
+            Shl | Shr => 90,
+            BitNot => 110,
+
+            Range | RangeInclusive => 82,
+
+            Eq | Neq | Gt | Lt | Geq | Leq | ComparisonChain { .. } | In | NotIn => 80,
+            BitAnd => 79,
+            BitXor => 78,
+            BitOr => 77,
             And => 75,
             Or => 70,
             Not => 110,
 
+            Coalesce => 60,
+            Conditional => 55,
+
             Assign | AddAssign | SubAssign | MulAssign | DivAssign | ModAssign | ExpAssign
-            | AndAssign | OrAssign => 50,
+            | AndAssign | OrAssign | BitAndAssign | BitOrAssign | BitXorAssign | ShlAssign
+            | ShrAssign => 50,
 
             Tuple => 40,
             Chain => 0,
+            Block => 200,
 
-            Const { .. } => 200,
+            While | For => 190,
+            Break | Continue => 200,
+            Let => 45,
+
+            FormatString { .. } | Const { .. } => 200,
             VariableIdentifierWrite { .. } | VariableIdentifierRead { .. } => 200,
             FunctionIdentifier { .. } => 190,
         }
@@ -154,7 +245,16 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
     /// Left-to-right chaining has priority if operators with different order but same precedence are chained.
     pub(crate) const fn is_left_to_right(&self) -> bool {
         use crate::operator::Operator::*;
-        !matches!(self, Assign | FunctionIdentifier { .. })
+        !matches!(
+            self,
+            Assign | Coalesce | Conditional | FunctionIdentifier { .. }
+        )
+    }
+
+    /// Returns true if this operator is an ordering comparator that can be part of a comparison chain.
+    pub(crate) const fn is_ordering_comparison(&self) -> bool {
+        use crate::operator::Operator::*;
+        matches!(self, Gt | Lt | Geq | Leq)
     }
 
     /// Returns true if chains of this operator should be flattened into one operator with many arguments.
@@ -170,23 +270,33 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
     }
 
     /// Returns the maximum amount of arguments required by this operator.
-    pub(crate) const fn max_argument_amount(&self) -> Option<usize> {
+    pub(crate) fn max_argument_amount(&self) -> Option<usize> {
         use crate::operator::Operator::*;
         match self {
             Add | Sub | Mul | Div | Mod | Exp | Eq | Neq | Gt | Lt | Geq | Leq | And | Or
-            | Assign | AddAssign | SubAssign | MulAssign | DivAssign | ModAssign | ExpAssign
-            | AndAssign | OrAssign => Some(2),
+            | BitAnd | BitOr | BitXor | Shl | Shr | In | NotIn | Range | RangeInclusive
+            | Coalesce | Assign | AddAssign | SubAssign | MulAssign
+            | DivAssign | ModAssign | ExpAssign | AndAssign | OrAssign | BitAndAssign
+            | BitOrAssign | BitXorAssign | ShlAssign | ShrAssign => Some(2),
+            Conditional => Some(3),
+            While | For => Some(2),
+            ComparisonChain { operators } => Some(operators.len() + 1),
             Tuple | Chain => None,
-            Not | Neg | RootNode => Some(1),
-            Const { .. } => Some(0),
+            Not | Neg | BitNot | RootNode | Block | Let => Some(1),
+            Const { .. } | Break | Continue => Some(0),
             VariableIdentifierWrite { .. } | VariableIdentifierRead { .. } => Some(0),
             FunctionIdentifier { .. } => Some(1),
+            FormatString { specs, .. } => Some(specs.len()),
         }
     }
 
     /// Returns true if this operator is unary, i.e. it requires exactly one argument.
     pub(crate) fn is_unary(&self) -> bool {
-        self.max_argument_amount() == Some(1) && *self != Operator::RootNode
+        self.max_argument_amount() == Some(1)
+            && !matches!(
+                self,
+                Operator::RootNode | Operator::Block | Operator::FormatString { .. }
+            )
     }
 
     /// Evaluates the operator with the given arguments and context.
@@ -197,7 +307,7 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
     ) -> EvalexprResultValue<NumericTypes> {
         use crate::operator::Operator::*;
         match self {
-            RootNode => {
+            RootNode | Block => {
                 if let Some(first) = arguments.first() {
                     Ok(first.clone())
                 } else {
@@ -206,6 +316,14 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
             },
             Add => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
+                #[cfg(feature = "datetime")]
+                if let Some(result) = eval_datetime_arithmetic(self, &arguments[0], &arguments[1]) {
+                    return result;
+                }
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 expect_number_or_string(&arguments[0])?;
                 expect_number_or_string(&arguments[1])?;
 
@@ -215,23 +333,10 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
                     result.push_str(&b);
                     Ok(Value::String(result))
                 } else if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_add(&b) {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::addition_error(
-                                    Value::<NumericTypes>::from_int(a),
-                                    Value::<NumericTypes>::from_int(b),
-                                ))
-                            }
-                        } else {
-                            a.checked_add(&b).map(Value::Int)
-                        }
-                    }
+                    eval_int_arithmetic(self, a, b, context)
                 } else if let (Ok(a), Ok(b)) = (arguments[0].as_number(), arguments[1].as_number())
                 {
-                    Ok(Value::Float(a + b))
+                    a.checked_add(&b).map(Value::Float)
                 } else {
                     Err(EvalexprError::wrong_type_combination(
                         self.clone(),
@@ -244,91 +349,79 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
             },
             Sub => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
+                #[cfg(feature = "datetime")]
+                if let Some(result) = eval_datetime_arithmetic(self, &arguments[0], &arguments[1]) {
+                    return result;
+                }
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_sub(&b) {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::subtraction_error(
-                                    Value::<NumericTypes>::from_int(a),
-                                    Value::<NumericTypes>::from_int(b),
-                                ))
-                            }
-                        } else {
-                            a.checked_sub(&b).map(Value::Int)
-                        }
-                    }
+                    eval_int_arithmetic(self, a, b, context)
                 } else {
-                    Ok(Value::Float(
-                        arguments[0].as_number()? - arguments[1].as_number()?,
-                    ))
+                    arguments[0]
+                        .as_number()?
+                        .checked_sub(&arguments[1].as_number()?)
+                        .map(Value::Float)
                 }
             },
             Neg => {
                 expect_operator_argument_amount(arguments.len(), 1)?;
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 if let Ok(a) = arguments[0].as_int() {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_neg() {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::negation_error(
-                                    Value::<NumericTypes>::from_int(a)
-                                ))
-                            }
-                        } else {
-                            a.checked_neg().map(Value::Int)
-                        }
-                    }
+                    eval_int_arithmetic(self, a, NumericTypes::Int::from_usize(0)?, context)
                 } else {
                     Ok(Value::Float(-arguments[0].as_number()?))
                 }
             },
             Mul => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_mul(&b) {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::multiplication_error(
-                                    Value::<NumericTypes>::from_int(a),
-                                    Value::<NumericTypes>::from_int(b),
-                                ))
-                            }
-                        } else {
-                            a.checked_mul(&b).map(Value::Int)
-                        }
-                    }
+                    eval_int_arithmetic(self, a, b, context)
                 } else {
-                    Ok(Value::Float(
-                        arguments[0].as_number()? * arguments[1].as_number()?,
-                    ))
+                    arguments[0]
+                        .as_number()?
+                        .checked_mul(&arguments[1].as_number()?)
+                        .map(Value::Float)
                 }
             },
             Div => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_div(&b) {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::division_error(
+                    if NumericTypes::exact_int_division() {
+                        let quotient = NumericTypes::int_as_float(&a)
+                            .checked_div(&NumericTypes::int_as_float(&b), context.rounding_mode())
+                            .map_err(|_| {
+                                EvalexprError::division_error(
                                     Value::<NumericTypes>::from_int(a),
                                     Value::<NumericTypes>::from_int(b),
-                                ))
-                            }
+                                )
+                            })?;
+                        return if quotient.trunc() == quotient {
+                            Ok(Value::Int(NumericTypes::float_as_int(&quotient)))
                         } else {
-                            a.checked_div(&b).map(Value::Int)
-                        }
+                            Ok(Value::Float(quotient))
+                        };
                     }
+
+                    eval_int_arithmetic(self, a, b, context)
                 } else {
-                    Ok(Value::Float(
-                        arguments[0].as_number()? / arguments[1].as_number()?,
-                    ))
+                    arguments[0]
+                        .as_number()?
+                        .checked_div(&arguments[1].as_number()?, context.rounding_mode())
+                        .map(Value::Float)
                 }
             },
             Mod => {
@@ -337,28 +430,20 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
                 arguments[1].as_number()?;
 
                 if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    cfg_if! {
-                        if #[cfg(feature = "num_primitive")] {
-                            if let Some(value) = a.checked_rem(&b) {
-                                Ok(Value::Int(value))
-                            } else {
-                                Err(EvalexprError::modulation_error(
-                                    Value::<NumericTypes>::from_int(a),
-                                    Value::<NumericTypes>::from_int(b),
-                                ))
-                            }
-                        } else {
-                            a.checked_rem(&b).map(Value::Int)
-                        }
-                    }
+                    eval_int_arithmetic(self, a, b, context)
                 } else {
-                    Ok(Value::Float(
-                        arguments[0].as_number()? % arguments[1].as_number()?,
-                    ))
+                    arguments[0]
+                        .as_number()?
+                        .checked_rem(&arguments[1].as_number()?)
+                        .map(Value::Float)
                 }
             },
             Exp => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
+                #[cfg(feature = "units")]
+                if arguments.iter().any(Value::is_quantity) {
+                    return eval_quantity_arithmetic(self, arguments, context);
+                }
                 cfg_if! {
                     if #[cfg(feature = "num_primitive")] {
                         Ok(Value::Float(
@@ -366,7 +451,7 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
                         ))
                     } else {
                         Ok(Value::Float(
-                            arguments[0].as_number()?.pow(&arguments[1].as_number()?)
+                            arguments[0].as_number()?.checked_pow(&arguments[1].as_number()?)?
                         ))
                     }
                 }
@@ -381,65 +466,22 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
 
                 Ok(Value::Boolean(arguments[0] != arguments[1]))
             },
-            Gt => {
-                expect_operator_argument_amount(arguments.len(), 2)?;
-                expect_number_or_string(&arguments[0])?;
-                expect_number_or_string(&arguments[1])?;
-
-                if let (Ok(a), Ok(b)) = (arguments[0].as_string(), arguments[1].as_string()) {
-                    Ok(Value::Boolean(a > b))
-                } else if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    Ok(Value::Boolean(a > b))
-                } else {
-                    Ok(Value::Boolean(
-                        arguments[0].as_number()? > arguments[1].as_number()?,
-                    ))
-                }
-            },
-            Lt => {
+            Gt | Lt | Geq | Leq => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
-                expect_number_or_string(&arguments[0])?;
-                expect_number_or_string(&arguments[1])?;
 
-                if let (Ok(a), Ok(b)) = (arguments[0].as_string(), arguments[1].as_string()) {
-                    Ok(Value::Boolean(a < b))
-                } else if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    Ok(Value::Boolean(a < b))
-                } else {
-                    Ok(Value::Boolean(
-                        arguments[0].as_number()? < arguments[1].as_number()?,
-                    ))
-                }
+                Ok(Value::Boolean(
+                    self.eval_ordering(&arguments[0], &arguments[1])?,
+                ))
             },
-            Geq => {
-                expect_operator_argument_amount(arguments.len(), 2)?;
-                expect_number_or_string(&arguments[0])?;
-                expect_number_or_string(&arguments[1])?;
+            ComparisonChain { operators } => {
+                expect_operator_argument_amount(arguments.len(), operators.len() + 1)?;
 
-                if let (Ok(a), Ok(b)) = (arguments[0].as_string(), arguments[1].as_string()) {
-                    Ok(Value::Boolean(a >= b))
-                } else if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    Ok(Value::Boolean(a >= b))
-                } else {
-                    Ok(Value::Boolean(
-                        arguments[0].as_number()? >= arguments[1].as_number()?,
-                    ))
-                }
-            },
-            Leq => {
-                expect_operator_argument_amount(arguments.len(), 2)?;
-                expect_number_or_string(&arguments[0])?;
-                expect_number_or_string(&arguments[1])?;
-
-                if let (Ok(a), Ok(b)) = (arguments[0].as_string(), arguments[1].as_string()) {
-                    Ok(Value::Boolean(a <= b))
-                } else if let (Ok(a), Ok(b)) = (arguments[0].as_int(), arguments[1].as_int()) {
-                    Ok(Value::Boolean(a <= b))
-                } else {
-                    Ok(Value::Boolean(
-                        arguments[0].as_number()? <= arguments[1].as_number()?,
-                    ))
+                for (operator, pair) in operators.iter().zip(arguments.windows(2)) {
+                    if !operator.eval_ordering(&pair[0], &pair[1])? {
+                        return Ok(Value::Boolean(false));
+                    }
                 }
+                Ok(Value::Boolean(true))
             },
             And => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
@@ -461,8 +503,110 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
 
                 Ok(Value::Boolean(!a))
             },
+            BitAnd => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+                let a = arguments[0].as_int()?;
+                let b = arguments[1].as_int()?;
+
+                cfg_if! {
+                    if #[cfg(feature = "num_primitive")] {
+                        Ok(Value::Int(a & b))
+                    } else {
+                        Ok(Value::Int(a.bitand(&b)))
+                    }
+                }
+            },
+            BitOr => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+                let a = arguments[0].as_int()?;
+                let b = arguments[1].as_int()?;
+
+                cfg_if! {
+                    if #[cfg(feature = "num_primitive")] {
+                        Ok(Value::Int(a | b))
+                    } else {
+                        Ok(Value::Int(a.bitor(&b)))
+                    }
+                }
+            },
+            BitXor => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+                let a = arguments[0].as_int()?;
+                let b = arguments[1].as_int()?;
+
+                cfg_if! {
+                    if #[cfg(feature = "num_primitive")] {
+                        Ok(Value::Int(a ^ b))
+                    } else {
+                        Ok(Value::Int(a.bitxor(&b)))
+                    }
+                }
+            },
+            BitNot => {
+                expect_operator_argument_amount(arguments.len(), 1)?;
+                let a = arguments[0].as_int()?;
+
+                cfg_if! {
+                    if #[cfg(feature = "num_primitive")] {
+                        Ok(Value::Int(!a))
+                    } else {
+                        Ok(Value::Int(a.not()))
+                    }
+                }
+            },
+            Shl => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+                let a = arguments[0].as_int()?;
+                let b = arguments[1].as_int()?;
+
+                a.checked_shl(&b).map(Value::Int)
+            },
+            Shr => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+                let a = arguments[0].as_int()?;
+                let b = arguments[1].as_int()?;
+
+                a.checked_shr(&b).map(Value::Int)
+            },
+            In => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+
+                Ok(Value::Boolean(value_contains(&arguments[1], &arguments[0])?))
+            },
+            NotIn => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+
+                Ok(Value::Boolean(!value_contains(&arguments[1], &arguments[0])?))
+            },
+            Range | RangeInclusive => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+
+                // Ranges are evaluated by the operator tree, as their elements consume loop iterations.
+                Err(EvalexprError::CustomMessage(
+                    "Ranges can only be evaluated by the operator tree".to_string(),
+                ))
+            },
+            Coalesce => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+
+                if arguments[0].is_empty() {
+                    Ok(arguments[1].clone())
+                } else {
+                    Ok(arguments[0].clone())
+                }
+            },
+            Conditional => {
+                expect_operator_argument_amount(arguments.len(), 3)?;
+
+                if arguments[0].as_boolean()? {
+                    Ok(arguments[1].clone())
+                } else {
+                    Ok(arguments[2].clone())
+                }
+            },
             Assign | AddAssign | SubAssign | MulAssign | DivAssign | ModAssign | ExpAssign
-            | AndAssign | OrAssign => Err(EvalexprError::ContextNotMutable),
+            | AndAssign | OrAssign | BitAndAssign | BitOrAssign | BitXorAssign | ShlAssign
+            | ShrAssign => Err(EvalexprError::ContextNotMutable),
             Tuple => Ok(Value::Tuple(arguments.into())),
             Chain => {
                 if arguments.is_empty() {
@@ -471,6 +615,44 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
 
                 Ok(arguments.last().cloned().unwrap_or(Value::Empty))
             },
+            While | For => {
+                expect_operator_argument_amount(arguments.len(), 2)?;
+
+                // Loops are evaluated by the operator tree, as they evaluate their arguments repeatedly.
+                Err(EvalexprError::CustomMessage(
+                    "Loops can only be evaluated by the operator tree".to_string(),
+                ))
+            },
+            Break => {
+                expect_operator_argument_amount(arguments.len(), 0)?;
+
+                Err(EvalexprError::BreakOutsideOfLoop)
+            },
+            Continue => {
+                expect_operator_argument_amount(arguments.len(), 0)?;
+
+                Err(EvalexprError::ContinueOutsideOfLoop)
+            },
+            FormatString { segments, specs } => {
+                expect_operator_argument_amount(arguments.len(), specs.len())?;
+
+                let mut result = String::new();
+                for (index, segment) in segments.iter().enumerate() {
+                    result.push_str(segment);
+                    if let (Some(spec), Some(argument)) = (specs.get(index), arguments.get(index)) {
+                        result.push_str(&spec.format(argument, context.rounding_mode()));
+                    }
+                }
+                Ok(Value::String(result))
+            },
+            Let => {
+                expect_operator_argument_amount(arguments.len(), 1)?;
+
+                // Let bindings are evaluated by the operator tree, as they bind to its local scopes.
+                Err(EvalexprError::CustomMessage(
+                    "Let bindings can only be evaluated by the operator tree".to_string(),
+                ))
+            },
             Const { value } => {
                 expect_operator_argument_amount(arguments.len(), 0)?;
 
@@ -500,7 +682,9 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
                     Err(EvalexprError::FunctionIdentifierNotFound(_))
                         if !context.are_builtin_functions_disabled() =>
                     {
-                        if let Some(builtin_function) = builtin_function(identifier) {
+                        if let Some(function) = ContextBuiltinFunction::from_identifier(identifier) {
+                            function.call(arguments, context)
+                        } else if let Some(builtin_function) = builtin_function(identifier) {
                             builtin_function.call(arguments)
                         } else {
                             Err(EvalexprError::FunctionIdentifierNotFound(
@@ -514,6 +698,48 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
         }
     }
 
+    /// Evaluates the ordering comparator `self` on the given arguments.
+    /// Strings are compared lexicographically, and numbers as integers if both are integers, and as floats otherwise.
+    /// Dates and durations are compared chronologically, and quantities after converting them to the same unit.
+    pub(crate) fn eval_ordering(
+        &self,
+        a: &Value<NumericTypes>,
+        b: &Value<NumericTypes>,
+    ) -> EvalexprResult<bool, NumericTypes> {
+        use crate::operator::Operator::*;
+        let ordering = match (a, b) {
+            #[cfg(feature = "datetime")]
+            (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
+            #[cfg(feature = "datetime")]
+            (Value::Duration(a), Value::Duration(b)) => a.partial_cmp(b),
+            #[cfg(feature = "units")]
+            (Value::Quantity(_), _) | (_, Value::Quantity(_)) => {
+                Quantity::from_value(a)?.checked_partial_cmp(&Quantity::from_value(b)?)?
+            },
+            _ => {
+                expect_number_or_string(a)?;
+                expect_number_or_string(b)?;
+
+                if let (Ok(a), Ok(b)) = (a.as_string(), b.as_string()) {
+                    a.partial_cmp(&b)
+                } else if let (Ok(a), Ok(b)) = (a.as_int(), b.as_int()) {
+                    a.partial_cmp(&b)
+                } else {
+                    a.as_number()?.checked_partial_cmp(&b.as_number()?)?
+                }
+            },
+        };
+
+        // Incomparable values like NaN fail every ordering comparison.
+        Ok(ordering.map_or(false, |ordering| match self {
+            Gt => ordering.is_gt(),
+            Lt => ordering.is_lt(),
+            Geq => ordering.is_ge(),
+            Leq => ordering.is_le(),
+            _ => unreachable!("{} is not an ordering comparator", self),
+        }))
+    }
+
     /// Evaluates the operator with the given arguments and mutable context.
     pub(crate) fn eval_mut<
         C: ContextWithMutableVariables + Context<NumericTypes = NumericTypes>,
@@ -526,13 +752,14 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
         match self {
             Assign => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
-                let target = arguments[0].as_string()?;
-                context.set_value(target, arguments[1].clone())?;
+                assign_to_pattern(&arguments[0], arguments[1].clone(), &mut |target, value| {
+                    context.set_value(target, value)
+                })?;
 
                 Ok(Value::Empty)
             },
             AddAssign | SubAssign | MulAssign | DivAssign | ModAssign | ExpAssign | AndAssign
-            | OrAssign => {
+            | OrAssign | BitAndAssign | BitOrAssign | BitXorAssign | ShlAssign | ShrAssign => {
                 expect_operator_argument_amount(arguments.len(), 2)?;
 
                 let target = arguments[0].as_string()?;
@@ -551,6 +778,11 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
                     ExpAssign => Exp.eval(&arguments, context),
                     AndAssign => And.eval(&arguments, context),
                     OrAssign => Or.eval(&arguments, context),
+                    BitAndAssign => BitAnd.eval(&arguments, context),
+                    BitOrAssign => BitOr.eval(&arguments, context),
+                    BitXorAssign => BitXor.eval(&arguments, context),
+                    ShlAssign => Shl.eval(&arguments, context),
+                    ShrAssign => Shr.eval(&arguments, context),
                     _ => unreachable!(
                         "Forgot to add a match arm for an assign operation: {}",
                         self
@@ -564,3 +796,248 @@ impl<NumericTypes: EvalexprNumericTypes> Operator<NumericTypes> {
         }
     }
 }
+
+/// Returns true if `element` is an element of the tuple `collection`, or a substring of the string `collection`.
+fn value_contains<NumericTypes: EvalexprNumericTypes>(
+    collection: &Value<NumericTypes>,
+    element: &Value<NumericTypes>,
+) -> EvalexprResult<bool, NumericTypes> {
+    match collection {
+        Value::Tuple(tuple) => tuple_contains(tuple, element),
+        Value::String(string) => Ok(string.contains(element.as_string()?.as_str())),
+        _ => Err(EvalexprError::type_error(
+            collection.clone(),
+            vec![ValueType::Tuple, ValueType::String],
+        )),
+    }
+}
+
+/// Evaluates the operator `Add` or `Sub` if one of its arguments is a date or a duration, and returns `None` otherwise.
+/// A date plus or minus a duration is a date, the difference of two dates is a duration, and durations can be added and subtracted.
+#[cfg(feature = "datetime")]
+fn eval_datetime_arithmetic<NumericTypes: EvalexprNumericTypes>(
+    operator: &Operator<NumericTypes>,
+    a: &Value<NumericTypes>,
+    b: &Value<NumericTypes>,
+) -> Option<EvalexprResultValue<NumericTypes>> {
+    use crate::operator::Operator::*;
+    let result = match (operator, a, b) {
+        (Add, Value::DateTime(datetime), Value::Duration(duration))
+        | (Add, Value::Duration(duration), Value::DateTime(datetime)) => {
+            datetime.checked_add_signed(*duration).map(Value::DateTime)
+        },
+        (Add, Value::Duration(a), Value::Duration(b)) => a.checked_add(b).map(Value::Duration),
+        (Sub, Value::DateTime(datetime), Value::Duration(duration)) => {
+            datetime.checked_sub_signed(*duration).map(Value::DateTime)
+        },
+        (Sub, Value::DateTime(a), Value::DateTime(b)) => {
+            Some(Value::Duration(a.signed_duration_since(*b)))
+        },
+        (Sub, Value::Duration(a), Value::Duration(b)) => a.checked_sub(b).map(Value::Duration),
+        (_, Value::DateTime(_) | Value::Duration(_), _)
+        | (_, _, Value::DateTime(_) | Value::Duration(_)) => {
+            return Some(Err(EvalexprError::wrong_type_combination(
+                operator.clone(),
+                vec![a.into(), b.into()],
+            )));
+        },
+        _ => return None,
+    };
+
+    Some(result.ok_or_else(|| match operator {
+        Add => EvalexprError::addition_error(a.clone(), b.clone()),
+        _ => EvalexprError::subtraction_error(a.clone(), b.clone()),
+    }))
+}
+
+/// Evaluates the arithmetic `operator` if one of its arguments is a quantity, where plain numbers are quantities without dimension.
+/// Quantities are only added to and subtracted from quantities of the same dimension, while products, quotients and integer powers combine their units.
+#[cfg(feature = "units")]
+fn eval_quantity_arithmetic<NumericTypes: EvalexprNumericTypes, C: Context<NumericTypes = NumericTypes>>(
+    operator: &Operator<NumericTypes>,
+    arguments: &[Value<NumericTypes>],
+    context: &C,
+) -> EvalexprResultValue<NumericTypes> {
+    use crate::operator::Operator::*;
+    let a = Quantity::from_value(&arguments[0])?;
+    let result = match operator {
+        Neg => a.checked_neg(),
+        Exp => {
+            let exponent = arguments[1].as_int()?;
+            let exponent = exponent.to_string().parse().map_err(|_| EvalexprError::IntToNum {
+                int: exponent,
+                target_type: std::any::type_name::<i32>(),
+            })?;
+            a.checked_powi(exponent, context.rounding_mode())
+        },
+        _ => {
+            let b = Quantity::from_value(&arguments[1])?;
+            match operator {
+                Add => a.checked_add(&b),
+                Sub => a.checked_sub(&b),
+                Mul => a.checked_mul(&b),
+                Div => a.checked_div(&b, context.rounding_mode()),
+                _ => unreachable!("{operator:?} is not a quantity arithmetic operator"),
+            }
+        },
+    };
+    result?.into_value()
+}
+
+/// Evaluates the arithmetic `operator` on the integers `a` and `b`, where `b` is zero for negations.
+/// An overflow is handled according to the integer overflow policy of the context.
+fn eval_int_arithmetic<NumericTypes: EvalexprNumericTypes, C: Context<NumericTypes = NumericTypes>>(
+    operator: &Operator<NumericTypes>,
+    a: NumericTypes::Int,
+    b: NumericTypes::Int,
+    context: &C,
+) -> EvalexprResultValue<NumericTypes> {
+    use crate::operator::Operator::*;
+
+    // The checked operations of `num_traits` return an option instead of an error.
+    // Integers are `Copy` with `num_primitive`, so the error can take them by value.
+    macro_rules! checked {
+        ($result:expr, $error:expr) => {{
+            cfg_if! {
+                if #[cfg(feature = "num_primitive")] {
+                    $result.ok_or_else(|| $error)
+                } else {
+                    $result
+                }
+            }
+        }};
+    }
+
+    let result = match operator {
+        Add => checked!(
+            a.checked_add(&b),
+            EvalexprError::addition_error(Value::Int(a), Value::Int(b))
+        ),
+        Sub => checked!(
+            a.checked_sub(&b),
+            EvalexprError::subtraction_error(Value::Int(a), Value::Int(b))
+        ),
+        Mul => checked!(
+            a.checked_mul(&b),
+            EvalexprError::multiplication_error(Value::Int(a), Value::Int(b))
+        ),
+        Div => checked!(
+            a.checked_div(&b),
+            EvalexprError::division_error(Value::Int(a), Value::Int(b))
+        ),
+        Mod => checked!(
+            a.checked_rem(&b),
+            EvalexprError::modulation_error(Value::Int(a), Value::Int(b))
+        ),
+        Neg => checked!(
+            a.checked_neg(),
+            EvalexprError::negation_error(Value::Int(a))
+        ),
+        _ => unreachable!("{operator:?} is not an integer arithmetic operator"),
+    };
+    let error = match result {
+        Ok(int) => return Ok(Value::Int(int)),
+        Err(error) => error,
+    };
+
+    // Dividing by zero is not an overflow.
+    let zero = NumericTypes::Int::from_usize(0)?;
+    if matches!(operator, Div | Mod) && b == zero {
+        return Err(error);
+    }
+
+    match context.int_overflow_policy() {
+        IntOverflowPolicy::Error => Err(error),
+        IntOverflowPolicy::Wrap => match operator {
+            Add => EvalexprInt::wrapping_add(&a, &b),
+            Sub => EvalexprInt::wrapping_sub(&a, &b),
+            Mul => EvalexprInt::wrapping_mul(&a, &b),
+            Neg => EvalexprInt::wrapping_neg(&a),
+            // The only division that overflows is `MIN / -1`, which wraps around to `MIN`.
+            Div => Ok(a),
+            // The remainder of `MIN / -1` is zero.
+            _ => Ok(zero),
+        }
+        .map(Value::Int),
+        IntOverflowPolicy::Saturate => {
+            let is_positive = match operator {
+                Add => b > zero,
+                Sub => b < zero,
+                Mul | Div => (a > zero) == (b > zero),
+                Neg => a < zero,
+                _ => return Ok(Value::Int(zero)),
+            };
+            if is_positive {
+                Ok(Value::Int(NumericTypes::Int::max_value()))
+            } else {
+                Ok(Value::Int(NumericTypes::Int::min_value()))
+            }
+        },
+        IntOverflowPolicy::PromoteToFloat => {
+            let (a, b) = (
+                NumericTypes::int_as_float(&a),
+                NumericTypes::int_as_float(&b),
+            );
+            match operator {
+                Add => a.checked_add(&b),
+                Sub => a.checked_sub(&b),
+                Mul => a.checked_mul(&b),
+                Div => a.checked_div(&b, context.rounding_mode()),
+                Mod => a.checked_rem(&b),
+                _ => Ok(-a),
+            }
+            .map(Value::Float)
+        },
+    }
+}
+
+/// Returns an iterator over the integers from `start` up to `end`, which is included only if `inclusive` is true.
+pub(crate) fn int_range<NumericTypes: EvalexprNumericTypes>(
+    start: NumericTypes::Int,
+    end: NumericTypes::Int,
+    inclusive: bool,
+) -> EvalexprResult<impl Iterator<Item = NumericTypes::Int>, NumericTypes> {
+    let one = NumericTypes::Int::from_usize(1)?;
+    let mut next = Some(start).filter(|start| *start < end || (inclusive && *start == end));
+
+    Ok(std::iter::from_fn(move || {
+        let current = next.take()?;
+        if current < end {
+            // The successor cannot overflow, as it is at most `end`.
+            cfg_if! {
+                if #[cfg(feature = "num_primitive")] {
+                    let successor = Some(current + one);
+                } else {
+                    let successor = current.checked_add(&one).ok();
+                }
+            }
+            next = successor.filter(|successor| *successor < end || (inclusive && *successor == end));
+        }
+        Some(current)
+    }))
+}
+
+/// Assigns `value` to the variables of the evaluated assignment target `pattern`.
+/// The pattern is either the identifier of a variable, or a tuple of patterns that is assigned element-wise,
+/// in which case `value` must be a tuple of the same length.
+pub(crate) fn assign_to_pattern<NumericTypes: EvalexprNumericTypes>(
+    pattern: &Value<NumericTypes>,
+    value: Value<NumericTypes>,
+    assign: &mut impl FnMut(String, Value<NumericTypes>) -> EvalexprResult<(), NumericTypes>,
+) -> EvalexprResult<(), NumericTypes> {
+    match pattern {
+        Value::Tuple(patterns) => match value {
+            Value::Tuple(values) if values.len() == patterns.len() => {
+                for (pattern, value) in patterns.iter().zip(values) {
+                    assign_to_pattern(pattern, value, assign)?;
+                }
+                Ok(())
+            },
+            value => Err(EvalexprError::expected_fixed_len_tuple(
+                patterns.len(),
+                value,
+            )),
+        },
+        pattern => assign(pattern.as_string()?, value),
+    }
+}
@@ -3,8 +3,11 @@
 use std::fmt;
 
 use crate::{
-    token::{PartialToken, Token},
-    value::numeric_types::EvalexprNumericTypes,
+    token::{write_identifier, FormatStringPart, PartialToken, Token},
+    value::{
+        format::{write_format_string_interpolation, write_format_string_literal},
+        numeric_types::EvalexprNumericTypes,
+    },
 };
 
 impl<NumericTypes: EvalexprNumericTypes> fmt::Display for Token<NumericTypes> {
@@ -29,6 +32,38 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for Token<NumericTypes> {
             Or => write!(f, "||"),
             Not => write!(f, "!"),
 
+            // Bitwise
+            Ampersand => write!(f, "&"),
+            VerticalBar => write!(f, "|"),
+            Tilde => write!(f, "~"),
+            Xor => write!(f, "xor"),
+            Shl => write!(f, "<<"),
+            Shr => write!(f, ">>"),
+
+            // Membership
+            In => write!(f, "in"),
+            NotIn => write!(f, "not in"),
+            DotDot => write!(f, ".."),
+            DotDotEq => write!(f, "..="),
+
+            // Optional values
+            DoubleQuestionMark => write!(f, "??"),
+
+            // Conditional
+            QuestionMark => write!(f, "?"),
+            Colon => write!(f, ":"),
+
+            // Loops and blocks
+            While => write!(f, "while"),
+            For => write!(f, "for"),
+            Break => write!(f, "break"),
+            Continue => write!(f, "continue"),
+            LCurlyBrace => write!(f, "{{"),
+            RCurlyBrace => write!(f, "}}"),
+
+            // Local variables
+            Let => write!(f, "let"),
+
             // Precedence
             LBrace => write!(f, "("),
             RBrace => write!(f, ")"),
@@ -43,17 +78,38 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for Token<NumericTypes> {
             HatAssign => write!(f, "^="),
             AndAssign => write!(f, "&&="),
             OrAssign => write!(f, "||="),
+            AmpersandAssign => write!(f, "&="),
+            VerticalBarAssign => write!(f, "|="),
+            XorAssign => write!(f, "xor="),
+            ShlAssign => write!(f, "<<="),
+            ShrAssign => write!(f, ">>="),
 
             // Special
             Comma => write!(f, ","),
             Semicolon => write!(f, ";"),
 
             // Values => write!(f, ""), Variables and Functions
-            Identifier(identifier) => identifier.fmt(f),
+            Identifier(identifier) => write_identifier(f, identifier),
             Float(float) => float.fmt(f),
             Int(int) => int.fmt(f),
             Boolean(boolean) => boolean.fmt(f),
             String(string) => fmt::Debug::fmt(string, f),
+            FormatString(parts) => {
+                write!(f, "f\"")?;
+                for part in parts {
+                    match part {
+                        FormatStringPart::Literal(literal) => {
+                            write_format_string_literal(f, literal)?
+                        },
+                        FormatStringPart::Interpolation { expression, spec } => {
+                            write_format_string_interpolation(f, expression, spec)?
+                        },
+                    }
+                }
+                write!(f, "\"")
+            },
+            #[cfg(feature = "units")]
+            Quantity(quantity) => write!(f, "{}[{}]", quantity.value(), quantity.unit()),
         }
     }
 }
@@ -77,6 +133,9 @@ impl<NumericTypes: EvalexprNumericTypes> fmt::Display for PartialToken<NumericTy
             Lt => write!(f, "<"),
             Ampersand => write!(f, "&"),
             VerticalBar => write!(f, "|"),
+            QuestionMark => write!(f, "?"),
+            #[cfg(feature = "units")]
+            Unit(unit) => write!(f, "[{}]", unit),
         }
     }
 }
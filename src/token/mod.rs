@@ -1,10 +1,19 @@
-use std::str::FromStr;
+use std::{borrow::Cow, fmt, iter::Peekable, mem, str::Chars, str::FromStr};
+
+#[cfg(feature = "num_primitive")]
+use num_traits::Num;
 
 use crate::{
     error::{EvalexprError, EvalexprResult},
-    value::numeric_types::{DefaultNumericTypes, EvalexprInt, EvalexprNumericTypes},
+    value::{
+        format::FormatSpec,
+        numeric_types::{DefaultNumericTypes, EvalexprFloat, EvalexprInt, EvalexprNumericTypes},
+    },
 };
 
+#[cfg(feature = "units")]
+use crate::value::units::{Quantity, Unit};
+
 mod display;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -28,6 +37,38 @@ pub enum Token<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     Or,
     Not,
 
+    // Bitwise
+    Ampersand,
+    VerticalBar,
+    Tilde,
+    Xor,
+    Shl,
+    Shr,
+
+    // Membership
+    In,
+    NotIn,
+    DotDot,
+    DotDotEq,
+
+    // Optional values
+    DoubleQuestionMark,
+
+    // Conditional
+    QuestionMark,
+    Colon,
+
+    // Loops and blocks
+    While,
+    For,
+    Break,
+    Continue,
+    LCurlyBrace,
+    RCurlyBrace,
+
+    // Local variables
+    Let,
+
     // Precedence
     LBrace,
     RBrace,
@@ -42,6 +83,11 @@ pub enum Token<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     HatAssign,
     AndAssign,
     OrAssign,
+    AmpersandAssign,
+    VerticalBarAssign,
+    XorAssign,
+    ShlAssign,
+    ShrAssign,
 
     // Special
     Comma,
@@ -53,6 +99,23 @@ pub enum Token<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     Int(NumericTypes::Int),
     Boolean(bool),
     String(String),
+    FormatString(Vec<FormatStringPart>),
+    #[cfg(feature = "units")]
+    Quantity(Quantity<NumericTypes>),
+}
+
+/// A part of a format string literal like `f"{a} of {b:.2}"`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum FormatStringPart {
+    /// Literal text.
+    Literal(String),
+    /// An interpolated expression in curly braces.
+    Interpolation {
+        /// The source of the expression.
+        expression: String,
+        /// The format specification that follows the expression after a colon.
+        spec: FormatSpec,
+    },
 }
 
 /// A partial token is an input character whose meaning depends on the characters around it.
@@ -88,6 +151,11 @@ pub enum PartialToken<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes>
     Ampersand,
     /// A vertical bar character '|'.
     VerticalBar,
+    /// A question mark character '?'.
+    QuestionMark,
+    /// A unit of measure in square brackets like `[km/h]`, which is attached to the number before it.
+    #[cfg(feature = "units")]
+    Unit(String),
 }
 
 // Make this a const fn as soon as is_whitespace and to_string get stable (issue #57563)
@@ -104,6 +172,8 @@ fn char_to_partial_token<NumericTypes: EvalexprNumericTypes>(
 
         '(' => PartialToken::Token(Token::LBrace),
         ')' => PartialToken::Token(Token::RBrace),
+        '{' => PartialToken::Token(Token::LCurlyBrace),
+        '}' => PartialToken::Token(Token::RCurlyBrace),
 
         ',' => PartialToken::Token(Token::Comma),
         ';' => PartialToken::Token(Token::Semicolon),
@@ -114,6 +184,8 @@ fn char_to_partial_token<NumericTypes: EvalexprNumericTypes>(
         '<' => PartialToken::Lt,
         '&' => PartialToken::Ampersand,
         '|' => PartialToken::VerticalBar,
+        '~' => PartialToken::Token(Token::Tilde),
+        '?' => PartialToken::QuestionMark,
 
         c => {
             if c.is_whitespace() {
@@ -146,6 +218,32 @@ impl<NumericTypes: EvalexprNumericTypes> Token<NumericTypes> {
             Token::Or => false,
             Token::Not => false,
 
+            Token::Ampersand => false,
+            Token::VerticalBar => false,
+            Token::Tilde => false,
+            Token::Xor => false,
+            Token::Shl => false,
+            Token::Shr => false,
+
+            Token::In => false,
+            Token::NotIn => false,
+            Token::DotDot => false,
+            Token::DotDotEq => false,
+
+            Token::DoubleQuestionMark => false,
+
+            Token::QuestionMark => false,
+            Token::Colon => false,
+
+            Token::While => false,
+            Token::For => false,
+            Token::Break => true,
+            Token::Continue => true,
+            Token::LCurlyBrace => false,
+            Token::RCurlyBrace => false,
+
+            Token::Let => false,
+
             Token::LBrace => true,
             Token::RBrace => false,
 
@@ -161,12 +259,20 @@ impl<NumericTypes: EvalexprNumericTypes> Token<NumericTypes> {
             Token::HatAssign => false,
             Token::AndAssign => false,
             Token::OrAssign => false,
+            Token::AmpersandAssign => false,
+            Token::VerticalBarAssign => false,
+            Token::XorAssign => false,
+            Token::ShlAssign => false,
+            Token::ShrAssign => false,
 
             Token::Identifier(_) => true,
             Token::Float(_) => true,
             Token::Int(_) => true,
             Token::Boolean(_) => true,
             Token::String(_) => true,
+            Token::FormatString(_) => true,
+            #[cfg(feature = "units")]
+            Token::Quantity(_) => true,
         }
     }
 
@@ -190,6 +296,32 @@ impl<NumericTypes: EvalexprNumericTypes> Token<NumericTypes> {
             Token::Or => false,
             Token::Not => false,
 
+            Token::Ampersand => false,
+            Token::VerticalBar => false,
+            Token::Tilde => false,
+            Token::Xor => false,
+            Token::Shl => false,
+            Token::Shr => false,
+
+            Token::In => false,
+            Token::NotIn => false,
+            Token::DotDot => false,
+            Token::DotDotEq => false,
+
+            Token::DoubleQuestionMark => false,
+
+            Token::QuestionMark => false,
+            Token::Colon => false,
+
+            Token::While => false,
+            Token::For => false,
+            Token::Break => true,
+            Token::Continue => true,
+            Token::LCurlyBrace => false,
+            Token::RCurlyBrace => true,
+
+            Token::Let => false,
+
             Token::LBrace => false,
             Token::RBrace => true,
 
@@ -205,12 +337,20 @@ impl<NumericTypes: EvalexprNumericTypes> Token<NumericTypes> {
             Token::HatAssign => false,
             Token::AndAssign => false,
             Token::OrAssign => false,
+            Token::AmpersandAssign => false,
+            Token::VerticalBarAssign => false,
+            Token::XorAssign => false,
+            Token::ShlAssign => false,
+            Token::ShrAssign => false,
 
             Token::Identifier(_) => true,
             Token::Float(_) => true,
             Token::Int(_) => true,
             Token::Boolean(_) => true,
             Token::String(_) => true,
+            Token::FormatString(_) => true,
+            #[cfg(feature = "units")]
+            Token::Quantity(_) => true,
         }
     }
 
@@ -228,6 +368,11 @@ impl<NumericTypes: EvalexprNumericTypes> Token<NumericTypes> {
                 | HatAssign
                 | AndAssign
                 | OrAssign
+                | AmpersandAssign
+                | VerticalBarAssign
+                | XorAssign
+                | ShlAssign
+                | ShrAssign
         )
     }
 }
@@ -238,34 +383,281 @@ fn parse_escape_sequence<Iter: Iterator<Item = char>, NumericTypes: EvalexprNume
 ) -> EvalexprResult<char, NumericTypes> {
     match iter.next() {
         Some('"') => Ok('"'),
+        Some('\'') => Ok('\''),
+        Some('`') => Ok('`'),
         Some('\\') => Ok('\\'),
+        Some('u') => parse_unicode_escape_sequence(iter),
         Some(c) => Err(EvalexprError::IllegalEscapeSequence(format!("\\{}", c))),
         None => Err(EvalexprError::IllegalEscapeSequence("\\".to_string())),
     }
 }
 
+/// Parses a unicode escape sequence like `\u{1F600}` within a string literal, after its `\u`.
+/// The curly braces contain the code point of the character as one to six hexadecimal digits.
+fn parse_unicode_escape_sequence<
+    Iter: Iterator<Item = char>,
+    NumericTypes: EvalexprNumericTypes,
+>(
+    iter: &mut Iter,
+) -> EvalexprResult<char, NumericTypes> {
+    let mut sequence = String::from("\\u");
+    let mut digits = String::new();
+
+    loop {
+        let c = iter.next();
+        if let Some(c) = c {
+            sequence.push(c);
+        }
+        match c {
+            Some('{') if sequence.len() == 3 => {},
+            Some('}') if sequence.len() > 3 => break,
+            Some(c) if c.is_ascii_hexdigit() && sequence.len() > 3 && digits.len() < 6 => {
+                digits.push(c)
+            },
+            _ => return Err(EvalexprError::IllegalEscapeSequence(sequence)),
+        }
+    }
+
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or(EvalexprError::IllegalEscapeSequence(sequence))
+}
+
+/// Returns the error for a string literal that is opened by the given quote, but not closed.
+fn unmatched_quote_error<NumericTypes: EvalexprNumericTypes>(
+    quote: char,
+) -> EvalexprError<NumericTypes> {
+    if quote == '\'' {
+        EvalexprError::UnmatchedSingleQuote
+    } else {
+        EvalexprError::UnmatchedDoubleQuote
+    }
+}
+
 /// Parses a string value from the given character iterator.
 ///
 /// The first character from the iterator is interpreted as first character of the string.
-/// The string is terminated by a double quote `"`.
-/// Occurrences of `"` within the string can be escaped with `\`.
+/// The string is terminated by the given quote, which is either a double quote `"` or a single quote `'`.
+/// Occurrences of quotes within the string can be escaped with `\`.
 /// The backslash needs to be escaped with another backslash `\`.
 fn parse_string_literal<Iter: Iterator<Item = char>, NumericTypes: EvalexprNumericTypes>(
     mut iter: &mut Iter,
+    quote: char,
+) -> EvalexprResult<PartialToken<NumericTypes>, NumericTypes> {
+    let mut result = String::new();
+
+    while let Some(c) = iter.next() {
+        match c {
+            c if c == quote => return Ok(PartialToken::Token(Token::String(result))),
+            '\\' => result.push(parse_escape_sequence(&mut iter)?),
+            c => result.push(c),
+        }
+    }
+
+    Err(unmatched_quote_error(quote))
+}
+
+/// Parses a quoted identifier like `` `order total` `` from the given character iterator.
+///
+/// The first character from the iterator is interpreted as first character of the identifier, after the opening backtick.
+/// The identifier may contain arbitrary characters, including escape sequences like `` \` ``, and is terminated by a backtick.
+fn parse_quoted_identifier<Iter: Iterator<Item = char>, NumericTypes: EvalexprNumericTypes>(
+    mut iter: &mut Iter,
 ) -> EvalexprResult<PartialToken<NumericTypes>, NumericTypes> {
     let mut result = String::new();
 
     while let Some(c) = iter.next() {
         match c {
-            '"' => return Ok(PartialToken::Token(Token::String(result))),
+            '`' if result.is_empty() => {
+                return Err(EvalexprError::IllegalIdentifier("``".to_string()))
+            },
+            '`' => return Ok(PartialToken::Token(Token::Identifier(result))),
             '\\' => result.push(parse_escape_sequence(&mut iter)?),
             c => result.push(c),
         }
     }
 
+    Err(EvalexprError::UnmatchedBacktick)
+}
+
+/// Returns true if the given literal is a valid unquoted identifier.
+///
+/// Identifiers follow the Unicode rules for identifiers: they start with a character with the `XID_Start` property
+/// or an underscore, followed by characters with the `XID_Continue` property.
+/// Multiple such identifiers can be joined with `::` or `.`, like in `math::ln` or `order.total`.
+fn is_identifier(literal: &str) -> bool {
+    literal
+        .split("::")
+        .flat_map(|path| path.split('.'))
+        .all(|segment| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .map_or(false, |c| c == '_' || unicode_ident::is_xid_start(c))
+                && chars.all(unicode_ident::is_xid_continue)
+        })
+}
+
+/// Writes the given identifier, quoted with backticks if it would not be parsed as this identifier otherwise.
+pub(crate) fn write_identifier(f: &mut fmt::Formatter, identifier: &str) -> fmt::Result {
+    let is_reserved = matches!(
+        identifier,
+        "in" | "not" | "xor" | "while" | "for" | "break" | "continue" | "let" | "true" | "false"
+    );
+    if is_identifier(identifier) && !is_reserved {
+        write!(f, "{}", identifier)
+    } else {
+        write!(f, "`")?;
+        for c in identifier.chars() {
+            match c {
+                '`' | '\\' => write!(f, "\\{}", c)?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "`")
+    }
+}
+
+/// Converts the given literal into an identifier token, or returns an error if it is not a valid identifier.
+fn literal_to_identifier<NumericTypes: EvalexprNumericTypes>(
+    literal: String,
+) -> EvalexprResult<Token<NumericTypes>, NumericTypes> {
+    if is_identifier(&literal) {
+        Ok(Token::Identifier(literal))
+    } else {
+        Err(EvalexprError::IllegalIdentifier(literal))
+    }
+}
+
+/// Parses a raw string value like `r"\d+"` or `r#"say "hi""#` from the given character iterator.
+///
+/// The first character from the iterator is interpreted as first character of the string, after the opening quote.
+/// The string is terminated by a double quote `"` followed by as many hashes `#` as were between the `r` and the opening quote.
+/// Backslashes have no special meaning within raw strings.
+fn parse_raw_string_literal<NumericTypes: EvalexprNumericTypes>(
+    iter: &mut Peekable<Chars<'_>>,
+    hashes: usize,
+) -> EvalexprResult<PartialToken<NumericTypes>, NumericTypes> {
+    let mut result = String::new();
+
+    while let Some(c) = iter.next() {
+        if c == '"' {
+            let mut lookahead = iter.clone();
+            if (0..hashes).all(|_| lookahead.next() == Some('#')) {
+                *iter = lookahead;
+                return Ok(PartialToken::Token(Token::String(result)));
+            }
+        }
+        result.push(c);
+    }
+
     Err(EvalexprError::UnmatchedDoubleQuote)
 }
 
+/// Parses a format string value from the given character iterator.
+///
+/// The first character from the iterator is interpreted as first character of the string, after the opening `f"` or `f'`.
+/// The string is terminated by the given quote.
+/// Expressions in curly braces, optionally followed by a colon and a format specification, are interpolated into the string.
+/// Literal curly braces are written as `{{` and `}}`, and other characters are escaped like in string literals.
+fn parse_format_string_literal<NumericTypes: EvalexprNumericTypes>(
+    iter: &mut Peekable<Chars<'_>>,
+    quote: char,
+) -> EvalexprResult<PartialToken<NumericTypes>, NumericTypes> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+
+    while let Some(c) = iter.next() {
+        match c {
+            c if c == quote => {
+                if !literal.is_empty() {
+                    parts.push(FormatStringPart::Literal(literal));
+                }
+                return Ok(PartialToken::Token(Token::FormatString(parts)));
+            },
+            '\\' => literal.push(parse_escape_sequence(iter)?),
+            '{' | '}' if iter.peek() == Some(&c) => {
+                iter.next();
+                literal.push(c);
+            },
+            '{' => {
+                if !literal.is_empty() {
+                    parts.push(FormatStringPart::Literal(mem::take(&mut literal)));
+                }
+                parts.push(parse_interpolation(iter)?);
+            },
+            '}' => return Err(EvalexprError::UnmatchedRCurlyBrace),
+            c => literal.push(c),
+        }
+    }
+
+    Err(unmatched_quote_error(quote))
+}
+
+/// Parses an interpolated expression of a format string from the given character iterator,
+/// up to and including its closing curly brace.
+///
+/// The expression ends at a colon that starts the format specification, unless the colon is nested in parentheses or curly braces,
+/// or part of a double colon like in `math::ln`.
+fn parse_interpolation<NumericTypes: EvalexprNumericTypes>(
+    iter: &mut Peekable<Chars<'_>>,
+) -> EvalexprResult<FormatStringPart, NumericTypes> {
+    let mut expression = String::new();
+    let mut depth = 0usize;
+
+    while let Some(c) = iter.next() {
+        match c {
+            '(' | '{' => depth += 1,
+            ')' | '}' if depth > 0 => depth -= 1,
+            '}' => {
+                return Ok(FormatStringPart::Interpolation {
+                    expression,
+                    spec: FormatSpec::default(),
+                })
+            },
+            ':' if depth == 0 && iter.peek() != Some(&':') && !expression.ends_with(':') => {
+                let mut spec = String::new();
+                for c in iter.by_ref() {
+                    if c == '}' {
+                        return Ok(FormatStringPart::Interpolation {
+                            expression,
+                            spec: FormatSpec::parse(&spec)?,
+                        });
+                    }
+                    spec.push(c);
+                }
+                return Err(EvalexprError::UnmatchedLCurlyBrace);
+            },
+            '"' | '\'' => {
+                // String literals in the expression are copied verbatim, as they may contain braces and colons.
+                expression.push(c);
+                loop {
+                    match iter.next() {
+                        Some(closing) if closing == c => {
+                            expression.push(closing);
+                            break;
+                        },
+                        Some('\\') => {
+                            expression.push('\\');
+                            if let Some(escaped) = iter.next() {
+                                expression.push(escaped);
+                            }
+                        },
+                        Some(c) => expression.push(c),
+                        None => return Err(unmatched_quote_error(c)),
+                    }
+                }
+                continue;
+            },
+            _ => {},
+        }
+        expression.push(c);
+    }
+
+    Err(EvalexprError::UnmatchedLCurlyBrace)
+}
+
 fn try_skip_comment<NumericTypes: EvalexprNumericTypes>(
     iter: &mut std::iter::Peekable<std::str::Chars<'_>>,
 ) -> EvalexprResult<bool, NumericTypes> {
@@ -311,8 +703,49 @@ fn str_to_partial_tokens<NumericTypes: EvalexprNumericTypes>(
     let mut iter = string.chars().peekable();
 
     while let Some(c) = iter.next() {
-        if c == '"' {
-            result.push(parse_string_literal(&mut iter)?);
+        #[cfg(feature = "units")]
+        if c == '[' {
+            result.push(parse_unit(&mut iter)?);
+            continue;
+        }
+
+        if c == '"' || c == '\'' {
+            match result.last() {
+                // A string literal that directly follows an `f` is a format string.
+                Some(PartialToken::Literal(last)) if last == "f" => {
+                    result.pop();
+                    result.push(parse_format_string_literal(&mut iter, c)?);
+                },
+                // A double-quoted string literal that directly follows an `r` and any amount of hashes is a raw string.
+                Some(PartialToken::Literal(last))
+                    if c == '"'
+                        && last
+                            .strip_prefix('r')
+                            .map_or(false, |hashes| hashes.chars().all(|c| c == '#')) =>
+                {
+                    let hashes = last.len() - 1;
+                    result.pop();
+                    result.push(parse_raw_string_literal(&mut iter, hashes)?);
+                },
+                _ => result.push(parse_string_literal(&mut iter, c)?),
+            }
+        } else if c == '`' {
+            result.push(parse_quoted_identifier(&mut iter)?);
+        } else if c == '.' && iter.peek() == Some(&'.') {
+            // Two dots separate the bounds of a range, even if they are directly attached to number literals.
+            iter.next();
+            if iter.peek() == Some(&'=') {
+                iter.next();
+                result.push(PartialToken::Token(Token::DotDotEq));
+            } else {
+                result.push(PartialToken::Token(Token::DotDot));
+            }
+        } else if c == ':'
+            && iter.peek() != Some(&':')
+            && !matches!(result.last(), Some(PartialToken::Literal(last)) if last.ends_with(':'))
+        {
+            // A single colon separates the branches of a conditional, while double colons are part of identifiers like `math::ln`.
+            result.push(PartialToken::Token(Token::Colon));
         } else {
             let partial_token = char_to_partial_token(c);
 
@@ -351,7 +784,7 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
         let third = tokens.get(2).cloned();
         let mut cutoff = 2;
 
-        result.extend(match first {
+        let token = match first {
             PartialToken::Token(token) => {
                 cutoff = 1;
                 Some(token)
@@ -400,10 +833,47 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
             },
             PartialToken::Literal(literal) => {
                 cutoff = 1;
-                if let Ok(number) = parse_dec_or_hex::<NumericTypes>(&literal) {
+                if literal == "in" {
+                    Some(Token::In)
+                } else if literal == "while" {
+                    Some(Token::While)
+                } else if literal == "for" {
+                    Some(Token::For)
+                } else if literal == "break" {
+                    Some(Token::Break)
+                } else if literal == "continue" {
+                    Some(Token::Continue)
+                } else if literal == "let" {
+                    Some(Token::Let)
+                } else if literal == "not" {
+                    // `not` is only a keyword when followed by `in`, with any amount of whitespace in between.
+                    let next_non_whitespace = tokens[1..]
+                        .iter()
+                        .position(|token| *token != PartialToken::Whitespace)
+                        .map(|position| position + 1);
+                    match next_non_whitespace {
+                        Some(position)
+                            if position > 1
+                                && tokens[position] == PartialToken::Literal("in".to_string()) =>
+                        {
+                            cutoff = position + 1;
+                            Some(Token::NotIn)
+                        },
+                        _ => Some(Token::Identifier(literal.to_string())),
+                    }
+                } else if literal == "xor" {
+                    if let Some(PartialToken::Eq) = second {
+                        cutoff = 2;
+                        Some(Token::XorAssign)
+                    } else {
+                        Some(Token::Xor)
+                    }
+                } else if let Ok(number) = parse_int_literal::<NumericTypes>(&literal) {
                     Some(Token::Int(number))
-                } else if let Ok(number) = literal.parse::<NumericTypes::Float>() {
+                } else if let Ok(number) = parse_float_literal::<NumericTypes>(&literal) {
                     Some(Token::Float(number))
+                } else if is_float_syntax(&literal) {
+                    return Err(EvalexprError::LiteralOutOfRange(literal.to_string()));
                 } else if let Ok(boolean) = literal.parse::<bool>() {
                     Some(Token::Boolean(boolean))
                 } else {
@@ -415,16 +885,17 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
                         (Some(second), Some(third))
                             if second == PartialToken::Minus || second == PartialToken::Plus =>
                         {
-                            if let Ok(number) = format!("{}{}{}", literal, second, third)
-                                .parse::<NumericTypes::Float>()
-                            {
+                            let scientific = format!("{}{}{}", literal, second, third);
+                            if let Ok(number) = parse_float_literal::<NumericTypes>(&scientific) {
                                 cutoff = 3;
                                 Some(Token::Float(number))
+                            } else if is_float_syntax(&scientific) {
+                                return Err(EvalexprError::LiteralOutOfRange(scientific));
                             } else {
-                                Some(Token::Identifier(literal.to_string()))
+                                Some(literal_to_identifier(literal)?)
                             }
                         },
-                        _ => Some(Token::Identifier(literal.to_string())),
+                        _ => Some(literal_to_identifier(literal)?),
                     }
                 }
             },
@@ -448,6 +919,13 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
             },
             PartialToken::Gt => match second {
                 Some(PartialToken::Eq) => Some(Token::Geq),
+                Some(PartialToken::Gt) => match third {
+                    Some(PartialToken::Eq) => {
+                        cutoff = 3;
+                        Some(Token::ShrAssign)
+                    },
+                    _ => Some(Token::Shr),
+                },
                 _ => {
                     cutoff = 1;
                     Some(Token::Gt)
@@ -455,6 +933,13 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
             },
             PartialToken::Lt => match second {
                 Some(PartialToken::Eq) => Some(Token::Leq),
+                Some(PartialToken::Lt) => match third {
+                    Some(PartialToken::Eq) => {
+                        cutoff = 3;
+                        Some(Token::ShlAssign)
+                    },
+                    _ => Some(Token::Shl),
+                },
                 _ => {
                     cutoff = 1;
                     Some(Token::Lt)
@@ -468,7 +953,11 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
                     },
                     _ => Some(Token::And),
                 },
-                _ => return Err(EvalexprError::unmatched_partial_token(first, second)),
+                Some(PartialToken::Eq) => Some(Token::AmpersandAssign),
+                _ => {
+                    cutoff = 1;
+                    Some(Token::Ampersand)
+                },
             },
             PartialToken::VerticalBar => match second {
                 Some(PartialToken::VerticalBar) => match third {
@@ -478,10 +967,37 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
                     },
                     _ => Some(Token::Or),
                 },
-                _ => return Err(EvalexprError::unmatched_partial_token(first, second)),
+                Some(PartialToken::Eq) => Some(Token::VerticalBarAssign),
+                _ => {
+                    cutoff = 1;
+                    Some(Token::VerticalBar)
+                },
+            },
+            PartialToken::QuestionMark => match second {
+                Some(PartialToken::QuestionMark) => Some(Token::DoubleQuestionMark),
+                _ => {
+                    cutoff = 1;
+                    Some(Token::QuestionMark)
+                },
+            },
+            #[cfg(feature = "units")]
+            PartialToken::Unit(symbols) => {
+                cutoff = 1;
+                let unit = Unit::parse(&symbols)
+                    .ok_or_else(|| EvalexprError::UnknownUnit(symbols.clone()))?;
+                match result.pop().map(|number| attach_unit(number, unit)) {
+                    Some(Ok(quantity)) => Some(quantity),
+                    _ => {
+                        return Err(EvalexprError::UnmatchedPartialToken {
+                            first: PartialToken::Unit(symbols),
+                            second: None,
+                        })
+                    },
+                }
             },
-        });
+        };
 
+        result.extend(token);
         tokens = &tokens[cutoff..];
     }
     Ok(result)
@@ -490,19 +1006,149 @@ fn partial_tokens_to_tokens<NumericTypes: EvalexprNumericTypes>(
 pub(crate) fn tokenize<NumericTypes: EvalexprNumericTypes>(
     string: &str,
 ) -> EvalexprResult<Vec<Token<NumericTypes>>, NumericTypes> {
-    partial_tokens_to_tokens(&str_to_partial_tokens(string)?)
+    let tokens = partial_tokens_to_tokens(&str_to_partial_tokens(string)?)?;
+    #[cfg(feature = "units")]
+    let tokens = attach_unit_symbols(tokens)?;
+    Ok(tokens)
 }
 
-fn parse_dec_or_hex<NumericTypes: EvalexprNumericTypes>(
+/// Parses a unit of measure in square brackets from the given character iterator, where the opening bracket was already consumed.
+#[cfg(feature = "units")]
+fn parse_unit<NumericTypes: EvalexprNumericTypes>(
+    iter: &mut Peekable<Chars>,
+) -> EvalexprResult<PartialToken<NumericTypes>, NumericTypes> {
+    let mut unit = String::new();
+    for c in iter.by_ref() {
+        if c == ']' {
+            return Ok(PartialToken::Unit(unit));
+        }
+        unit.push(c);
+    }
+    Err(EvalexprError::UnknownUnit(format!("[{}", unit)))
+}
+
+/// Attaches a unit to a number token, or returns the token if it is not a number.
+#[cfg(feature = "units")]
+fn attach_unit<NumericTypes: EvalexprNumericTypes>(
+    number: Token<NumericTypes>,
+    unit: Unit,
+) -> Result<Token<NumericTypes>, Token<NumericTypes>> {
+    let value = match number {
+        Token::Int(int) => NumericTypes::int_as_float(&int),
+        Token::Float(float) => float,
+        token => return Err(token),
+    };
+    Ok(Token::Quantity(Quantity::new(value, unit)))
+}
+
+/// Attaches unit symbols to the numbers directly before them, like in `5 m`.
+/// An integer power directly after the symbol belongs to the unit, so `4 m^2` is four square metres.
+/// Otherwise, a number followed by an identifier is not a valid expression.
+#[cfg(feature = "units")]
+fn attach_unit_symbols<NumericTypes: EvalexprNumericTypes>(
+    tokens: Vec<Token<NumericTypes>>,
+) -> EvalexprResult<Vec<Token<NumericTypes>>, NumericTypes> {
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let unit = match &token {
+            Token::Identifier(symbol) => Unit::symbol(symbol),
+            _ => None,
+        };
+        match (unit, result.pop()) {
+            (Some(unit), Some(previous)) => match attach_unit(previous, unit) {
+                Ok(Token::Quantity(quantity)) if tokens.next_if_eq(&Token::Hat).is_some() => {
+                    result.push(raise_unit_symbol(quantity, &token, &mut tokens)?)
+                },
+                Ok(quantity) => result.push(quantity),
+                Err(previous) => result.extend([previous, token]),
+            },
+            (_, previous) => result.extend(previous.into_iter().chain([token])),
+        }
+    }
+    Ok(result)
+}
+
+/// Raises the unit of a quantity like `4 m` to the integer power that follows the `^` after its unit symbol.
+/// Returns an [`EvalexprError::UnknownUnit`] if the power is not an integer literal, like in `4 m^x`.
+#[cfg(feature = "units")]
+fn raise_unit_symbol<NumericTypes: EvalexprNumericTypes>(
+    quantity: Quantity<NumericTypes>,
+    symbol: &Token<NumericTypes>,
+    tokens: &mut Peekable<impl Iterator<Item = Token<NumericTypes>>>,
+) -> EvalexprResult<Token<NumericTypes>, NumericTypes> {
+    let negative = tokens.next_if_eq(&Token::Minus).is_some();
+    let exponent = tokens.next();
+    let unit = match &exponent {
+        Some(Token::Int(exponent)) => {
+            exponent
+                .to_string()
+                .parse::<i32>()
+                .ok()
+                .and_then(|exponent| {
+                    quantity
+                        .unit()
+                        .powi(if negative { -exponent } else { exponent })
+                })
+        },
+        _ => None,
+    };
+    match unit {
+        Some(unit) => {
+            let (value, _) = quantity.into_parts();
+            Ok(Token::Quantity(Quantity::new(value, unit)))
+        },
+        None => Err(EvalexprError::UnknownUnit(format!(
+            "{}^{}{}",
+            symbol,
+            if negative { "-" } else { "" },
+            exponent.map_or_else(String::new, |exponent| exponent.to_string())
+        ))),
+    }
+}
+
+/// Removes the `_` digit separators from a numeric literal.
+///
+/// Literals that do not start with a digit are returned unchanged, such that identifiers like `_a` stay intact.
+fn remove_digit_separators(literal: &str) -> Cow<'_, str> {
+    if literal.starts_with(|c: char| c.is_ascii_digit()) && literal.contains('_') {
+        Cow::Owned(literal.replace('_', ""))
+    } else {
+        Cow::Borrowed(literal)
+    }
+}
+
+/// Parses a decimal integer literal, or a hexadecimal, binary or octal one if it is prefixed with `0x`, `0b` or `0o`.
+fn parse_int_literal<NumericTypes: EvalexprNumericTypes>(
     literal: &str,
 ) -> Result<NumericTypes::Int, ()> {
+    let literal = remove_digit_separators(literal);
     if let Some(literal) = literal.strip_prefix("0x") {
         NumericTypes::Int::from_hex_str(literal)
+    } else if let Some(literal) = literal.strip_prefix("0b") {
+        NumericTypes::Int::from_str_radix(literal, 2).map_err(|_| ())
+    } else if let Some(literal) = literal.strip_prefix("0o") {
+        NumericTypes::Int::from_str_radix(literal, 8).map_err(|_| ())
     } else {
-        NumericTypes::Int::from_str(literal).map_err(|_| ())
+        NumericTypes::Int::from_str(&literal).map_err(|_| ())
     }
 }
 
+/// Parses a float literal, which may be given in scientific notation like `6.02e23`.
+fn parse_float_literal<NumericTypes: EvalexprNumericTypes>(
+    literal: &str,
+) -> Result<NumericTypes::Float, ()> {
+    NumericTypes::Float::from_literal(&remove_digit_separators(literal))
+}
+
+/// Returns true if the given literal has the syntax of a float literal, like `1e30`.
+///
+/// A literal with this syntax that cannot be parsed by the float type is out of range for it.
+fn is_float_syntax(literal: &str) -> bool {
+    literal.starts_with(|c: char| c.is_ascii_digit())
+        && remove_digit_separators(literal).parse::<f64>().is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -514,7 +1160,8 @@ mod tests {
     #[test]
     fn test_partial_token_display() {
         let chars = vec![
-            '+', '-', '*', '/', '%', '^', '(', ')', ',', ';', '=', '!', '>', '<', '&', '|', ' ',
+            '+', '-', '*', '/', '%', '^', '(', ')', ',', ';', '=', '!', '>', '<', '&', '|', '~',
+            ' ',
         ];
 
         for char in chars {
@@ -527,8 +1174,7 @@ mod tests {
 
     #[test]
     fn test_token_display() {
-        let token_string =
-            "+ - * / % ^ == != > < >= <= && || ! ( ) = += -= *= /= %= ^= &&= ||= , ; ";
+        let token_string = "+ - * / % ^ == != > < >= <= && || ! & | ~ xor << >> in not in .. ..= ( ) = += -= *= /= %= ^= &&= ||= &= |= xor= <<= >>= , ; ";
         let tokens = tokenize::<DefaultNumericTypes>(token_string).unwrap();
         let mut result_string = String::new();
 
@@ -1,6 +1,6 @@
 use crate::{
     error::EvalexprResultValue,
-    token::Token,
+    token::{tokenize, FormatStringPart, Token},
     value::{
         numeric_types::{DefaultNumericTypes, EvalexprNumericTypes},
         TupleType, EMPTY_VALUE,
@@ -9,11 +9,13 @@ use crate::{
 };
 
 use crate::{
+    context::ScopedContext,
+    function::builtin::ContextBuiltinFunction,
     error::{EvalexprError, EvalexprResult},
     operator::*,
-    value::Value,
+    value::{value_type::ValueType, Value},
 };
-use std::mem;
+use std::{cell::Cell, mem};
 
 // Exclude display module from coverage, as it prints not well-defined prefix notation.
 #[cfg(not(tarpaulin_include))]
@@ -321,6 +323,361 @@ impl<NumericTypes: EvalexprNumericTypes> Node<NumericTypes> {
             })
     }
 
+    /// If this node is a range literal like `1..10`, possibly surrounded by parentheses,
+    /// returns the nodes of its bounds and whether the upper bound is included.
+    fn as_range(&self) -> Option<(&Self, &Self, bool)> {
+        let mut range = self;
+        while range.operator() == &Operator::RootNode && range.children().len() == 1 {
+            range = &range.children()[0];
+        }
+        let inclusive = match range.operator() {
+            Operator::Range => false,
+            Operator::RangeInclusive => true,
+            _ => return None,
+        };
+        let [start, end] = range.children() else {
+            return None;
+        };
+
+        Some((start, end, inclusive))
+    }
+
+    /// If this node is a membership test against a range literal like `x in 1..10`,
+    /// evaluates it by comparing against the bounds of the range instead of creating the tuple of all its elements.
+    ///
+    /// Returns `None` if this node is not such a membership test.
+    fn eval_range_membership(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let negated = match self.operator() {
+            Operator::In => false,
+            Operator::NotIn => true,
+            _ => return None,
+        };
+        let [element, range] = self.children() else {
+            return None;
+        };
+        let (start, end, inclusive) = range.as_range()?;
+
+        Some((|| {
+            let element = eval_child(element)?;
+            let start = eval_child(start)?.as_int()?;
+            let end = eval_child(end)?.as_int()?;
+
+            // Elements are compared like in a tuple, so only integers can be contained in a range.
+            let contained = match &element {
+                Value::Int(int) => start <= *int && (*int < end || (inclusive && *int == end)),
+                Value::String(_) | Value::Float(_) | Value::Boolean(_) => false,
+                _ => {
+                    return Err(EvalexprError::type_error(
+                        element,
+                        vec![
+                            ValueType::String,
+                            ValueType::Int,
+                            ValueType::Float,
+                            ValueType::Boolean,
+                        ],
+                    ))
+                },
+            };
+            Ok(Value::Boolean(contained != negated))
+        })())
+    }
+
+    /// If this node is a comparison chain like `a < b <= c`, evaluates its arguments from left to right,
+    /// stopping at the first comparison that does not hold.
+    ///
+    /// Returns `None` if this node is not a complete comparison chain.
+    fn eval_comparison_chain(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let Operator::ComparisonChain { operators } = self.operator() else {
+            return None;
+        };
+        if self.children().len() != operators.len() + 1 {
+            return None;
+        }
+        let (first, rest) = self.children().split_first()?;
+
+        Some((|| {
+            let mut left = eval_child(first)?;
+            for (operator, child) in operators.iter().zip(rest) {
+                let right = eval_child(child)?;
+                if !operator.eval_ordering(&left, &right)? {
+                    return Ok(Value::Boolean(false));
+                }
+                left = right;
+            }
+            Ok(Value::Boolean(true))
+        })())
+    }
+
+    /// Returns true if this node only reads the variable with the given identifier, possibly surrounded by parentheses.
+    fn is_variable_read(&self, identifier: &str) -> bool {
+        match self.operator() {
+            Operator::VariableIdentifierRead {
+                identifier: read_identifier,
+            } => read_identifier == identifier,
+            Operator::RootNode => {
+                matches!(self.children(), [child] if child.is_variable_read(identifier))
+            },
+            _ => false,
+        }
+    }
+
+    /// Converts the error of reading a variable that is not set into `Value::Empty`, if this node only reads that variable.
+    fn unset_variable_as_empty(
+        &self,
+        result: EvalexprResultValue<NumericTypes>,
+    ) -> EvalexprResultValue<NumericTypes> {
+        match result {
+            Err(EvalexprError::VariableIdentifierNotFound(identifier))
+                if self.is_variable_read(&identifier) =>
+            {
+                Ok(Value::Empty)
+            },
+            result => result,
+        }
+    }
+
+    /// If this node is a null-coalescing operator like `a ?? b`, evaluates its second argument only if the first one is empty or a variable that is not set.
+    ///
+    /// Returns `None` if this node is not a complete null-coalescing operator.
+    fn eval_coalesce(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let [value, fallback] = self.children() else {
+            return None;
+        };
+
+        Some(match value.unset_variable_as_empty(eval_child(value)) {
+            Ok(Value::Empty) => eval_child(fallback),
+            result => result,
+        })
+    }
+
+    /// If this node is a conditional like `c ? a : b`, evaluates only the branch that is chosen by the condition.
+    ///
+    /// Returns `None` if this node is not a complete conditional.
+    fn eval_conditional(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let [condition, if_true, if_false] = self.children() else {
+            return None;
+        };
+
+        Some(
+            match eval_child(condition).and_then(|condition| condition.as_boolean()) {
+                Ok(true) => eval_child(if_true),
+                Ok(false) => eval_child(if_false),
+                Err(error) => Err(error),
+            },
+        )
+    }
+
+    /// Returns true if this node calls a builtin function like `is_set` that receives a variable that is not set as `Value::Empty`.
+    /// This is not the case if builtin functions are disabled, or if the context links a function with the same identifier.
+    fn receives_unset_variables<C: Context<NumericTypes = NumericTypes>>(&self, context: &C) -> bool {
+        match self.operator() {
+            Operator::FunctionIdentifier { identifier } => {
+                !context.are_builtin_functions_disabled()
+                    && !context.has_function(identifier)
+                    && matches!(
+                        ContextBuiltinFunction::from_identifier(identifier),
+                        Some(function) if function.receives_unset_variables()
+                    )
+            },
+            _ => false,
+        }
+    }
+
+    /// If this node is a while loop, evaluates its body as long as its condition is true.
+    ///
+    /// Returns `None` if this node is not a complete while loop.
+    fn eval_while(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let [condition, body] = self.children() else {
+            return None;
+        };
+
+        Some((|| {
+            while eval_child(condition)?.as_boolean()? {
+                consume_loop_iteration(loop_iteration_budget)?;
+                if !continue_loop_after(eval_child(body))? {
+                    break;
+                }
+            }
+            Ok(Value::Empty)
+        })())
+    }
+
+    /// If this node is a range literal like `1..10`, evaluates it to the tuple of all its elements.
+    /// Each element consumes one loop iteration, so large ranges cannot exhaust the memory.
+    ///
+    /// Returns `None` if this node is not a complete range.
+    fn eval_range(
+        &self,
+        mut eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        let (start, end, inclusive) = self.as_range()?;
+
+        Some((|| {
+            let start = eval_child(start)?.as_int()?;
+            let end = eval_child(end)?.as_int()?;
+            int_range(start, end, inclusive)?
+                .map(|element| {
+                    consume_loop_iteration(loop_iteration_budget)?;
+                    Ok(Value::Int(element))
+                })
+                .collect::<EvalexprResult<_, _>>()
+                .map(Value::Tuple)
+        })())
+    }
+
+    /// If this node is a for loop, evaluates its body once for each element of the iterated tuple or empty value,
+    /// with the element bound to the variable of the loop in a scope of its own.
+    /// Ranges like `1..10` are iterated without creating the tuple of all their elements.
+    ///
+    /// Returns `None` if this node is not a complete for loop.
+    fn eval_for_mut<C: ContextWithMutableVariables + Context<NumericTypes = NumericTypes>>(
+        &self,
+        context: &mut ScopedContext<&mut C>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        if self.operator() != &Operator::For {
+            return None;
+        }
+        let [header, body] = self.children() else {
+            return None;
+        };
+        let [membership] = header.children() else {
+            return None;
+        };
+        let [variable, iterable] = membership.children() else {
+            return None;
+        };
+        let Operator::VariableIdentifierWrite { identifier } = variable.operator() else {
+            return Some(Err(EvalexprError::MalformedForLoopHeader));
+        };
+
+        Some((|| {
+            let elements: Box<dyn Iterator<Item = Value<NumericTypes>>> =
+                if let Some((start, end, inclusive)) = iterable.as_range() {
+                    let start = start
+                        .eval_with_scoped_context_mut(context, loop_iteration_budget)?
+                        .as_int()?;
+                    let end = end
+                        .eval_with_scoped_context_mut(context, loop_iteration_budget)?
+                        .as_int()?;
+                    Box::new(int_range(start, end, inclusive)?.map(Value::Int))
+                } else {
+                    // Like the empty tuple, an empty value has no elements.
+                    match iterable.eval_with_scoped_context_mut(context, loop_iteration_budget)? {
+                        Value::Empty => Box::new(std::iter::empty()),
+                        value => Box::new(value.as_tuple()?.into_iter()),
+                    }
+                };
+
+            // The variable of the loop is local to the loop, so it may take values of different types.
+            context.push_scope();
+            let result = (|| {
+                for element in elements {
+                    consume_loop_iteration(loop_iteration_budget)?;
+                    context.bind(identifier.clone(), element);
+                    if !continue_loop_after(
+                        body.eval_with_scoped_context_mut(context, loop_iteration_budget),
+                    )? {
+                        break;
+                    }
+                }
+                Ok(Value::Empty)
+            })();
+            context.pop_scope();
+            result
+        })())
+    }
+
+    /// Evaluates this node without evaluating all its children first, if its operator requires that.
+    ///
+    /// Returns `None` if this node should be evaluated by evaluating all its children and then its operator.
+    fn eval_lazily(
+        &self,
+        eval_child: impl FnMut(&Self) -> EvalexprResultValue<NumericTypes>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> Option<EvalexprResultValue<NumericTypes>> {
+        match self.operator() {
+            Operator::In | Operator::NotIn => self.eval_range_membership(eval_child),
+            Operator::ComparisonChain { .. } => self.eval_comparison_chain(eval_child),
+            Operator::Coalesce => self.eval_coalesce(eval_child),
+            Operator::Conditional => self.eval_conditional(eval_child),
+            Operator::Range | Operator::RangeInclusive => {
+                self.eval_range(eval_child, loop_iteration_budget)
+            },
+            Operator::While => self.eval_while(eval_child, loop_iteration_budget),
+            // For loops are evaluated by `eval_for_mut`, so like assignments they require a mutable context.
+            Operator::For if self.children().len() == 2 => {
+                Some(Err(EvalexprError::ContextNotMutable))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns true if this node is a variable, or a tuple of variables and such tuples, possibly in parentheses.
+    /// Such tuple patterns can be assigned to element-wise.
+    fn is_tuple_pattern(&self) -> bool {
+        match self.operator() {
+            Operator::VariableIdentifierRead { .. } | Operator::VariableIdentifierWrite { .. } => {
+                true
+            },
+            Operator::RootNode => matches!(self.children(), [child] if child.is_tuple_pattern()),
+            Operator::Tuple => self.children().iter().all(Self::is_tuple_pattern),
+            _ => false,
+        }
+    }
+
+    /// Marks all variables in this tuple pattern as being written to.
+    fn mark_as_written(&mut self) {
+        if let Operator::VariableIdentifierRead { identifier } = &self.operator {
+            self.operator = Operator::variable_identifier_write(identifier.clone());
+        }
+        for child in &mut self.children {
+            child.mark_as_written();
+        }
+    }
+
+    /// If this node is a `let` binding, returns the node of the bound variable or tuple pattern and the node of its value.
+    ///
+    /// Returns `None` if this node is not a `let` binding.
+    fn let_binding(&self) -> Option<EvalexprResult<(&Self, &Self), NumericTypes>> {
+        if self.operator() != &Operator::Let {
+            return None;
+        }
+        let [assignment] = self.children() else {
+            return Some(Err(EvalexprError::MalformedLetBinding));
+        };
+        let (Operator::Assign, [pattern, value]) = (assignment.operator(), assignment.children())
+        else {
+            return Some(Err(EvalexprError::MalformedLetBinding));
+        };
+        if !pattern.is_tuple_pattern() {
+            return Some(Err(EvalexprError::MalformedLetBinding));
+        }
+        Some(Ok((pattern, value)))
+    }
+
+    /// Returns true if the local variables bound within this node should be dropped after evaluating it.
+    fn opens_scope(&self) -> bool {
+        matches!(self.operator(), Operator::Chain | Operator::Block)
+    }
+
     /// Evaluates the operator tree rooted at this node with the given context.
     ///
     /// Fails, if one of the operators in the expression tree fails.
@@ -328,11 +685,56 @@ impl<NumericTypes: EvalexprNumericTypes> Node<NumericTypes> {
         &self,
         context: &C,
     ) -> EvalexprResultValue<NumericTypes> {
-        let mut arguments = Vec::new();
-        for child in self.children() {
-            arguments.push(child.eval_with_context(context)?);
+        let loop_iteration_budget = Cell::new(context.loop_iteration_budget());
+        self.eval_with_scoped_context(&mut ScopedContext::new(context), &loop_iteration_budget)
+    }
+
+    /// Evaluates the operator tree rooted at this node with the given context and the local variables bound so far,
+    /// performing at most `loop_iteration_budget` loop iterations.
+    fn eval_with_scoped_context<C: Context<NumericTypes = NumericTypes>>(
+        &self,
+        context: &mut ScopedContext<&C>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> EvalexprResultValue<NumericTypes> {
+        if let Some(binding) = self.let_binding() {
+            let (pattern, value) = binding?;
+            let pattern = pattern.eval_with_scoped_context(context, loop_iteration_budget)?;
+            let value = value.eval_with_scoped_context(context, loop_iteration_budget)?;
+            assign_to_pattern(&pattern, value, &mut |identifier, value| {
+                context.bind(identifier, value);
+                Ok(())
+            })?;
+            return Ok(Value::Empty);
+        }
+
+        let opens_scope = self.opens_scope();
+        if opens_scope {
+            context.push_scope();
         }
-        self.operator().eval(&arguments, context)
+        let result = (|| {
+            if let Some(result) = self.eval_lazily(
+                |child| child.eval_with_scoped_context(context, loop_iteration_budget),
+                loop_iteration_budget,
+            ) {
+                return result;
+            }
+
+            let receives_unset_variables = self.receives_unset_variables(&*context);
+            let mut arguments = Vec::new();
+            for child in self.children() {
+                let argument = child.eval_with_scoped_context(context, loop_iteration_budget);
+                arguments.push(if receives_unset_variables {
+                    child.unset_variable_as_empty(argument)?
+                } else {
+                    argument?
+                });
+            }
+            self.operator().eval(&arguments, &*context)
+        })();
+        if opens_scope {
+            context.pop_scope();
+        }
+        result
     }
 
     /// Evaluates the operator tree rooted at this node with the given mutable context.
@@ -344,11 +746,61 @@ impl<NumericTypes: EvalexprNumericTypes> Node<NumericTypes> {
         &self,
         context: &mut C,
     ) -> EvalexprResultValue<NumericTypes> {
-        let mut arguments = Vec::new();
-        for child in self.children() {
-            arguments.push(child.eval_with_context_mut(context)?);
+        let loop_iteration_budget = Cell::new(context.loop_iteration_budget());
+        self.eval_with_scoped_context_mut(&mut ScopedContext::new(context), &loop_iteration_budget)
+    }
+
+    /// Evaluates the operator tree rooted at this node with the given mutable context and the local variables bound so far,
+    /// performing at most `loop_iteration_budget` loop iterations.
+    fn eval_with_scoped_context_mut<
+        C: ContextWithMutableVariables + Context<NumericTypes = NumericTypes>,
+    >(
+        &self,
+        context: &mut ScopedContext<&mut C>,
+        loop_iteration_budget: &Cell<usize>,
+    ) -> EvalexprResultValue<NumericTypes> {
+        if let Some(binding) = self.let_binding() {
+            let (pattern, value) = binding?;
+            let pattern = pattern.eval_with_scoped_context_mut(context, loop_iteration_budget)?;
+            let value = value.eval_with_scoped_context_mut(context, loop_iteration_budget)?;
+            assign_to_pattern(&pattern, value, &mut |identifier, value| {
+                context.bind(identifier, value);
+                Ok(())
+            })?;
+            return Ok(Value::Empty);
+        }
+
+        let opens_scope = self.opens_scope();
+        if opens_scope {
+            context.push_scope();
+        }
+        let result = (|| {
+            if let Some(result) = self.eval_for_mut(context, loop_iteration_budget) {
+                return result;
+            }
+            if let Some(result) = self.eval_lazily(
+                |child| child.eval_with_scoped_context_mut(context, loop_iteration_budget),
+                loop_iteration_budget,
+            ) {
+                return result;
+            }
+
+            let receives_unset_variables = self.receives_unset_variables(&*context);
+            let mut arguments = Vec::new();
+            for child in self.children() {
+                let argument = child.eval_with_scoped_context_mut(context, loop_iteration_budget);
+                arguments.push(if receives_unset_variables {
+                    child.unset_variable_as_empty(argument)?
+                } else {
+                    argument?
+                });
+            }
+            self.operator().eval_mut(&arguments, context)
+        })();
+        if opens_scope {
+            context.pop_scope();
         }
-        self.operator().eval_mut(&arguments, context)
+        result
     }
 
     /// Evaluates the operator tree rooted at this node.
@@ -707,7 +1159,7 @@ impl<NumericTypes: EvalexprNumericTypes> Node<NumericTypes> {
                     }
 
                     // Unwrap cannot fail because is_leaf being false and has_enough_children being true implies that the operator wants and has at least one child
-                    let last_child = self.children.pop().unwrap();
+                    let mut last_child = self.children.pop().unwrap();
                     // Root nodes have at most one child
                     // TODO I am not sure if this is the correct error
                     if self.operator() == &Operator::RootNode && !self.children().is_empty() {
@@ -720,6 +1172,26 @@ impl<NumericTypes: EvalexprNumericTypes> Node<NumericTypes> {
                     {
                         return Err(EvalexprError::MissingOperatorOutsideOfBrace);
                     }
+
+                    // Ordering comparators following each other like `a < b <= c` are chained into one comparison,
+                    // instead of comparing the boolean result of `a < b` with `c`.
+                    if node.operator().is_ordering_comparison() && last_child.has_enough_children()
+                    {
+                        let operators = match last_child.operator_mut() {
+                            Operator::ComparisonChain { operators } => Some(mem::take(operators)),
+                            operator if operator.is_ordering_comparison() => {
+                                Some(vec![operator.clone()])
+                            },
+                            _ => None,
+                        };
+                        if let Some(mut operators) = operators {
+                            operators.push(node.operator);
+                            last_child.operator = Operator::ComparisonChain { operators };
+                            self.children.push(last_child);
+                            return Ok(());
+                        }
+                    }
+
                     self.children.push(node);
                     let node = self.children.last_mut().unwrap();
 
@@ -757,7 +1229,10 @@ fn collapse_root_stack_to<NumericTypes: EvalexprNumericTypes>(
     loop {
         if let Some(mut potential_higher_root) = root_stack.pop() {
             // TODO I'm not sure about this >, as I have no example for different sequence operators with the same precedence
-            if potential_higher_root.operator().precedence() > collapse_goal.operator().precedence()
+            // Root nodes are never collapsed, as they are only closed by their closing token or the end of the expression
+            if potential_higher_root.operator() != &Operator::RootNode
+                && potential_higher_root.operator().precedence()
+                    > collapse_goal.operator().precedence()
             {
                 potential_higher_root.children.push(root);
                 root = potential_higher_root;
@@ -821,10 +1296,219 @@ fn collapse_all_sequences<NumericTypes: EvalexprNumericTypes>(
     Ok(())
 }
 
+/// Consumes one loop iteration from the budget, failing if it is exhausted.
+fn consume_loop_iteration<NumericTypes: EvalexprNumericTypes>(
+    loop_iteration_budget: &Cell<usize>,
+) -> EvalexprResult<(), NumericTypes> {
+    let remaining = loop_iteration_budget
+        .get()
+        .checked_sub(1)
+        .ok_or(EvalexprError::LoopIterationBudgetExceeded)?;
+    loop_iteration_budget.set(remaining);
+    Ok(())
+}
+
+/// Handles the result of evaluating the body of a loop.
+/// Returns false if the loop was left with `break`, and true if it should continue with its next iteration.
+fn continue_loop_after<NumericTypes: EvalexprNumericTypes>(
+    body_result: EvalexprResultValue<NumericTypes>,
+) -> EvalexprResult<bool, NumericTypes> {
+    match body_result {
+        Err(EvalexprError::BreakOutsideOfLoop) => Ok(false),
+        Ok(_) | Err(EvalexprError::ContinueOutsideOfLoop) => Ok(true),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the error for a root that was opened by `opening_token` but never closed.
+fn unclosed_root_error<NumericTypes: EvalexprNumericTypes>(
+    opening_token: &Token<NumericTypes>,
+) -> EvalexprError<NumericTypes> {
+    match opening_token {
+        Token::QuestionMark => EvalexprError::UnmatchedQuestionMark,
+        Token::While | Token::For => EvalexprError::MissingLoopBody,
+        Token::LCurlyBrace => EvalexprError::UnmatchedLCurlyBrace,
+        _ => EvalexprError::UnmatchedLBrace,
+    }
+}
+
+/// Closes the root on top of the root stack and returns it.
+///
+/// Fails with `unmatched_error` if there is no open root,
+/// and if the root was not opened by a token accepted by `is_opening_token`, fails because that root is not closed.
+fn close_root<NumericTypes: EvalexprNumericTypes>(
+    root_stack: &mut Vec<Node<NumericTypes>>,
+    opening_tokens: &mut Vec<Token<NumericTypes>>,
+    is_opening_token: impl Fn(&Token<NumericTypes>) -> bool,
+    unmatched_error: EvalexprError<NumericTypes>,
+) -> EvalexprResult<Node<NumericTypes>, NumericTypes> {
+    let Some(opening_token) = opening_tokens.pop() else {
+        return Err(unmatched_error);
+    };
+    if !is_opening_token(&opening_token) {
+        return Err(unclosed_root_error(&opening_token));
+    }
+
+    collapse_all_sequences(root_stack)?;
+    root_stack.pop().ok_or(EvalexprError::UnmatchedRBrace)
+}
+
+/// Checks that the root `header` of a for loop is of the form `identifier in expression`,
+/// and marks the identifier as being written to.
+fn for_loop_header<NumericTypes: EvalexprNumericTypes>(
+    mut header: Node<NumericTypes>,
+) -> EvalexprResult<Node<NumericTypes>, NumericTypes> {
+    if let [membership] = header.children.as_mut_slice() {
+        if membership.operator == Operator::In {
+            if let [variable, _] = membership.children.as_mut_slice() {
+                if let Operator::VariableIdentifierRead { identifier } = &variable.operator {
+                    variable.operator = Operator::variable_identifier_write(identifier.clone());
+                    return Ok(header);
+                }
+            }
+        }
+    }
+
+    Err(EvalexprError::MalformedForLoopHeader)
+}
+
+/// Turns assignments to tuples of variables into assignments to tuple patterns, which are assigned element-wise.
+///
+/// As the aggregation operator has a lower precedence than the assignment operator,
+/// `a, b = b, a` is first parsed as the tuple `a, (b = b), a`, and `(a, b) = 1, 2` as the tuple `((a, b) = 1), 2`.
+/// Such tuples are restructured into the assignment `(a, b) = (b, a)` and `(a, b) = (1, 2)` respectively.
+/// A plain variable assignment like `x = 1, 2` stays the tuple `(x = 1), 2`.
+fn resolve_tuple_assignments<NumericTypes: EvalexprNumericTypes>(node: &mut Node<NumericTypes>) {
+    for child in &mut node.children {
+        resolve_tuple_assignments(child);
+    }
+
+    if node.operator == Operator::Tuple {
+        if let Some(position) = node
+            .children
+            .iter()
+            .position(|element| !element.is_tuple_pattern())
+        {
+            if let Some(is_let) = tuple_element_assignment(&node.children[position], position) {
+                let mut elements = mem::take(&mut node.children);
+                let rest = elements.split_off(position + 1);
+                let mut assignment = elements
+                    .pop()
+                    .and_then(|mut element| element.children.pop());
+                if is_let {
+                    assignment = assignment.and_then(|mut binding| binding.children.pop());
+                }
+                let mut assignment =
+                    assignment.expect("the tuple element was checked to be an assignment");
+                let mut value = assignment
+                    .children
+                    .pop()
+                    .expect("the assignment was checked to have two children");
+                let target = assignment
+                    .children
+                    .pop()
+                    .expect("the assignment was checked to have two children");
+
+                if !elements.is_empty() {
+                    elements.push(target);
+                    assignment.children.push(Node {
+                        operator: Operator::Tuple,
+                        children: elements,
+                    });
+                } else {
+                    assignment.children.push(target);
+                }
+                if !rest.is_empty() {
+                    value = Node {
+                        operator: Operator::Tuple,
+                        children: std::iter::once(value).chain(rest).collect(),
+                    };
+                }
+                assignment.children.push(value);
+
+                *node = if is_let {
+                    Node {
+                        operator: Operator::Let,
+                        children: vec![assignment],
+                    }
+                } else {
+                    assignment
+                };
+            }
+        }
+    }
+
+    if node.operator == Operator::Assign {
+        if let Some(target) = node.children.first_mut() {
+            if target.is_tuple_pattern() {
+                target.mark_as_written();
+            }
+        }
+    }
+}
+
+/// If the tuple `element` at the given position is an assignment to a tuple pattern,
+/// returns whether the assignment is part of a `let` binding.
+///
+/// The first element of a tuple is only considered if it assigns to a tuple and not a single variable.
+/// A `let` binding is only considered in the first element.
+fn tuple_element_assignment<NumericTypes: EvalexprNumericTypes>(
+    element: &Node<NumericTypes>,
+    position: usize,
+) -> Option<bool> {
+    let [assignment] = element.children.as_slice() else {
+        return None;
+    };
+    let (assignment, is_let) = match assignment.children.as_slice() {
+        [binding] if position == 0 && assignment.operator == Operator::Let => (binding, true),
+        _ => (assignment, false),
+    };
+    let (Operator::Assign, [target, _]) = (&assignment.operator, assignment.children.as_slice())
+    else {
+        return None;
+    };
+    let assigns_to_tuple = position > 0
+        || target
+            .iter()
+            .any(|node| node.operator() == &Operator::Tuple);
+    (target.is_tuple_pattern() && assigns_to_tuple).then_some(is_let)
+}
+
+/// Builds the node of a format string, whose children are the parsed interpolated expressions.
+fn format_string<NumericTypes: EvalexprNumericTypes>(
+    parts: Vec<FormatStringPart>,
+) -> EvalexprResult<Node<NumericTypes>, NumericTypes> {
+    let mut segments = vec![String::new()];
+    let mut specs = Vec::new();
+    let mut children = Vec::new();
+
+    for part in parts {
+        match part {
+            FormatStringPart::Literal(literal) => {
+                if let Some(segment) = segments.last_mut() {
+                    segment.push_str(&literal);
+                }
+            },
+            FormatStringPart::Interpolation { expression, spec } => {
+                children.push(tokens_to_operator_tree(tokenize(&expression)?)?);
+                specs.push(spec);
+                segments.push(String::new());
+            },
+        }
+    }
+
+    Ok(Node {
+        operator: Operator::FormatString { segments, specs },
+        children,
+    })
+}
+
 pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
     tokens: Vec<Token<NumericTypes>>,
 ) -> EvalexprResult<Node<NumericTypes>, NumericTypes> {
     let mut root_stack = vec![Node::root_node()];
+    // The tokens that opened the roots above the bottom of the root stack, which wait for their closing tokens.
+    let mut opening_tokens = Vec::new();
     let mut last_token_is_rightsided_value = false;
     let mut token_iter = tokens.iter().peekable();
 
@@ -855,17 +1539,66 @@ pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
             Token::Or => Some(Node::new(Operator::Or)),
             Token::Not => Some(Node::new(Operator::Not)),
 
-            Token::LBrace => {
-                root_stack.push(Node::root_node());
-                None
+            Token::Ampersand => Some(Node::new(Operator::BitAnd)),
+            Token::VerticalBar => Some(Node::new(Operator::BitOr)),
+            Token::Tilde => Some(Node::new(Operator::BitNot)),
+            Token::Xor => Some(Node::new(Operator::BitXor)),
+            Token::Shl => Some(Node::new(Operator::Shl)),
+            Token::Shr => Some(Node::new(Operator::Shr)),
+
+            Token::In => Some(Node::new(Operator::In)),
+            Token::NotIn => Some(Node::new(Operator::NotIn)),
+            Token::DotDot => Some(Node::new(Operator::Range)),
+            Token::DotDotEq => Some(Node::new(Operator::RangeInclusive)),
+
+            Token::DoubleQuestionMark => Some(Node::new(Operator::Coalesce)),
+
+            Token::LBrace => None,
+            Token::RBrace => Some(close_root(
+                &mut root_stack,
+                &mut opening_tokens,
+                |token| token == &Token::LBrace,
+                EvalexprError::UnmatchedRBrace,
+            )?),
+
+            Token::QuestionMark => Some(Node::new(Operator::Conditional)),
+            Token::Colon => Some(close_root(
+                &mut root_stack,
+                &mut opening_tokens,
+                |token| token == &Token::QuestionMark,
+                EvalexprError::UnmatchedColon,
+            )?),
+
+            Token::While => Some(Node::new(Operator::While)),
+            Token::For => Some(Node::new(Operator::For)),
+            Token::Break => Some(Node::new(Operator::Break)),
+            Token::Continue => Some(Node::new(Operator::Continue)),
+            Token::Let => Some(Node::new(Operator::Let)),
+            Token::LCurlyBrace => match opening_tokens.last() {
+                // The curly brace closes the header of a loop before opening its body.
+                Some(Token::While) => Some(close_root(
+                    &mut root_stack,
+                    &mut opening_tokens,
+                    |_| true,
+                    EvalexprError::MissingLoopBody,
+                )?),
+                Some(Token::For) => Some(for_loop_header(close_root(
+                    &mut root_stack,
+                    &mut opening_tokens,
+                    |_| true,
+                    EvalexprError::MissingLoopBody,
+                )?)?),
+                _ => None,
             },
-            Token::RBrace => {
-                if root_stack.len() <= 1 {
-                    return Err(EvalexprError::UnmatchedRBrace);
-                } else {
-                    collapse_all_sequences(&mut root_stack)?;
-                    root_stack.pop()
-                }
+            Token::RCurlyBrace => {
+                let mut block = close_root(
+                    &mut root_stack,
+                    &mut opening_tokens,
+                    |token| token == &Token::LCurlyBrace,
+                    EvalexprError::UnmatchedRCurlyBrace,
+                )?;
+                block.operator = Operator::Block;
+                Some(block)
             },
 
             Token::Assign => Some(Node::new(Operator::Assign)),
@@ -877,6 +1610,11 @@ pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
             Token::HatAssign => Some(Node::new(Operator::ExpAssign)),
             Token::AndAssign => Some(Node::new(Operator::AndAssign)),
             Token::OrAssign => Some(Node::new(Operator::OrAssign)),
+            Token::AmpersandAssign => Some(Node::new(Operator::BitAndAssign)),
+            Token::VerticalBarAssign => Some(Node::new(Operator::BitOrAssign)),
+            Token::XorAssign => Some(Node::new(Operator::BitXorAssign)),
+            Token::ShlAssign => Some(Node::new(Operator::ShlAssign)),
+            Token::ShrAssign => Some(Node::new(Operator::ShrAssign)),
 
             Token::Comma => Some(Node::new(Operator::Tuple)),
             Token::Semicolon => Some(Node::new(Operator::Chain)),
@@ -897,9 +1635,12 @@ pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
                 result
             },
             Token::Float(float) => Some(Node::new(Operator::value(Value::Float(float)))),
+            #[cfg(feature = "units")]
+            Token::Quantity(quantity) => Some(Node::new(Operator::value(Value::Quantity(quantity)))),
             Token::Int(int) => Some(Node::new(Operator::value(Value::Int(int)))),
             Token::Boolean(boolean) => Some(Node::new(Operator::value(Value::Boolean(boolean)))),
             Token::String(string) => Some(Node::new(Operator::value(Value::String(string)))),
+            Token::FormatString(parts) => Some(format_string(parts)?),
         };
 
         if let Some(mut node) = node {
@@ -936,8 +1677,22 @@ pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
                         } else {
                             // If the new sequence doesn't have a higher precedence, then all sequences with a higher precedence are collapsed below this one
                             root = collapse_root_stack_to(&mut root_stack, root, &node)?;
-                            node.children.push(root);
-                            root_stack.push(node);
+                            match root_stack.last_mut() {
+                                // If a sequence of the same variant remains, the collapsed sequences are its last element, and the next element begins
+                                Some(lower_root)
+                                    if mem::discriminant(lower_root.operator())
+                                        == mem::discriminant(node.operator()) =>
+                                {
+                                    lower_root.children.push(root);
+                                    lower_root.children.push(Node::root_node());
+                                },
+                                // Otherwise, the collapsed sequences are the first element of a new sequence
+                                _ => {
+                                    node.children.push(root);
+                                    node.children.push(Node::root_node());
+                                    root_stack.push(node);
+                                },
+                            }
                         }
                     }
                 // println!("Stack after sequence operation: {:?}", root_stack);
@@ -959,15 +1714,28 @@ pub(crate) fn tokens_to_operator_tree<NumericTypes: EvalexprNumericTypes>(
             }
         }
 
+        // Parenthesized expressions, the branch of a conditional that is taken if the condition is true,
+        // the headers of loops and blocks are parsed below a new root that is closed by their closing token.
+        if matches!(
+            token,
+            Token::LBrace | Token::QuestionMark | Token::While | Token::For | Token::LCurlyBrace
+        ) {
+            root_stack.push(Node::root_node());
+            opening_tokens.push(token.clone());
+        }
+
         last_token_is_rightsided_value = token.is_rightsided_value();
     }
 
     // In the end, all sequences are implicitly terminated
     collapse_all_sequences(&mut root_stack)?;
 
-    if root_stack.len() > 1 {
+    if let Some(opening_token) = opening_tokens.last() {
+        Err(unclosed_root_error(opening_token))
+    } else if root_stack.len() > 1 {
         Err(EvalexprError::UnmatchedLBrace)
-    } else if let Some(root) = root_stack.pop() {
+    } else if let Some(mut root) = root_stack.pop() {
+        resolve_tuple_assignments(&mut root);
         Ok(root)
     } else {
         Err(EvalexprError::UnmatchedRBrace)
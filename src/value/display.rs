@@ -25,6 +25,16 @@ impl<NumericTypes: EvalexprNumericTypes> Display for Value<NumericTypes> {
                 write!(f, ")")
             },
             Value::Empty => write!(f, "()"),
+            #[cfg(feature = "datetime")]
+            Value::DateTime(datetime) => write!(
+                f,
+                "{}",
+                datetime.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+            ),
+            #[cfg(feature = "datetime")]
+            Value::Duration(duration) => write!(f, "{}", duration),
+            #[cfg(feature = "units")]
+            Value::Quantity(quantity) => write!(f, "{}", quantity),
         }
     }
 }
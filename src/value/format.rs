@@ -0,0 +1,223 @@
+use std::fmt::{Display, Error, Formatter};
+
+use crate::{
+    value::numeric_types::{EvalexprFloat, EvalexprNumericTypes, RoundingMode},
+    EvalexprError, EvalexprResult, Value,
+};
+
+/// The alignment of an interpolated value within the width of its format specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatAlignment {
+    /// Align to the left, written as `<`.
+    Left,
+    /// Center the value, written as `^`.
+    Center,
+    /// Align to the right, written as `>`.
+    Right,
+}
+
+impl FormatAlignment {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(FormatAlignment::Left),
+            '^' => Some(FormatAlignment::Center),
+            '>' => Some(FormatAlignment::Right),
+            _ => None,
+        }
+    }
+}
+
+/// The largest width and precision a format specification may have.
+const MAXIMUM_WIDTH_AND_PRECISION: usize = 1024;
+
+/// The format specification of a value interpolated into a format string, like the `>8.2` in `f"{price:>8.2}"`.
+///
+/// It is written as `[[fill]alignment][0][width][.precision]`, where the alignment is one of `<`, `^` and `>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// The character that pads the value up to the width.
+    pub fill: char,
+    /// The alignment of the value within the width.
+    /// If `None`, numbers are aligned to the right and all other values to the left.
+    pub alignment: Option<FormatAlignment>,
+    /// If true, numbers are padded with zeros after their sign, ignoring the fill and the alignment.
+    pub zero_padding: bool,
+    /// The minimum amount of characters of the formatted value.
+    pub width: Option<usize>,
+    /// The amount of decimal places of a number or of the magnitude of a quantity, or the maximum amount of characters of any other value.
+    pub precision: Option<usize>,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            alignment: None,
+            zero_padding: false,
+            width: None,
+            precision: None,
+        }
+    }
+}
+
+impl FormatSpec {
+    /// Parses a format specification of the form `[[fill]alignment][0][width][.precision]`.
+    /// The width and the precision may be at most 1024.
+    pub(crate) fn parse<NumericTypes: EvalexprNumericTypes>(
+        spec: &str,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        let illegal = || EvalexprError::IllegalFormatSpecification(spec.to_string());
+        let mut result = Self::default();
+        let mut rest = spec;
+
+        let mut chars = rest.chars();
+        if let (Some(fill), Some(alignment)) = (
+            chars.next(),
+            chars.next().and_then(FormatAlignment::from_char),
+        ) {
+            result.fill = fill;
+            result.alignment = Some(alignment);
+            rest = chars.as_str();
+        } else if let Some(alignment) = rest.chars().next().and_then(FormatAlignment::from_char) {
+            result.alignment = Some(alignment);
+            rest = &rest[1..];
+        }
+
+        let parse_bounded = |digits: &str| match digits.parse() {
+            Ok(number) if number <= MAXIMUM_WIDTH_AND_PRECISION => Ok(number),
+            _ => Err(illegal()),
+        };
+
+        let width_length = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if width_length > 1 && rest.starts_with('0') {
+            result.zero_padding = true;
+        }
+        if width_length > 0 {
+            result.width = Some(parse_bounded(&rest[..width_length])?);
+        }
+        rest = &rest[width_length..];
+
+        if let Some(precision) = rest.strip_prefix('.') {
+            if precision.is_empty() || !precision.chars().all(|c| c.is_ascii_digit()) {
+                return Err(illegal());
+            }
+            result.precision = Some(parse_bounded(precision)?);
+        } else if !rest.is_empty() {
+            return Err(illegal());
+        }
+
+        Ok(result)
+    }
+
+    /// Formats the given value according to this specification.
+    /// Strings are inserted without quotes, and floats are rounded to the precision according to `rounding_mode`.
+    pub(crate) fn format<NumericTypes: EvalexprNumericTypes>(
+        &self,
+        value: &Value<NumericTypes>,
+        rounding_mode: RoundingMode,
+    ) -> String {
+        let formatted = match (value, self.precision) {
+            (Value::Float(float), Some(precision)) => format!(
+                "{:.*}",
+                precision,
+                float.round_to_decimal_places(precision, rounding_mode)
+            ),
+            // Converting to a float could lose digits, so zeros are appended instead.
+            (Value::Int(int), Some(0)) => int.to_string(),
+            (Value::Int(int), Some(precision)) => format!("{}.{}", int, "0".repeat(precision)),
+            #[cfg(feature = "units")]
+            (Value::Quantity(quantity), Some(precision)) => {
+                format!(
+                    "{:.*} {}",
+                    precision,
+                    quantity
+                        .value()
+                        .round_to_decimal_places(precision, rounding_mode),
+                    quantity.unit()
+                )
+            },
+            (value, Some(precision)) => value.str_from().chars().take(precision).collect(),
+            (value, None) => value.str_from(),
+        };
+
+        let padding = self
+            .width
+            .unwrap_or(0)
+            .saturating_sub(formatted.chars().count());
+        if self.zero_padding && value.is_number() {
+            let (sign, digits) = match formatted.strip_prefix('-') {
+                Some(digits) => ("-", digits),
+                None => ("", formatted.as_str()),
+            };
+            return format!("{}{}{}", sign, "0".repeat(padding), digits);
+        }
+        let alignment = self.alignment.unwrap_or(if value.is_number() {
+            FormatAlignment::Right
+        } else {
+            FormatAlignment::Left
+        });
+        let left_padding = match alignment {
+            FormatAlignment::Left => 0,
+            FormatAlignment::Center => padding / 2,
+            FormatAlignment::Right => padding,
+        };
+
+        let mut result = String::with_capacity(formatted.len() + padding);
+        result.extend(std::iter::repeat(self.fill).take(left_padding));
+        result.push_str(&formatted);
+        result.extend(std::iter::repeat(self.fill).take(padding - left_padding));
+        result
+    }
+}
+
+impl Display for FormatSpec {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if let Some(alignment) = self.alignment {
+            if self.fill != ' ' {
+                write!(f, "{}", self.fill)?;
+            }
+            match alignment {
+                FormatAlignment::Left => write!(f, "<")?,
+                FormatAlignment::Center => write!(f, "^")?,
+                FormatAlignment::Right => write!(f, ">")?,
+            }
+        }
+        if self.zero_padding {
+            write!(f, "0")?;
+        }
+        if let Some(width) = self.width {
+            write!(f, "{}", width)?;
+        }
+        if let Some(precision) = self.precision {
+            write!(f, ".{}", precision)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes literal text of a format string, escaping double quotes, backslashes and curly braces.
+pub(crate) fn write_format_string_literal(f: &mut Formatter, literal: &str) -> Result<(), Error> {
+    for c in literal.chars() {
+        match c {
+            '"' | '\\' => write!(f, "\\{}", c)?,
+            '{' | '}' => write!(f, "{}{}", c, c)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes an interpolation of a format string around the given expression, including its format specification if it is not the default.
+pub(crate) fn write_format_string_interpolation(
+    f: &mut Formatter,
+    expression: &str,
+    spec: &FormatSpec,
+) -> Result<(), Error> {
+    if spec == &FormatSpec::default() {
+        write!(f, "{{{}}}", expression)
+    } else {
+        write!(f, "{{{}:{}}}", expression, spec)
+    }
+}
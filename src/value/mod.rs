@@ -7,7 +7,10 @@ use self::numeric_types::{DefaultNumericTypes, EvalexprNumericTypes};
 use numeric_types::with_num::EvalexprNumericTypesConvert;
 
 mod display;
+pub mod format;
 pub mod numeric_types;
+#[cfg(feature = "units")]
+pub mod units;
 pub mod value_type;
 
 /// The type used to represent tuples in `Value::Tuple`.
@@ -36,6 +39,15 @@ pub enum Value<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
     Tuple(TupleType<NumericTypes>),
     /// An empty value.
     Empty,
+    /// A point in time with an offset from UTC.
+    #[cfg(feature = "datetime")]
+    DateTime(chrono::DateTime<chrono::FixedOffset>),
+    /// A signed amount of time.
+    #[cfg(feature = "datetime")]
+    Duration(chrono::TimeDelta),
+    /// A float with a unit of measure.
+    #[cfg(feature = "units")]
+    Quantity(units::Quantity<NumericTypes>),
 }
 
 impl<NumericTypes: EvalexprNumericTypes> Value<NumericTypes> {
@@ -73,6 +85,24 @@ impl<NumericTypes: EvalexprNumericTypes> Value<NumericTypes> {
         matches!(self, Value::Empty)
     }
 
+    /// Returns true if `self` is a `Value::DateTime`.
+    #[cfg(feature = "datetime")]
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Value::DateTime(_))
+    }
+
+    /// Returns true if `self` is a `Value::Duration`.
+    #[cfg(feature = "datetime")]
+    pub fn is_duration(&self) -> bool {
+        matches!(self, Value::Duration(_))
+    }
+
+    /// Returns true if `self` is a `Value::Quantity`.
+    #[cfg(feature = "units")]
+    pub fn is_quantity(&self) -> bool {
+        matches!(self, Value::Quantity(_))
+    }
+
     /// Clones the value stored in `self` as `String`, or returns `Err` if `self` is not a `Value::String`.
     pub fn as_string(&self) -> EvalexprResult<String, NumericTypes> {
         match self {
@@ -168,6 +198,44 @@ impl<NumericTypes: EvalexprNumericTypes> Value<NumericTypes> {
         }
     }
 
+    /// Returns the value stored in `self` as `DateTime`, or returns `Err` if `self` is not a `Value::DateTime`.
+    #[cfg(feature = "datetime")]
+    pub fn as_datetime(
+        &self,
+    ) -> EvalexprResult<chrono::DateTime<chrono::FixedOffset>, NumericTypes> {
+        match self {
+            Value::DateTime(datetime) => Ok(*datetime),
+            value => Err(EvalexprError::type_error(
+                value.clone(),
+                vec![value_type::ValueType::DateTime],
+            )),
+        }
+    }
+
+    /// Returns the value stored in `self` as `TimeDelta`, or returns `Err` if `self` is not a `Value::Duration`.
+    #[cfg(feature = "datetime")]
+    pub fn as_duration(&self) -> EvalexprResult<chrono::TimeDelta, NumericTypes> {
+        match self {
+            Value::Duration(duration) => Ok(*duration),
+            value => Err(EvalexprError::type_error(
+                value.clone(),
+                vec![value_type::ValueType::Duration],
+            )),
+        }
+    }
+
+    /// Clones the value stored in `self` as `Quantity`, or returns `Err` if `self` is not a `Value::Quantity`.
+    #[cfg(feature = "units")]
+    pub fn as_quantity(&self) -> EvalexprResult<units::Quantity<NumericTypes>, NumericTypes> {
+        match self {
+            Value::Quantity(quantity) => Ok(quantity.clone()),
+            value => Err(EvalexprError::type_error(
+                value.clone(),
+                vec![value_type::ValueType::Quantity],
+            )),
+        }
+    }
+
     /// Returns a string for the `str::from` built-in function.
     pub fn str_from(&self) -> String {
         match self {
@@ -177,6 +245,10 @@ impl<NumericTypes: EvalexprNumericTypes> Value<NumericTypes> {
             Value::Boolean(v) => v.to_string(),
             Value::Tuple(_) => self.to_string(),
             Value::Empty => String::from("()"),
+            #[cfg(feature = "datetime")]
+            Value::DateTime(_) | Value::Duration(_) => self.to_string(),
+            #[cfg(feature = "units")]
+            Value::Quantity(_) => self.to_string(),
         }
     }
 
@@ -263,6 +335,38 @@ impl<NumericTypes: EvalexprNumericTypes> From<TupleType<NumericTypes>> for Value
     }
 }
 
+#[cfg(feature = "datetime")]
+impl<NumericTypes: EvalexprNumericTypes> From<chrono::DateTime<chrono::FixedOffset>>
+    for Value<NumericTypes>
+{
+    fn from(datetime: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        Value::DateTime(datetime)
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl<NumericTypes: EvalexprNumericTypes> From<chrono::DateTime<chrono::Utc>>
+    for Value<NumericTypes>
+{
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        Value::DateTime(datetime.fixed_offset())
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl<NumericTypes: EvalexprNumericTypes> From<chrono::TimeDelta> for Value<NumericTypes> {
+    fn from(duration: chrono::TimeDelta) -> Self {
+        Value::Duration(duration)
+    }
+}
+
+#[cfg(feature = "units")]
+impl<NumericTypes: EvalexprNumericTypes> From<units::Quantity<NumericTypes>> for Value<NumericTypes> {
+    fn from(quantity: units::Quantity<NumericTypes>) -> Self {
+        Value::Quantity(quantity)
+    }
+}
+
 impl<NumericTypes: EvalexprNumericTypes> From<Value<NumericTypes>>
     for EvalexprResultValue<NumericTypes>
 {
@@ -370,6 +474,21 @@ where
         Value::Boolean(value) => Ok(Value::Boolean(*value)),
         Value::Tuple(value) => Ok(Value::Tuple(value.iter().map(try_convert).collect::<Result<Vec<_>, _>>()?)),
         Value::Empty => Ok(Value::Empty),
+        #[cfg(feature = "datetime")]
+        Value::DateTime(value) => Ok(Value::DateTime(*value)),
+        #[cfg(feature = "datetime")]
+        Value::Duration(value) => Ok(Value::Duration(*value)),
+        #[cfg(feature = "units")]
+        Value::Quantity(quantity) => {
+            let (value, unit) = quantity.clone().into_parts();
+            match cast(value) {
+                Some(value) => Ok(Value::Quantity(units::Quantity::new(value, unit))),
+                None => Err(EvalexprError::FloatToNum {
+                    float: quantity.clone().into_parts().0,
+                    target_type: type_name::<NumericTypesB::Float>()
+                }),
+            }
+        }
     }
 }
 
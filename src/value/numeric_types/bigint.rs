@@ -0,0 +1,240 @@
+use crate::{
+    function::builtin::{int_binomial, int_factorial},
+    EvalexprError, EvalexprInt, EvalexprNumericTypes, EvalexprResult, Function, Value,
+};
+use num_bigint::BigInt;
+use num_traits::{CheckedDiv, CheckedEuclid, FromPrimitive, Num, Signed, ToPrimitive, Zero};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses the arbitrary-precision [`BigInt`] as its integer type and [`f64`] as its float type.
+/// Integer arithmetic grows as needed, so for example factorials of large numbers evaluate exactly.
+/// To bound the memory, shifts and products are limited by [`BIGINT_MAXIMUM_LEFT_SHIFT`] and [`BIGINT_MAXIMUM_PRODUCT_BITS`],
+/// and `math::factorial` and `math::binomial` reject arguments whose results could exceed the latter.
+///
+/// Since [`BigInt`] has no bounds, [`EvalexprInt::min_value`] and [`EvalexprInt::max_value`] return the
+/// smallest and largest integers that convert into finite floats.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BigIntNumericTypes;
+
+impl EvalexprNumericTypes for BigIntNumericTypes {
+    type Int = BigInt;
+    type Float = f64;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        int.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Truncates the float towards zero. NaN and infinite floats become zero.
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        BigInt::from_f64(*float).unwrap_or_default()
+    }
+
+    /// Bounds the arguments of `math::factorial` and `math::binomial`,
+    /// as their results would otherwise exceed [`BIGINT_MAXIMUM_PRODUCT_BITS`] only after a long computation.
+    fn builtin_function(identifier: &str) -> Option<Function<Self>> {
+        match identifier {
+            "math::factorial" => Some(Function::new(|argument| {
+                let n = argument.as_int()?;
+                expect_bounded_product(&n, &n, &n)?;
+                Ok(Value::Int(int_factorial(&n)?))
+            })),
+            "math::binomial" => Some(Function::new(|argument| {
+                let arguments = argument.as_fixed_len_tuple(2)?;
+                let (n, k): (BigInt, BigInt) = (arguments[0].as_int()?, arguments[1].as_int()?);
+                // Only the smaller one of `k` and `n - k` factors are multiplied.
+                expect_bounded_product(&k, &(&n - &k).min(k.clone()), &n)?;
+                Ok(Value::Int(int_binomial(&n, &k)?))
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// The largest amount of bits a [`BigInt`] may be shifted to the left by.
+/// Larger shifts result in a [`EvalexprError::ShiftError`], as their results would exhaust the memory.
+pub const BIGINT_MAXIMUM_LEFT_SHIFT: usize = 1 << 20;
+
+/// The largest amount of bits of a product of [`BigInt`]s.
+/// Larger products result in a [`EvalexprError::MultiplicationError`], as repeated multiplications
+/// would otherwise exhaust the memory.
+pub const BIGINT_MAXIMUM_PRODUCT_BITS: u64 = 1 << 20;
+
+/// Returns an [`EvalexprError::ArgumentOutOfRange`] for `argument` if a product of `factors` factors
+/// up to `largest_factor` could have more than [`BIGINT_MAXIMUM_PRODUCT_BITS`] bits.
+fn expect_bounded_product(
+    argument: &BigInt,
+    factors: &BigInt,
+    largest_factor: &BigInt,
+) -> EvalexprResult<(), BigIntNumericTypes> {
+    let maximum_factors = BigInt::from(BIGINT_MAXIMUM_PRODUCT_BITS / largest_factor.bits().max(1));
+    if *factors > maximum_factors {
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(argument.clone()),
+            Value::Int(BigInt::zero()),
+            Value::Int(maximum_factors),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns a [`EvalexprError::ShiftError`] for shifting `int` by `rhs`.
+fn shift_error<NumericTypes: EvalexprNumericTypes<Int = BigInt>>(
+    int: &BigInt,
+    rhs: &BigInt,
+) -> EvalexprError<NumericTypes> {
+    EvalexprError::shift_error(Value::Int(int.clone()), Value::Int(rhs.clone()))
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Int = Self>> EvalexprInt<NumericTypes> for BigInt {
+    fn min_value() -> Self {
+        -<Self as EvalexprInt<NumericTypes>>::max_value()
+    }
+
+    fn max_value() -> Self {
+        BigInt::from_f64(f64::MAX).unwrap_or_default()
+    }
+
+    fn from_usize(int: usize) -> EvalexprResult<Self, NumericTypes> {
+        Ok(BigInt::from(int))
+    }
+
+    fn into_usize(self) -> EvalexprResult<usize, NumericTypes> {
+        self.to_usize()
+            .ok_or(EvalexprError::IntIntoUsize { int: self })
+    }
+
+    fn from_hex_str(literal: &str) -> Result<Self, ()> {
+        <Self as EvalexprInt<NumericTypes>>::from_str_radix(literal, 16)
+    }
+
+    fn from_str_radix(literal: &str, radix: u32) -> Result<Self, ()> {
+        <BigInt as Num>::from_str_radix(literal, radix).map_err(|_| ())
+    }
+
+    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self - rhs)
+    }
+
+    fn checked_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(-self)
+    }
+
+    /// Returns an error if the product could have more than [`BIGINT_MAXIMUM_PRODUCT_BITS`] bits.
+    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if self.bits() + rhs.bits() > BIGINT_MAXIMUM_PRODUCT_BITS {
+            Err(EvalexprError::multiplication_error(
+                Value::Int(self.clone()),
+                Value::Int(rhs.clone()),
+            ))
+        } else {
+            Ok(self * rhs)
+        }
+    }
+
+    fn checked_div(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        CheckedDiv::checked_div(self, rhs).ok_or_else(|| {
+            EvalexprError::division_error(Value::Int(self.clone()), Value::Int(rhs.clone()))
+        })
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.is_zero() {
+            Err(EvalexprError::modulation_error(
+                Value::Int(self.clone()),
+                Value::Int(rhs.clone()),
+            ))
+        } else {
+            Ok(self % rhs)
+        }
+    }
+
+    fn checked_div_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        CheckedEuclid::checked_div_euclid(self, rhs).ok_or_else(|| {
+            EvalexprError::division_error(Value::Int(self.clone()), Value::Int(rhs.clone()))
+        })
+    }
+
+    fn checked_rem_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        CheckedEuclid::checked_rem_euclid(self, rhs).ok_or_else(|| {
+            EvalexprError::modulation_error(Value::Int(self.clone()), Value::Int(rhs.clone()))
+        })
+    }
+
+    fn abs(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(Signed::abs(self))
+    }
+
+    fn bitand(&self, rhs: &Self) -> Self {
+        self & rhs
+    }
+
+    fn bitor(&self, rhs: &Self) -> Self {
+        self | rhs
+    }
+
+    fn bitxor(&self, rhs: &Self) -> Self {
+        self ^ rhs
+    }
+
+    fn not(&self) -> Self {
+        !self
+    }
+
+    /// Shifts to the right instead if `rhs` is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shift to the left is larger than [`BIGINT_MAXIMUM_LEFT_SHIFT`], like shifts of primitive integers that overflow.
+    fn shl(&self, rhs: &Self) -> Self {
+        let result = if rhs.is_negative() {
+            <Self as EvalexprInt<NumericTypes>>::checked_shr(self, &-rhs)
+        } else {
+            <Self as EvalexprInt<NumericTypes>>::checked_shl(self, rhs)
+        };
+        result.unwrap_or_else(|_| panic!("attempt to shift left with overflow"))
+    }
+
+    /// Shifts to the left instead if `rhs` is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shift to the left is larger than [`BIGINT_MAXIMUM_LEFT_SHIFT`], like shifts of primitive integers that overflow.
+    fn shr(&self, rhs: &Self) -> Self {
+        let result = if rhs.is_negative() {
+            <Self as EvalexprInt<NumericTypes>>::checked_shl(self, &-rhs)
+        } else {
+            <Self as EvalexprInt<NumericTypes>>::checked_shr(self, rhs)
+        };
+        result.unwrap_or_else(|_| panic!("attempt to shift left with overflow"))
+    }
+
+    /// Returns an error if `rhs` is negative or larger than [`BIGINT_MAXIMUM_LEFT_SHIFT`].
+    /// As [`BigInt`] grows as needed, no bits are shifted out.
+    fn checked_shl(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        match rhs.to_usize() {
+            Some(shift) if shift <= BIGINT_MAXIMUM_LEFT_SHIFT => Ok(self << shift),
+            _ => Err(shift_error(self, rhs)),
+        }
+    }
+
+    /// Returns an error if `rhs` is negative.
+    /// Shifting a negative number rounds towards negative infinity, like an arithmetic shift of a primitive integer,
+    /// so shifting by more than the amount of bits of `self` results in `0` or `-1`.
+    fn checked_shr(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.is_negative() {
+            Err(shift_error(self, rhs))
+        } else if let Some(shift) = rhs.to_usize() {
+            Ok(self >> shift)
+        } else if self.is_negative() {
+            Ok(BigInt::from(-1))
+        } else {
+            Ok(BigInt::zero())
+        }
+    }
+}
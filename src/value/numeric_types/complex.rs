@@ -0,0 +1,481 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    EvalexprError, EvalexprFloat, EvalexprNumericTypes, EvalexprResult, Function, RoundingMode,
+    Value,
+};
+use num_complex::{Complex64, ParseComplexError};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i64`] as its integer type and the complex number [`Complex`] as its float type.
+/// Imaginary literals are written with an `i` suffix, like `2i` or `0.5i`, so `3 + 4i` is a complex number.
+/// The `math::*` builtin functions return the principal value for complex arguments,
+/// so for example `math::sqrt(-1)` evaluates to `1i`.
+/// There is no constant for π, which is written as `math::acos(-1)` instead,
+/// so Euler's identity reads `math::exp(1i * math::acos(-1)) + 1`.
+///
+/// Complex numbers with a non-zero imaginary part cannot be ordered, so comparing them with `<` and the like results in an
+/// [`EvalexprError::ComparisonError`].
+/// Dividing by zero results in an [`EvalexprError::DivisionError`] and the remainder of zero in an
+/// [`EvalexprError::ModulationError`], as their results would be NaN in both parts.
+///
+/// The following builtin functions are available in addition to the generic ones:
+///
+/// | Identifier | Argument Amount | Argument Types | Description |
+/// |------------|-----------------|----------------|-------------|
+/// | `re` | 1 | Numeric | Returns the real part of the argument |
+/// | `im` | 1 | Numeric | Returns the imaginary part of the argument |
+/// | `arg` | 1 | Numeric | Returns the argument of the number, which is the angle to the positive real axis in radians |
+/// | `conj` | 1 | Numeric | Returns the complex conjugate of the argument |
+/// | `abs` | 1 | Numeric | Returns the absolute value of the argument, which is its distance from zero |
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ComplexNumericTypes;
+
+impl EvalexprNumericTypes for ComplexNumericTypes {
+    type Int = i64;
+    type Float = Complex;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        Complex::new(*int as f64, 0.0)
+    }
+
+    /// Truncates the real part of the complex number, like the `as` operator does for [`f64`].
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        float.0.re as Self::Int
+    }
+
+    fn builtin_function(identifier: &str) -> Option<Function<Self>> {
+        fn unary(function: fn(Complex64) -> Complex64) -> Option<Function<ComplexNumericTypes>> {
+            Some(Function::new(
+                move |argument: &Value<ComplexNumericTypes>| {
+                    Ok(Value::Float(Complex(function(
+                        argument.as_number()?.value(),
+                    ))))
+                },
+            ))
+        }
+
+        match identifier {
+            "re" => unary(|z| Complex64::from(z.re)),
+            "im" => unary(|z| Complex64::from(z.im)),
+            "arg" => unary(|z| Complex64::from(z.arg())),
+            "conj" => unary(|z| z.conj()),
+            "abs" => unary(|z| Complex64::from(z.norm())),
+            _ => None,
+        }
+    }
+}
+
+/// A complex number of two [`f64`]s, used as the float type of [`ComplexNumericTypes`].
+///
+/// It is displayed like `3+4i`, or like `3` and `4i` if the imaginary or real part is zero.
+/// A precision given in a format string like `f"{x:.2}"` applies to both parts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Complex(pub Complex64);
+
+impl Complex {
+    /// Creates the complex number `re + im * i`.
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex(Complex64::new(re, im))
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> f64 {
+        self.0.re
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> f64 {
+        self.0.im
+    }
+
+    /// Returns true if the imaginary part is zero.
+    pub fn is_real(&self) -> bool {
+        self.0.im == 0.0
+    }
+
+    /// Returns the complex number with a negative zero imaginary part replaced by a positive one.
+    /// Arithmetic can produce a negative zero imaginary part, which would otherwise select the wrong side of a branch cut,
+    /// for example making the square root of `-1.0` evaluate to `-1i`.
+    fn value(self) -> Complex64 {
+        if self.is_real() {
+            Complex64::new(self.0.re, 0.0)
+        } else {
+            self.0
+        }
+    }
+
+    /// Applies the function to the real part if the number is real and the result is real,
+    /// and otherwise applies the complex function.
+    fn real_or_complex(
+        self,
+        real: impl FnOnce(f64) -> f64,
+        complex: impl FnOnce(Complex64) -> Complex64,
+    ) -> Self {
+        if self.is_real() {
+            let result = real(self.0.re);
+            if !result.is_nan() || self.0.re.is_nan() {
+                return Complex::new(result, 0.0);
+            }
+        }
+        Complex(complex(self.value()))
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.0)
+    }
+}
+
+/// Real numbers are ordered as usual, while complex numbers with a non-zero imaginary part are only equal to themselves.
+impl PartialOrd for Complex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.is_real() && other.is_real() {
+            self.0.re.partial_cmp(&other.0.re)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses complex numbers like `3`, `-2.5i`, `i` or `3+4i`.
+impl FromStr for Complex {
+    type Err = ParseComplexError<std::num::ParseFloatError>;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Complex64::from_str(string).map(Complex)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_part(f: &mut fmt::Formatter<'_>, part: f64) -> fmt::Result {
+            match f.precision() {
+                Some(precision) => write!(f, "{:.*}", precision, part),
+                None => write!(f, "{}", part),
+            }
+        }
+
+        let Complex64 { re, im } = self.0;
+        if im == 0.0 {
+            write_part(f, re)
+        } else {
+            if re != 0.0 {
+                write_part(f, re)?;
+                if !im.is_sign_negative() {
+                    write!(f, "+")?;
+                }
+            }
+            write_part(f, im)?;
+            write!(f, "i")
+        }
+    }
+}
+
+macro_rules! impl_operator {
+    ($trait:ident, $method:ident) => {
+        impl $trait for Complex {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Complex(self.0.$method(rhs.0))
+            }
+        }
+    };
+}
+
+impl_operator!(Add, add);
+impl_operator!(Sub, sub);
+impl_operator!(Mul, mul);
+impl_operator!(Div, div);
+
+/// Real numbers use the remainder of [`f64`],
+/// and complex numbers the remainder of the division rounded towards zero in both parts.
+impl Rem for Complex {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        if self.is_real() && rhs.is_real() {
+            Complex::from(self.0.re % rhs.0.re)
+        } else {
+            Complex(self.0 % rhs.0)
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex(-self.0)
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for Complex {
+    fn neg_infinity() -> Self {
+        Complex::from(f64::NEG_INFINITY)
+    }
+
+    fn infinity() -> Self {
+        Complex::from(f64::INFINITY)
+    }
+
+    /// Integer exponents are computed by repeated multiplication, so for example `1i ^ 2` is exactly `-1`.
+    fn pow(&self, exponent: &Self) -> Self {
+        if exponent.is_real() {
+            let exponent = exponent.0.re;
+            if exponent.fract() == 0.0 && exponent.abs() <= f64::from(i32::MAX) && !self.is_real() {
+                return Complex(self.0.powi(exponent as i32));
+            }
+            self.real_or_complex(|re| re.powf(exponent), |z| z.powf(exponent))
+        } else {
+            Complex(self.value().powc(exponent.value()))
+        }
+    }
+
+    fn ln(&self) -> Self {
+        self.real_or_complex(f64::ln, Complex64::ln)
+    }
+
+    fn log(&self, base: &Self) -> Self {
+        if base.is_real() {
+            let base = base.0.re;
+            self.real_or_complex(|re| re.log(base), |z| z.log(base))
+        } else {
+            Complex(self.value().ln() / base.value().ln())
+        }
+    }
+
+    fn log2(&self) -> Self {
+        self.real_or_complex(f64::log2, Complex64::log2)
+    }
+
+    fn log10(&self) -> Self {
+        self.real_or_complex(f64::log10, Complex64::log10)
+    }
+
+    fn exp(&self) -> Self {
+        self.real_or_complex(f64::exp, Complex64::exp)
+    }
+
+    fn exp2(&self) -> Self {
+        self.real_or_complex(f64::exp2, Complex64::exp2)
+    }
+
+    fn cos(&self) -> Self {
+        self.real_or_complex(f64::cos, Complex64::cos)
+    }
+
+    fn cosh(&self) -> Self {
+        self.real_or_complex(f64::cosh, Complex64::cosh)
+    }
+
+    fn acos(&self) -> Self {
+        self.real_or_complex(f64::acos, Complex64::acos)
+    }
+
+    fn acosh(&self) -> Self {
+        self.real_or_complex(f64::acosh, Complex64::acosh)
+    }
+
+    fn sin(&self) -> Self {
+        self.real_or_complex(f64::sin, Complex64::sin)
+    }
+
+    fn sinh(&self) -> Self {
+        self.real_or_complex(f64::sinh, Complex64::sinh)
+    }
+
+    fn asin(&self) -> Self {
+        self.real_or_complex(f64::asin, Complex64::asin)
+    }
+
+    fn asinh(&self) -> Self {
+        self.real_or_complex(f64::asinh, Complex64::asinh)
+    }
+
+    fn tan(&self) -> Self {
+        self.real_or_complex(f64::tan, Complex64::tan)
+    }
+
+    fn tanh(&self) -> Self {
+        self.real_or_complex(f64::tanh, Complex64::tanh)
+    }
+
+    fn atan(&self) -> Self {
+        self.real_or_complex(f64::atan, Complex64::atan)
+    }
+
+    fn atanh(&self) -> Self {
+        self.real_or_complex(f64::atanh, Complex64::atanh)
+    }
+
+    /// Uses only the real parts of the arguments.
+    fn atan2(&self, x: &Self) -> Self {
+        Complex::from(self.0.re.atan2(x.0.re))
+    }
+
+    fn sqrt(&self) -> Self {
+        self.real_or_complex(f64::sqrt, Complex64::sqrt)
+    }
+
+    /// Returns the real cube root for real numbers, for example `-2` for `-8`, and the principal cube root otherwise.
+    fn cbrt(&self) -> Self {
+        self.real_or_complex(f64::cbrt, Complex64::cbrt)
+    }
+
+    /// Returns the length of the hypotenuse of a right-angle triangle with the absolute values of the arguments as legs.
+    fn hypot(&self, other: &Self) -> Self {
+        Complex::from(self.0.norm().hypot(other.0.norm()))
+    }
+
+    fn floor(&self) -> Self {
+        Complex::new(self.0.re.floor(), self.0.im.floor())
+    }
+
+    fn round(&self) -> Self {
+        Complex::new(self.0.re.round(), self.0.im.round())
+    }
+
+    fn ceil(&self) -> Self {
+        Complex::new(self.0.re.ceil(), self.0.im.ceil())
+    }
+
+    fn trunc(&self) -> Self {
+        Complex::new(self.0.re.trunc(), self.0.im.trunc())
+    }
+
+    fn fract(&self) -> Self {
+        Complex::new(self.0.re.fract(), self.0.im.fract())
+    }
+
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(Complex::new(self.0.re.to_radians(), self.0.im.to_radians()))
+    }
+
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(Complex::new(self.0.re.to_degrees(), self.0.im.to_degrees()))
+    }
+
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns true if the number is neither zero, infinite nor NaN, and none of its parts is subnormal.
+    fn is_normal(&self) -> bool {
+        let Complex64 { re, im } = self.0;
+        (re.is_normal() || re == 0.0) && (im.is_normal() || im == 0.0) && (re != 0.0 || im != 0.0)
+    }
+
+    /// Returns the distance from zero.
+    fn abs(&self) -> Self {
+        Complex::from(self.0.norm())
+    }
+
+    /// Returns `self` if the numbers cannot be ordered.
+    fn min(&self, other: &Self) -> Self {
+        if other < self {
+            *other
+        } else {
+            *self
+        }
+    }
+
+    /// Returns `self` if the numbers cannot be ordered.
+    fn max(&self, other: &Self) -> Self {
+        if other > self {
+            *other
+        } else {
+            *self
+        }
+    }
+
+    fn random() -> EvalexprResult<Self, NumericTypes> {
+        <f64 as EvalexprFloat<crate::DefaultNumericTypes>>::random()
+            .map(Complex::from)
+            .map_err(|_| EvalexprError::RandNotEnabled)
+    }
+
+    /// Returns an error if one of the numbers has a non-zero imaginary part.
+    fn checked_partial_cmp(&self, rhs: &Self) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+        if self.is_real() && rhs.is_real() {
+            Ok(self.0.re.partial_cmp(&rhs.0.re))
+        } else {
+            Err(EvalexprError::comparison_error(
+                Value::Float(*self),
+                Value::Float(*rhs),
+            ))
+        }
+    }
+
+    /// Returns an error if one of the numbers has a non-zero imaginary part, like comparing them does.
+    fn checked_min(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        <Self as EvalexprFloat<NumericTypes>>::checked_partial_cmp(self, other)?;
+        Ok(<Self as EvalexprFloat<NumericTypes>>::min(self, other))
+    }
+
+    /// Returns an error if one of the numbers has a non-zero imaginary part, like comparing them does.
+    fn checked_max(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        <Self as EvalexprFloat<NumericTypes>>::checked_partial_cmp(self, other)?;
+        Ok(<Self as EvalexprFloat<NumericTypes>>::max(self, other))
+    }
+
+    /// Returns an error if `rhs` is zero, as the quotient would be NaN in both parts.
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        _rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.0 == Complex64::new(0.0, 0.0) {
+            Err(EvalexprError::division_error(
+                Value::Float(*self),
+                Value::Float(*rhs),
+            ))
+        } else {
+            Ok(*self / *rhs)
+        }
+    }
+
+    /// Returns an error if `rhs` is zero, as the remainder would be NaN in both parts.
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.0 == Complex64::new(0.0, 0.0) {
+            Err(EvalexprError::modulation_error(
+                Value::Float(*self),
+                Value::Float(*rhs),
+            ))
+        } else {
+            Ok(*self % *rhs)
+        }
+    }
+
+    /// Accepts real literals like `2.5` and imaginary literals like `2.5i`, but not `i` on its own,
+    /// which remains a variable identifier.
+    fn from_literal(literal: &str) -> Result<Self, ()> {
+        match literal.strip_suffix('i') {
+            Some(imaginary) if imaginary.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+                imaginary.parse::<f64>().map(|im| Complex::new(0.0, im))
+            },
+            _ => literal.parse::<f64>().map(Complex::from),
+        }
+        .map_err(|_| ())
+    }
+}
@@ -0,0 +1,517 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    EvalexprError, EvalexprFloat, EvalexprNumericTypes, EvalexprResult, RoundingMode, Value,
+};
+use num_traits::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps, RoundingStrategy};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i64`] as its integer type and the exact decimal [`Decimal`] as its float type.
+/// Decimal literals like `19.99` are represented exactly, so `0.1 + 0.2 == 0.3` holds.
+/// Sums, differences and products are exact as long as they fit into the 28 significant digits of [`Decimal`],
+/// and quotients that are not exact are rounded according to the [`RoundingMode`] of the context.
+///
+/// Powers with integer exponents, logarithms, `math::exp`, `math::sqrt` and the sine, cosine and tangent are computed
+/// with decimal arithmetic. All other functions, like the hyperbolic and inverse trigonometric functions, are approximated
+/// with [`f64`].
+/// Decimals have no NaN and no infinities, so math functions like `math::sqrt(-1.0)` or `math::exp(1000.0)` whose results
+/// are undefined or do not fit into a [`Decimal`] result in a [`EvalexprError::MathFunctionError`].
+/// When called directly, the infallible [`EvalexprFloat`] methods map such results to zero, [`Decimal::MAX`] or [`Decimal::MIN`] instead.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DecimalNumericTypes;
+
+impl EvalexprNumericTypes for DecimalNumericTypes {
+    type Int = i64;
+    type Float = Decimal;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        Decimal::from(*int)
+    }
+
+    /// Truncates the decimal towards zero, saturating at the bounds of [`i64`].
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        float
+            .trunc()
+            .to_i64()
+            .unwrap_or(if float.is_sign_negative() {
+                i64::MIN
+            } else {
+                i64::MAX
+            })
+    }
+}
+
+/// The largest mantissa of a [`Decimal`].
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+/// The largest scale of a [`Decimal`].
+const MAX_SCALE: u32 = 28;
+
+/// Converts the given float into a decimal, mapping NaN to zero and saturating at the bounds of [`Decimal`].
+fn from_f64(float: f64) -> Decimal {
+    Decimal::from_f64(float).unwrap_or(if float.is_nan() {
+        Decimal::ZERO
+    } else if float > 0.0 {
+        Decimal::MAX
+    } else {
+        Decimal::MIN
+    })
+}
+
+/// Approximates the math function `name` of a float with [`f64`],
+/// returning an error if the result is NaN, infinite or does not fit into a [`Decimal`].
+fn checked_approximate<NumericTypes: EvalexprNumericTypes<Float = Decimal>>(
+    name: &str,
+    decimal: &Decimal,
+    function: impl FnOnce(f64) -> f64,
+) -> EvalexprResult<Decimal, NumericTypes> {
+    Decimal::from_f64(function(decimal.to_f64().unwrap_or_default()))
+        .ok_or_else(|| EvalexprError::math_function_error(name, Value::Float(*decimal)))
+}
+
+/// Approximates the math function `name` of two floats with [`f64`],
+/// returning an error if the result is NaN, infinite or does not fit into a [`Decimal`].
+fn checked_approximate2<NumericTypes: EvalexprNumericTypes<Float = Decimal>>(
+    name: &str,
+    a: &Decimal,
+    b: &Decimal,
+    function: impl FnOnce(f64, f64) -> f64,
+) -> EvalexprResult<Decimal, NumericTypes> {
+    Decimal::from_f64(function(
+        a.to_f64().unwrap_or_default(),
+        b.to_f64().unwrap_or_default(),
+    ))
+    .ok_or_else(|| {
+        EvalexprError::math_function_error(
+            name,
+            Value::Tuple(vec![Value::Float(*a), Value::Float(*b)]),
+        )
+    })
+}
+
+/// Approximates the given function of a float with [`f64`].
+fn approximate(decimal: &Decimal, function: impl FnOnce(f64) -> f64) -> Decimal {
+    from_f64(function(decimal.to_f64().unwrap_or_default()))
+}
+
+/// Approximates the given function of two floats with [`f64`].
+fn approximate2(a: &Decimal, b: &Decimal, function: impl FnOnce(f64, f64) -> f64) -> Decimal {
+    from_f64(function(
+        a.to_f64().unwrap_or_default(),
+        b.to_f64().unwrap_or_default(),
+    ))
+}
+
+/// Raises `base` to `exponent` with decimal arithmetic.
+///
+/// Returns `None` if the result is too large, or if it is not real because a negative base is raised to a fractional exponent.
+fn powd(base: &Decimal, exponent: &Decimal) -> Option<Decimal> {
+    if base.is_sign_negative() && !base.is_zero() && !exponent.fract().is_zero() {
+        None
+    } else {
+        base.checked_powd(*exponent)
+    }
+}
+
+/// Divides `dividend` by `divisor` with as many significant digits as a [`Decimal`] can hold,
+/// rounding the last digit according to `rounding_mode`.
+///
+/// Returns `None` if the divisor is zero or the quotient is too large.
+fn divide(dividend: &Decimal, divisor: &Decimal, rounding_mode: RoundingMode) -> Option<Decimal> {
+    if divisor.is_zero() {
+        return None;
+    }
+
+    let divisor_mantissa = divisor.mantissa().unsigned_abs();
+    let mut quotient = dividend.mantissa().unsigned_abs() / divisor_mantissa;
+    let mut remainder = dividend.mantissa().unsigned_abs() % divisor_mantissa;
+    // The quotient of the mantissas needs to be shifted by the difference of the scales.
+    let mut scale = i64::from(dividend.scale()) - i64::from(divisor.scale());
+
+    // Long division, one decimal digit at a time.
+    // Both the remainder and the divisor mantissa are below 2^96, so multiplying them by ten never overflows.
+    let next_digit = |quotient: u128, remainder: u128| {
+        let remainder = remainder * 10;
+        (
+            quotient * 10 + remainder / divisor_mantissa,
+            remainder % divisor_mantissa,
+        )
+    };
+    while scale < 0 {
+        (quotient, remainder) = next_digit(quotient, remainder);
+        if quotient > MAX_MANTISSA {
+            return None;
+        }
+        scale += 1;
+    }
+    while remainder != 0 && scale < i64::from(MAX_SCALE) {
+        let (next_quotient, next_remainder) = next_digit(quotient, remainder);
+        // Leave room for rounding up.
+        if next_quotient >= MAX_MANTISSA {
+            break;
+        }
+        (quotient, remainder) = (next_quotient, next_remainder);
+        scale += 1;
+    }
+
+    let negative = dividend.is_sign_negative() != divisor.is_sign_negative();
+    if remainder != 0 {
+        let half = (remainder * 2).cmp(&divisor_mantissa);
+        let round_away_from_zero = match rounding_mode {
+            RoundingMode::NearestEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && quotient % 2 == 1)
+            },
+            RoundingMode::NearestAwayFromZero => half != Ordering::Less,
+            RoundingMode::NearestTowardZero => half == Ordering::Greater,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+        };
+        if round_away_from_zero {
+            quotient += 1;
+            if quotient > MAX_MANTISSA {
+                return None;
+            }
+        }
+    }
+
+    let mantissa = i128::try_from(quotient).ok()?;
+    Decimal::try_from_i128_with_scale(
+        if negative { -mantissa } else { mantissa },
+        u32::try_from(scale).ok()?,
+    )
+    .ok()
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for Decimal {
+    fn neg_infinity() -> Self {
+        Decimal::MIN
+    }
+
+    fn infinity() -> Self {
+        Decimal::MAX
+    }
+
+    fn pow(&self, exponent: &Self) -> Self {
+        powd(self, exponent).unwrap_or_else(|| approximate2(self, exponent, f64::powf))
+    }
+
+    fn ln(&self) -> Self {
+        MathematicalOps::checked_ln(self).unwrap_or_else(|| approximate(self, f64::ln))
+    }
+
+    fn log(&self, base: &Self) -> Self {
+        match (
+            MathematicalOps::checked_ln(self),
+            MathematicalOps::checked_ln(base),
+        ) {
+            (Some(ln), Some(base_ln)) => Decimal::checked_div(ln, base_ln),
+            _ => None,
+        }
+        .unwrap_or_else(|| approximate2(self, base, f64::log))
+    }
+
+    fn log2(&self) -> Self {
+        <Self as EvalexprFloat<NumericTypes>>::log(self, &Decimal::TWO)
+    }
+
+    fn log10(&self) -> Self {
+        MathematicalOps::checked_log10(self).unwrap_or_else(|| approximate(self, f64::log10))
+    }
+
+    fn exp(&self) -> Self {
+        MathematicalOps::checked_exp(self).unwrap_or_else(|| approximate(self, f64::exp))
+    }
+
+    fn exp2(&self) -> Self {
+        <Self as EvalexprFloat<NumericTypes>>::pow(&Decimal::TWO, self)
+    }
+
+    fn cos(&self) -> Self {
+        self.checked_cos()
+            .unwrap_or_else(|| approximate(self, f64::cos))
+    }
+
+    fn cosh(&self) -> Self {
+        approximate(self, f64::cosh)
+    }
+
+    fn acos(&self) -> Self {
+        approximate(self, f64::acos)
+    }
+
+    fn acosh(&self) -> Self {
+        approximate(self, f64::acosh)
+    }
+
+    fn sin(&self) -> Self {
+        self.checked_sin()
+            .unwrap_or_else(|| approximate(self, f64::sin))
+    }
+
+    fn sinh(&self) -> Self {
+        approximate(self, f64::sinh)
+    }
+
+    fn asin(&self) -> Self {
+        approximate(self, f64::asin)
+    }
+
+    fn asinh(&self) -> Self {
+        approximate(self, f64::asinh)
+    }
+
+    fn tan(&self) -> Self {
+        self.checked_tan()
+            .unwrap_or_else(|| approximate(self, f64::tan))
+    }
+
+    fn tanh(&self) -> Self {
+        approximate(self, f64::tanh)
+    }
+
+    fn atan(&self) -> Self {
+        approximate(self, f64::atan)
+    }
+
+    fn atanh(&self) -> Self {
+        approximate(self, f64::atanh)
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        approximate2(self, x, f64::atan2)
+    }
+
+    fn sqrt(&self) -> Self {
+        MathematicalOps::sqrt(self).unwrap_or_else(|| approximate(self, f64::sqrt))
+    }
+
+    fn cbrt(&self) -> Self {
+        approximate(self, f64::cbrt)
+    }
+
+    fn hypot(&self, other: &Self) -> Self {
+        Decimal::checked_mul(*self, *self)
+            .zip(Decimal::checked_mul(*other, *other))
+            .and_then(|(a, b)| Decimal::checked_add(a, b))
+            .and_then(|sum| MathematicalOps::sqrt(&sum))
+            .unwrap_or_else(|| approximate2(self, other, f64::hypot))
+    }
+
+    fn floor(&self) -> Self {
+        Decimal::floor(self)
+    }
+
+    fn round(&self) -> Self {
+        self.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    fn ceil(&self) -> Self {
+        Decimal::ceil(self)
+    }
+
+    fn trunc(&self) -> Self {
+        Decimal::trunc(self)
+    }
+
+    fn fract(&self) -> Self {
+        Decimal::fract(self)
+    }
+
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(Decimal::checked_mul(*self, Decimal::PI)
+            .and_then(|product| Decimal::checked_div(product, Decimal::from(180)))
+            .unwrap_or_else(|| approximate(self, f64::to_radians)))
+    }
+
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(Decimal::checked_mul(*self, Decimal::from(180))
+            .and_then(|product| Decimal::checked_div(product, Decimal::PI))
+            .unwrap_or_else(|| approximate(self, f64::to_degrees)))
+    }
+
+    /// Decimals are never NaN.
+    fn is_nan(&self) -> bool {
+        false
+    }
+
+    /// Decimals are always finite.
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Decimals are never infinite.
+    fn is_infinite(&self) -> bool {
+        false
+    }
+
+    /// Returns true if `self` is not zero.
+    fn is_normal(&self) -> bool {
+        !self.is_zero()
+    }
+
+    fn abs(&self) -> Self {
+        Decimal::abs(self)
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        Ord::min(*self, *other)
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        Ord::max(*self, *other)
+    }
+
+    fn random() -> EvalexprResult<Self, NumericTypes> {
+        <f64 as EvalexprFloat<crate::DefaultNumericTypes>>::random()
+            .map(from_f64)
+            .map_err(|_| EvalexprError::RandNotEnabled)
+    }
+
+    /// Accepts both plain literals like `19.99` and scientific notation like `1.5e3`.
+    fn from_literal(literal: &str) -> Result<Self, ()> {
+        Decimal::from_str(literal)
+            .or_else(|_| Decimal::from_scientific(literal))
+            .map_err(|_| ())
+    }
+
+    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Decimal::checked_add(*self, *rhs)
+            .ok_or_else(|| EvalexprError::addition_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Decimal::checked_sub(*self, *rhs).ok_or_else(|| {
+            EvalexprError::subtraction_error(Value::Float(*self), Value::Float(*rhs))
+        })
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Decimal::checked_mul(*self, *rhs).ok_or_else(|| {
+            EvalexprError::multiplication_error(Value::Float(*self), Value::Float(*rhs))
+        })
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Decimal::checked_rem(*self, *rhs)
+            .ok_or_else(|| EvalexprError::modulation_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        divide(self, rhs, rounding_mode)
+            .ok_or_else(|| EvalexprError::division_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    /// Rounds with [`Decimal::round_dp_with_strategy`], because the [`Display`](std::fmt::Display) implementation of [`Decimal`] truncates.
+    /// A result of zero is positive, so `-0.001` is formatted as `0.00`.
+    fn round_to_decimal_places(&self, decimal_places: usize, rounding_mode: RoundingMode) -> Self {
+        let strategy = match rounding_mode {
+            RoundingMode::NearestEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::NearestAwayFromZero => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::NearestTowardZero => RoundingStrategy::MidpointTowardZero,
+            RoundingMode::TowardZero => RoundingStrategy::ToZero,
+            RoundingMode::AwayFromZero => RoundingStrategy::AwayFromZero,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+            RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+        };
+        let decimal_places = u32::try_from(decimal_places).unwrap_or(u32::MAX);
+        let mut rounded = self.round_dp_with_strategy(decimal_places, strategy);
+        if rounded.is_zero() {
+            rounded.set_sign_positive(true);
+        }
+        rounded
+    }
+
+    fn checked_pow(&self, exponent: &Self) -> EvalexprResult<Self, NumericTypes> {
+        powd(self, exponent).map_or_else(
+            || checked_approximate2("math::pow", self, exponent, f64::powf),
+            Ok,
+        )
+    }
+
+    fn checked_ln(&self) -> EvalexprResult<Self, NumericTypes> {
+        MathematicalOps::checked_ln(self)
+            .map_or_else(|| checked_approximate("math::ln", self, f64::ln), Ok)
+    }
+
+    fn checked_log(&self, base: &Self) -> EvalexprResult<Self, NumericTypes> {
+        match (
+            MathematicalOps::checked_ln(self),
+            MathematicalOps::checked_ln(base),
+        ) {
+            (Some(ln), Some(base_ln)) => Decimal::checked_div(ln, base_ln),
+            _ => None,
+        }
+        .map_or_else(
+            || checked_approximate2("math::log", self, base, f64::log),
+            Ok,
+        )
+    }
+
+    fn checked_log2(&self) -> EvalexprResult<Self, NumericTypes> {
+        <Self as EvalexprFloat<NumericTypes>>::checked_log(self, &Decimal::TWO)
+            .map_err(|_| EvalexprError::math_function_error("math::log2", Value::Float(*self)))
+    }
+
+    fn checked_log10(&self) -> EvalexprResult<Self, NumericTypes> {
+        MathematicalOps::checked_log10(self)
+            .map_or_else(|| checked_approximate("math::log10", self, f64::log10), Ok)
+    }
+
+    fn checked_exp(&self) -> EvalexprResult<Self, NumericTypes> {
+        MathematicalOps::checked_exp(self)
+            .map_or_else(|| checked_approximate("math::exp", self, f64::exp), Ok)
+    }
+
+    fn checked_exp2(&self) -> EvalexprResult<Self, NumericTypes> {
+        <Self as EvalexprFloat<NumericTypes>>::checked_pow(&Decimal::TWO, self)
+            .map_err(|_| EvalexprError::math_function_error("math::exp2", Value::Float(*self)))
+    }
+
+    fn checked_cosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::cosh", self, f64::cosh)
+    }
+
+    fn checked_acos(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::acos", self, f64::acos)
+    }
+
+    fn checked_acosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::acosh", self, f64::acosh)
+    }
+
+    fn checked_sinh(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::sinh", self, f64::sinh)
+    }
+
+    fn checked_asin(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::asin", self, f64::asin)
+    }
+
+    fn checked_atanh(&self) -> EvalexprResult<Self, NumericTypes> {
+        checked_approximate("math::atanh", self, f64::atanh)
+    }
+
+    fn checked_sqrt(&self) -> EvalexprResult<Self, NumericTypes> {
+        MathematicalOps::sqrt(self)
+            .map_or_else(|| checked_approximate("math::sqrt", self, f64::sqrt), Ok)
+    }
+
+    fn checked_hypot(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Decimal::checked_mul(*self, *self)
+            .zip(Decimal::checked_mul(*other, *other))
+            .and_then(|(a, b)| Decimal::checked_add(a, b))
+            .and_then(|sum| MathematicalOps::sqrt(&sum))
+            .map_or_else(
+                || checked_approximate2("math::hypot", self, other, f64::hypot),
+                Ok,
+            )
+    }
+}
@@ -0,0 +1,425 @@
+use std::{
+    cmp::Ordering,
+    f64::consts::{LN_10, LN_2},
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::{EvalexprError, EvalexprFloat, EvalexprNumericTypes, EvalexprResult};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i64`] as its integer type and the dual number [`Dual`] as its float type.
+/// A dual number carries the gradient of its value with respect to a set of seed variables,
+/// so evaluating an expression computes its derivatives alongside its value, which is known as forward-mode automatic differentiation.
+/// Seed variables are created with [`Dual::variable`], and all other floats, like literals, are constants.
+/// Functions registered in a context keep propagating the gradient as long as they only use the operations of [`EvalexprFloat`].
+///
+/// ```rust
+/// use evalexpr::*;
+///
+/// let mut context = HashMapContext::<DualNumericTypes>::new();
+/// context.set_value("x".into(), Value::Float(Dual::variable(3.0, 0))).unwrap();
+/// context.set_value("y".into(), Value::Float(Dual::variable(2.0, 1))).unwrap();
+///
+/// let result = eval_number_with_context("x^2 * y + y", &context).unwrap();
+/// assert_eq!(result.value(), 20.0);
+/// assert_eq!(result.gradient(), [12.0, 10.0]);
+/// ```
+///
+/// Integers are constants, so their arithmetic stays exact, and `7 / 2` evaluates to the integer `3`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DualNumericTypes;
+
+impl EvalexprNumericTypes for DualNumericTypes {
+    type Int = i64;
+    type Float = Dual;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        Dual::constant(*int as f64)
+    }
+
+    /// Truncates the value of the dual number, like the `as` operator does for [`f64`].
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        float.value as Self::Int
+    }
+}
+
+/// A dual number, used as the float type of [`DualNumericTypes`].
+///
+/// It consists of a value and the gradient of the value with respect to the seed variables,
+/// where the entry at index `i` is the partial derivative with respect to the seed variable `i`.
+/// Missing entries at the end of the gradient are zero, so constants have an empty gradient.
+///
+/// Dual numbers are compared and displayed by their value only.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dual {
+    value: f64,
+    gradient: Vec<f64>,
+}
+
+impl Dual {
+    /// Creates a dual number with the given value and gradient.
+    pub fn new(value: f64, gradient: Vec<f64>) -> Self {
+        Dual { value, gradient }
+    }
+
+    /// Creates a constant, whose gradient is zero.
+    pub fn constant(value: f64) -> Self {
+        Dual {
+            value,
+            gradient: Vec::new(),
+        }
+    }
+
+    /// Creates the seed variable with the given index, whose partial derivative with respect to itself is one.
+    pub fn variable(value: f64, index: usize) -> Self {
+        let mut gradient = vec![0.0; index + 1];
+        gradient[index] = 1.0;
+        Dual { value, gradient }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the gradient, without the trailing entries that are zero.
+    pub fn gradient(&self) -> &[f64] {
+        let length = self
+            .gradient
+            .iter()
+            .rposition(|derivative| *derivative != 0.0)
+            .map_or(0, |index| index + 1);
+        &self.gradient[..length]
+    }
+
+    /// Returns the partial derivative with respect to the seed variable with the given index.
+    pub fn derivative(&self, index: usize) -> f64 {
+        self.gradient.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the result of a function of `self` with the given value and derivative.
+    fn chain(&self, value: f64, derivative: f64) -> Self {
+        Dual {
+            value,
+            gradient: self
+                .gradient
+                .iter()
+                .map(|gradient| scale(*gradient, derivative))
+                .collect(),
+        }
+    }
+
+    /// Returns the result of a function of `self` and `other` with the given value and partial derivatives.
+    fn chain2(&self, other: &Self, value: f64, derivative: f64, other_derivative: f64) -> Self {
+        let length = self.gradient.len().max(other.gradient.len());
+        Dual {
+            value,
+            gradient: (0..length)
+                .map(|index| {
+                    scale(self.derivative(index), derivative)
+                        + scale(other.derivative(index), other_derivative)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Multiplies a derivative by a factor of the chain rule.
+/// A zero derivative stays zero, even if the factor is infinite or NaN.
+fn scale(derivative: f64, factor: f64) -> f64 {
+    if derivative == 0.0 {
+        0.0
+    } else {
+        derivative * factor
+    }
+}
+
+/// Parses a float, which becomes a constant.
+impl FromStr for Dual {
+    type Err = <f64 as FromStr>::Err;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        string.parse().map(Dual::constant)
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl PartialEq for Dual {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for Dual {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.chain2(&rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.chain2(&rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.chain2(&rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let quotient = self.value / rhs.value;
+        self.chain2(&rhs, quotient, 1.0 / rhs.value, -quotient / rhs.value)
+    }
+}
+
+impl Rem for Dual {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self.chain2(
+            &rhs,
+            self.value % rhs.value,
+            1.0,
+            -(self.value / rhs.value).trunc(),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for Dual {
+    fn neg_infinity() -> Self {
+        Dual::constant(f64::NEG_INFINITY)
+    }
+
+    fn infinity() -> Self {
+        Dual::constant(f64::INFINITY)
+    }
+
+    fn pow(&self, exponent: &Self) -> Self {
+        let power = self.value.powf(exponent.value);
+        // `x ^ 0` is constant, even at zero where `0 * x ^ -1` would be NaN.
+        let derivative = if exponent.value == 0.0 {
+            0.0
+        } else {
+            exponent.value * self.value.powf(exponent.value - 1.0)
+        };
+        self.chain2(exponent, power, derivative, power * self.value.ln())
+    }
+
+    fn ln(&self) -> Self {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn log(&self, base: &Self) -> Self {
+        <Self as EvalexprFloat<NumericTypes>>::ln(self)
+            / <Self as EvalexprFloat<NumericTypes>>::ln(base)
+    }
+
+    fn log2(&self) -> Self {
+        self.chain(self.value.log2(), 1.0 / (self.value * LN_2))
+    }
+
+    fn log10(&self) -> Self {
+        self.chain(self.value.log10(), 1.0 / (self.value * LN_10))
+    }
+
+    fn exp(&self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    fn exp2(&self) -> Self {
+        let exp2 = self.value.exp2();
+        self.chain(exp2, exp2 * LN_2)
+    }
+
+    fn cos(&self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn cosh(&self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    fn acos(&self) -> Self {
+        self.chain(
+            self.value.acos(),
+            -1.0 / (1.0 - self.value * self.value).sqrt(),
+        )
+    }
+
+    fn acosh(&self) -> Self {
+        self.chain(
+            self.value.acosh(),
+            1.0 / (self.value * self.value - 1.0).sqrt(),
+        )
+    }
+
+    fn sin(&self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn sinh(&self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    fn asin(&self) -> Self {
+        self.chain(
+            self.value.asin(),
+            1.0 / (1.0 - self.value * self.value).sqrt(),
+        )
+    }
+
+    fn asinh(&self) -> Self {
+        self.chain(
+            self.value.asinh(),
+            1.0 / (self.value * self.value + 1.0).sqrt(),
+        )
+    }
+
+    fn tan(&self) -> Self {
+        let tan = self.value.tan();
+        self.chain(tan, 1.0 + tan * tan)
+    }
+
+    fn tanh(&self) -> Self {
+        let tanh = self.value.tanh();
+        self.chain(tanh, 1.0 - tanh * tanh)
+    }
+
+    fn atan(&self) -> Self {
+        self.chain(self.value.atan(), 1.0 / (1.0 + self.value * self.value))
+    }
+
+    fn atanh(&self) -> Self {
+        self.chain(self.value.atanh(), 1.0 / (1.0 - self.value * self.value))
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        let squared_norm = x.value * x.value + self.value * self.value;
+        self.chain2(
+            x,
+            self.value.atan2(x.value),
+            x.value / squared_norm,
+            -self.value / squared_norm,
+        )
+    }
+
+    fn sqrt(&self) -> Self {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, 0.5 / sqrt)
+    }
+
+    fn cbrt(&self) -> Self {
+        let cbrt = self.value.cbrt();
+        self.chain(cbrt, 1.0 / (3.0 * cbrt * cbrt))
+    }
+
+    fn hypot(&self, other: &Self) -> Self {
+        let hypot = self.value.hypot(other.value);
+        self.chain2(other, hypot, self.value / hypot, other.value / hypot)
+    }
+
+    fn floor(&self) -> Self {
+        Dual::constant(self.value.floor())
+    }
+
+    fn round(&self) -> Self {
+        Dual::constant(self.value.round())
+    }
+
+    fn ceil(&self) -> Self {
+        Dual::constant(self.value.ceil())
+    }
+
+    fn trunc(&self) -> Self {
+        Dual::constant(self.value.trunc())
+    }
+
+    fn fract(&self) -> Self {
+        self.chain(self.value.fract(), 1.0)
+    }
+
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.chain(self.value.to_radians(), 1f64.to_radians()))
+    }
+
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.chain(self.value.to_degrees(), 1f64.to_degrees()))
+    }
+
+    fn is_nan(&self) -> bool {
+        self.value.is_nan()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.value.is_finite()
+    }
+
+    fn is_infinite(&self) -> bool {
+        self.value.is_infinite()
+    }
+
+    fn is_normal(&self) -> bool {
+        self.value.is_normal()
+    }
+
+    fn abs(&self) -> Self {
+        self.chain(self.value.abs(), self.value.signum())
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        if other.value < self.value || self.value.is_nan() {
+            other.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        if other.value > self.value || self.value.is_nan() {
+            other.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    fn random() -> EvalexprResult<Self, NumericTypes> {
+        <f64 as EvalexprFloat<crate::DefaultNumericTypes>>::random()
+            .map(Dual::constant)
+            .map_err(|_| EvalexprError::RandNotEnabled)
+    }
+}
@@ -0,0 +1,918 @@
+use std::{
+    cmp::Ordering,
+    f64::consts::{FRAC_PI_2, PI},
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    EvalexprError, EvalexprFloat, EvalexprNumericTypes, EvalexprResult, Function, RoundingMode,
+    Value,
+};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i64`] as its integer type and the interval [`Interval`] as its float type.
+/// Every float is a closed interval `[lo, hi]` of [`f64`]s that is guaranteed to contain the exact result,
+/// because all operations round the lower bound down and the upper bound up.
+/// For example, if `a`, `b` and `c` are intervals of the inputs of a formula like `a * b - c`,
+/// its result bounds every possible outcome.
+/// Float literals that are not integers become the smallest interval containing the decimal, so `0.1` is not a point.
+///
+/// Ordering intervals that overlap results in an [`EvalexprError::ComparisonError`], because the answer could be both true and false.
+/// `min` and `max` combine an int and an interval that overlap as intervals, so `min(interval(1, 3), 2)` is `[1, 2]`.
+/// Equality compares the bounds, so `interval(1, 2) == interval(1, 2)` holds.
+///
+/// The following builtin functions are available in addition to the generic ones:
+///
+/// | Identifier | Argument Amount | Argument Types | Description |
+/// |------------|-----------------|----------------|-------------|
+/// | `interval` | 2 | Numeric, Numeric | Returns the interval from the first to the second argument, or an `InvertedInterval` error if the second is smaller than the first |
+/// | `interval::around` | 2 | Numeric, Numeric | Returns the interval of all values whose distance from the first argument is at most the second argument |
+/// | `interval::lo` | 1 | Numeric | Returns the lower bound of the argument |
+/// | `interval::hi` | 1 | Numeric | Returns the upper bound of the argument |
+/// | `interval::width` | 1 | Numeric | Returns the width of the argument, which is the difference of its bounds |
+/// | `interval::midpoint` | 1 | Numeric | Returns the midpoint of the argument |
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IntervalNumericTypes;
+
+impl EvalexprNumericTypes for IntervalNumericTypes {
+    type Int = i64;
+    type Float = Interval;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        Interval::from_int(*int)
+    }
+
+    /// Truncates the midpoint of the interval, like the `as` operator does for [`f64`].
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        float.midpoint() as Self::Int
+    }
+
+    fn builtin_function(identifier: &str) -> Option<Function<Self>> {
+        fn unary(function: fn(Interval) -> Interval) -> Option<Function<IntervalNumericTypes>> {
+            Some(Function::new(
+                move |argument: &Value<IntervalNumericTypes>| {
+                    Ok(Value::Float(function(argument.as_number()?)))
+                },
+            ))
+        }
+
+        match identifier {
+            "interval" => Some(Function::new(|argument: &Value<Self>| {
+                let arguments = argument.as_fixed_len_tuple(2)?;
+                let (lo, hi) = (arguments[0].as_number()?, arguments[1].as_number()?);
+                if hi.hi < lo.lo {
+                    return Err(EvalexprError::InvertedInterval {
+                        lo: arguments[0].clone(),
+                        hi: arguments[1].clone(),
+                    });
+                }
+                Ok(Value::Float(Interval::new(lo.lo, hi.hi)))
+            })),
+            "interval::around" => Some(Function::new(|argument: &Value<Self>| {
+                let arguments = argument.as_fixed_len_tuple(2)?;
+                let (midpoint, radius) = (arguments[0].as_number()?, arguments[1].as_number()?);
+                let radius = Interval::new(-radius.hi.abs(), radius.hi.abs());
+                Ok(Value::Float(midpoint + radius))
+            })),
+            "interval::lo" => unary(|interval| Interval::point(interval.lo)),
+            "interval::hi" => unary(|interval| Interval::point(interval.hi)),
+            "interval::width" => {
+                unary(|interval| Interval::point(interval.hi) - Interval::point(interval.lo))
+            },
+            "interval::midpoint" => unary(|interval| Interval::point(interval.midpoint())),
+            _ => None,
+        }
+    }
+}
+
+/// A closed interval of [`f64`]s, used as the float type of [`IntervalNumericTypes`].
+///
+/// It is displayed like `[1.5, 2.5]`.
+/// An interval whose bounds are NaN represents an undefined result, like the square root of a negative interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+/// Returns the smallest float that is greater than `x`.
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Returns the largest float that is smaller than `x`.
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+/// Below this magnitude, the rounding error of a product or quotient may not be representable.
+fn tiny() -> f64 {
+    f64::MIN_POSITIVE * 2f64.powi(53)
+}
+
+/// Rounds down the result `x` of an operation, given the error `exact - x`.
+/// An error of NaN means that the error is unknown.
+fn round_down((x, error): (f64, f64)) -> f64 {
+    if error >= 0.0 {
+        x
+    } else {
+        next_down(x)
+    }
+}
+
+/// Rounds up the result `x` of an operation, given the error `exact - x`.
+/// An error of NaN means that the error is unknown.
+fn round_up((x, error): (f64, f64)) -> f64 {
+    if error <= 0.0 {
+        x
+    } else {
+        next_up(x)
+    }
+}
+
+/// Returns the error of a result that is infinite even though the operands were finite,
+/// which is the opposite infinity because the exact result is smaller.
+fn overflow_error(x: f64) -> f64 {
+    -x
+}
+
+/// Returns `a + b` and its rounding error.
+fn sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let error = if sum.is_infinite() && a.is_finite() && b.is_finite() {
+        overflow_error(sum)
+    } else if !sum.is_finite() {
+        0.0
+    } else {
+        // The rounding error of a sum is itself a float.
+        let b_rounded = sum - a;
+        (a - (sum - b_rounded)) + (b - b_rounded)
+    };
+    (sum, error)
+}
+
+/// Returns `a * b` and its rounding error.
+/// Zero times infinity is zero, because infinite bounds are never reached.
+fn product(a: f64, b: f64) -> (f64, f64) {
+    if a == 0.0 || b == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let product = a * b;
+    let error = if product.is_infinite() {
+        if a.is_finite() && b.is_finite() {
+            overflow_error(product)
+        } else {
+            0.0
+        }
+    } else if product.abs() < tiny() {
+        f64::NAN
+    } else {
+        a.mul_add(b, -product)
+    };
+    (product, error)
+}
+
+/// Returns `a / b` and the sign of its rounding error, for a non-zero `b`.
+fn quotient(a: f64, b: f64) -> (f64, f64) {
+    let quotient = a / b;
+    let error = if quotient.is_infinite() {
+        if a.is_finite() {
+            overflow_error(quotient)
+        } else {
+            0.0
+        }
+    } else if a == 0.0 || b.is_infinite() {
+        0.0
+    } else if quotient.abs() < tiny() || a.abs() < tiny() {
+        f64::NAN
+    } else {
+        // The remainder `a - quotient * b` is a float, and has the sign of the error if `b` is positive.
+        -quotient.mul_add(b, -a) * b.signum()
+    };
+    (quotient, error)
+}
+
+/// Returns the square root of `x` and the sign of its rounding error, for a non-negative `x`.
+fn square_root(x: f64) -> (f64, f64) {
+    let root = x.sqrt();
+    let error = if x == 0.0 || x.is_infinite() {
+        0.0
+    } else if x < tiny() {
+        f64::NAN
+    } else {
+        -root.mul_add(root, -x)
+    };
+    (root, error)
+}
+
+/// Returns the minimum of the floats, treating NaN as missing.
+fn min(values: impl IntoIterator<Item = f64>) -> f64 {
+    values.into_iter().fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the maximum of the floats, treating NaN as missing.
+fn max(values: impl IntoIterator<Item = f64>) -> f64 {
+    values.into_iter().fold(f64::NEG_INFINITY, f64::max)
+}
+
+impl Interval {
+    /// The interval of all floats.
+    const ENTIRE: Self = Interval {
+        lo: f64::NEG_INFINITY,
+        hi: f64::INFINITY,
+    };
+
+    /// The interval representing an undefined result.
+    const NAN: Self = Interval {
+        lo: f64::NAN,
+        hi: f64::NAN,
+    };
+
+    /// Creates the interval `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is greater than `hi`.
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(
+            lo.partial_cmp(&hi) != Some(Ordering::Greater),
+            "the interval [{lo}, {hi}] is empty"
+        );
+        Interval { lo, hi }
+    }
+
+    /// Creates the interval `[x, x]` that contains only `x`.
+    pub fn point(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    /// Returns the lower bound.
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the difference of the bounds, rounded up.
+    pub fn width(&self) -> f64 {
+        round_up(sum(self.hi, -self.lo))
+    }
+
+    /// Returns the float closest to the middle of the interval.
+    pub fn midpoint(&self) -> f64 {
+        if self.lo == f64::NEG_INFINITY && self.hi == f64::INFINITY {
+            0.0
+        } else if self.lo.is_finite() && self.hi.is_finite() {
+            self.lo / 2.0 + self.hi / 2.0
+        } else {
+            self.lo + self.hi
+        }
+    }
+
+    /// Returns true if `x` lies within the interval.
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Returns the interval `[lo, hi]`, or NaN if one of the bounds is NaN.
+    fn from_bounds(lo: f64, hi: f64) -> Self {
+        if lo.is_nan() || hi.is_nan() {
+            Self::NAN
+        } else {
+            Interval { lo, hi }
+        }
+    }
+
+    /// Returns the interval from the rounded-down `lo` to the rounded-up `hi`,
+    /// for bounds that were computed with rounding to the nearest float.
+    fn outward(lo: f64, hi: f64) -> Self {
+        Self::from_bounds(next_down(lo), next_up(hi))
+    }
+
+    /// Returns the smallest interval containing the integer.
+    fn from_int(int: i64) -> Self {
+        let x = int as f64;
+        // The float of `i64::MAX` is out of range for `i64`, so compare as `i128`.
+        match (x as i128).cmp(&(int as i128)) {
+            Ordering::Less => Interval::new(x, next_up(x)),
+            Ordering::Equal => Self::point(x),
+            Ordering::Greater => Interval::new(next_down(x), x),
+        }
+    }
+
+    /// Returns the smallest interval containing the decimal number, which is a point if the float is exact.
+    fn from_decimal(decimal: &str) -> Result<Self, ()> {
+        let x: f64 = decimal.parse().map_err(|_| ())?;
+        let digits = decimal.strip_prefix('-').unwrap_or(decimal);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        // A float has at most 1074 fractional digits, so if they match the decimal padded with zeros, it is exact.
+        let is_exact = x.is_finite()
+            && integer.bytes().all(|byte| byte.is_ascii_digit())
+            && fraction.bytes().all(|byte| byte.is_ascii_digit())
+            && format!("{:.1074}", x.abs()) == format!("{integer}.{fraction:0<1074}");
+        if is_exact {
+            Ok(Self::point(x))
+        } else {
+            Ok(Self::outward(x, x))
+        }
+    }
+
+    /// Returns the hull of the interval and the given float, or the interval if the float is NaN.
+    fn including(self, x: f64) -> Self {
+        Interval {
+            lo: self.lo.min(x),
+            hi: self.hi.max(x),
+        }
+    }
+
+    /// Restricts the interval to the domain `[lo, hi]` of a function, returning NaN if they do not overlap.
+    fn restrict(self, lo: f64, hi: f64) -> Option<Self> {
+        let restricted = Interval {
+            lo: self.lo.max(lo),
+            hi: self.hi.min(hi),
+        };
+        (restricted.lo <= restricted.hi).then_some(restricted)
+    }
+
+    /// Applies a non-decreasing function defined on `[domain_lo, domain_hi]` to the interval.
+    fn increasing(self, function: fn(f64) -> f64, domain_lo: f64, domain_hi: f64) -> Self {
+        match self.restrict(domain_lo, domain_hi) {
+            Some(x) => Self::outward(function(x.lo), function(x.hi)),
+            None => Self::NAN,
+        }
+    }
+
+    /// Applies a non-increasing function defined on `[domain_lo, domain_hi]` to the interval.
+    fn decreasing(self, function: fn(f64) -> f64, domain_lo: f64, domain_hi: f64) -> Self {
+        match self.restrict(domain_lo, domain_hi) {
+            Some(x) => Self::outward(function(x.hi), function(x.lo)),
+            None => Self::NAN,
+        }
+    }
+
+    /// Returns true if the interval might contain a number of the form `offset + k * period` for an integer `k`.
+    /// The constants of `offset` and `period` are rounded, so the check is generous at the bounds.
+    /// It is meant for intervals narrower than a few periods, and reports true for all intervals of very large floats.
+    fn contains_periodic(self, offset: f64, period: f64) -> bool {
+        let magnitude = self.lo.abs().max(self.hi.abs());
+        if magnitude >= 2f64.powi(50) {
+            return true;
+        }
+
+        let slack = magnitude * 1e-14 + f64::EPSILON;
+        let first = ((self.lo - offset) / period).floor() - 1.0;
+        let last = ((self.hi - offset) / period).ceil() + 1.0;
+        (first as i64..=last as i64)
+            .map(|k| offset + k as f64 * period)
+            .any(|x| self.lo - slack <= x && x <= self.hi + slack)
+    }
+
+    /// Applies a function with period `2π` that is bounded by `[-1, 1]`, has its maxima at `maximum + 2kπ` and its minima at
+    /// `minimum + 2kπ`, and is monotonic in between.
+    fn periodic(self, function: fn(f64) -> f64, maximum: f64, minimum: f64) -> Self {
+        if self.is_undefined_for_periodic() {
+            return Self::NAN;
+        }
+        if self.hi - self.lo >= 2.0 * PI {
+            return Interval::new(-1.0, 1.0);
+        }
+
+        let (lo, hi) = (function(self.lo), function(self.hi));
+        let mut result = Self::outward(lo.min(hi), lo.max(hi));
+        if self.contains_periodic(maximum, 2.0 * PI) {
+            result.hi = 1.0;
+        }
+        if self.contains_periodic(minimum, 2.0 * PI) {
+            result.lo = -1.0;
+        }
+        Interval {
+            lo: result.lo.max(-1.0),
+            hi: result.hi.min(1.0),
+        }
+    }
+
+    /// Raises the interval to an integer power by repeated multiplication.
+    fn powi(self, exponent: i64) -> Self {
+        fn point_powi(x: f64, exponent: u64) -> Interval {
+            let mut base = Interval::point(x);
+            let mut remaining = exponent;
+            let mut result = Interval::point(1.0);
+            while remaining > 0 {
+                if remaining % 2 == 1 {
+                    result = result * base;
+                }
+                remaining /= 2;
+                if remaining > 0 {
+                    base = base * base;
+                }
+            }
+            result
+        }
+
+        let magnitude = exponent.unsigned_abs();
+        let result = if magnitude % 2 == 1 {
+            // Odd powers are increasing.
+            Interval::new(
+                point_powi(self.lo, magnitude).lo,
+                point_powi(self.hi, magnitude).hi,
+            )
+        } else {
+            // Even powers are increasing in the absolute value.
+            let absolute = <Self as EvalexprFloat<IntervalNumericTypes>>::abs(&self);
+            Interval::new(
+                point_powi(absolute.lo, magnitude).lo,
+                point_powi(absolute.hi, magnitude).hi,
+            )
+        };
+
+        if exponent < 0 {
+            Interval::point(1.0) / result
+        } else {
+            result
+        }
+    }
+
+    fn is_nan_interval(&self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    /// Returns true if the interval is NaN or an infinite point, where periodic functions are undefined.
+    fn is_undefined_for_periodic(&self) -> bool {
+        self.is_nan_interval() || (self.lo == self.hi && self.lo.is_infinite())
+    }
+}
+
+/// Parses intervals like `[1.5, 2.5]`, or a single decimal like `0.1`, which becomes the smallest interval containing it.
+impl FromStr for Interval {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let parse = |string: &str| Interval::from_decimal(string.trim());
+        match string
+            .trim()
+            .strip_prefix('[')
+            .and_then(|string| string.strip_suffix(']'))
+        {
+            Some(bounds) => {
+                let (lo, hi) = bounds.split_once(',').ok_or(())?;
+                let (lo, hi) = (parse(lo)?, parse(hi)?);
+                if lo.lo <= hi.hi {
+                    Ok(Interval::new(lo.lo, hi.hi))
+                } else {
+                    Err(())
+                }
+            },
+            None => parse(string),
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "[{:.*}, {:.*}]", precision, self.lo, precision, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+/// Intervals are ordered if they do not overlap, and equal if they are the same point.
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else if self.lo == self.hi && self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_bounds(
+            round_down(sum(self.lo, rhs.lo)),
+            round_up(sum(self.hi, rhs.hi)),
+        )
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_nan_interval() || rhs.is_nan_interval() {
+            return Self::NAN;
+        }
+
+        let products = [
+            product(self.lo, rhs.lo),
+            product(self.lo, rhs.hi),
+            product(self.hi, rhs.lo),
+            product(self.hi, rhs.hi),
+        ];
+        Self::from_bounds(min(products.map(round_down)), max(products.map(round_up)))
+    }
+}
+
+/// Dividing by an interval that contains zero results in the interval of all floats.
+impl Div for Interval {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if self.is_nan_interval() || rhs.is_nan_interval() || rhs == Self::point(0.0) {
+            Self::NAN
+        } else if rhs.contains(0.0) {
+            Self::ENTIRE
+        } else {
+            let quotients = [
+                quotient(self.lo, rhs.lo),
+                quotient(self.lo, rhs.hi),
+                quotient(self.hi, rhs.lo),
+                quotient(self.hi, rhs.hi),
+            ];
+            Self::from_bounds(min(quotients.map(round_down)), max(quotients.map(round_up)))
+        }
+    }
+}
+
+/// The remainder of points is exact.
+/// For other intervals, the result is bounded by the dividend and the absolute value of the divisor.
+impl Rem for Interval {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        if self.is_nan_interval() || rhs.is_nan_interval() || rhs.contains(0.0) {
+            Self::NAN
+        } else if self.lo == self.hi && rhs.lo == rhs.hi {
+            Self::point(self.lo % rhs.lo)
+        } else {
+            let divisor = rhs.lo.abs().max(rhs.hi.abs());
+            Interval {
+                lo: if self.lo >= 0.0 {
+                    0.0
+                } else {
+                    self.lo.max(-divisor)
+                },
+                hi: if self.hi <= 0.0 {
+                    0.0
+                } else {
+                    self.hi.min(divisor)
+                },
+            }
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for Interval {
+    fn neg_infinity() -> Self {
+        Self::point(f64::NEG_INFINITY)
+    }
+
+    fn infinity() -> Self {
+        Self::point(f64::INFINITY)
+    }
+
+    fn pow(&self, exponent: &Self) -> Self {
+        if exponent.lo == exponent.hi
+            && exponent.lo.fract() == 0.0
+            && exponent.lo.abs() <= i64::MAX as f64
+        {
+            return self.powi(exponent.lo as i64);
+        }
+
+        // `x^y` is monotonic in both `x` and `y` for positive `x`, so the extremes are at the corners.
+        match self.restrict(0.0, f64::INFINITY) {
+            Some(base) if !exponent.is_nan_interval() => {
+                let powers = [
+                    base.lo.powf(exponent.lo),
+                    base.lo.powf(exponent.hi),
+                    base.hi.powf(exponent.lo),
+                    base.hi.powf(exponent.hi),
+                ];
+                Self::outward(min(powers), max(powers))
+            },
+            _ => Self::NAN,
+        }
+    }
+
+    fn ln(&self) -> Self {
+        self.increasing(f64::ln, 0.0, f64::INFINITY)
+    }
+
+    fn log(&self, base: &Self) -> Self {
+        <Self as EvalexprFloat<NumericTypes>>::ln(self)
+            / <Self as EvalexprFloat<NumericTypes>>::ln(base)
+    }
+
+    fn log2(&self) -> Self {
+        self.increasing(f64::log2, 0.0, f64::INFINITY)
+    }
+
+    fn log10(&self) -> Self {
+        self.increasing(f64::log10, 0.0, f64::INFINITY)
+    }
+
+    fn exp(&self) -> Self {
+        self.increasing(f64::exp, f64::NEG_INFINITY, f64::INFINITY)
+            .restrict(0.0, f64::INFINITY)
+            .unwrap_or(Self::NAN)
+    }
+
+    fn exp2(&self) -> Self {
+        self.increasing(f64::exp2, f64::NEG_INFINITY, f64::INFINITY)
+            .restrict(0.0, f64::INFINITY)
+            .unwrap_or(Self::NAN)
+    }
+
+    fn cos(&self) -> Self {
+        self.periodic(f64::cos, 0.0, PI)
+    }
+
+    fn cosh(&self) -> Self {
+        let absolute = <Self as EvalexprFloat<NumericTypes>>::abs(self);
+        absolute
+            .increasing(f64::cosh, 0.0, f64::INFINITY)
+            .restrict(1.0, f64::INFINITY)
+            .unwrap_or(Self::NAN)
+    }
+
+    fn acos(&self) -> Self {
+        self.decreasing(f64::acos, -1.0, 1.0)
+    }
+
+    fn acosh(&self) -> Self {
+        self.increasing(f64::acosh, 1.0, f64::INFINITY)
+    }
+
+    fn sin(&self) -> Self {
+        self.periodic(f64::sin, FRAC_PI_2, -FRAC_PI_2)
+    }
+
+    fn sinh(&self) -> Self {
+        self.increasing(f64::sinh, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    fn asin(&self) -> Self {
+        self.increasing(f64::asin, -1.0, 1.0)
+    }
+
+    fn asinh(&self) -> Self {
+        self.increasing(f64::asinh, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Results in the interval of all floats if the interval contains a pole.
+    fn tan(&self) -> Self {
+        if self.is_undefined_for_periodic() {
+            Self::NAN
+        } else if self.hi - self.lo >= PI || self.contains_periodic(FRAC_PI_2, PI) {
+            Self::ENTIRE
+        } else {
+            self.increasing(f64::tan, f64::NEG_INFINITY, f64::INFINITY)
+        }
+    }
+
+    fn tanh(&self) -> Self {
+        self.increasing(f64::tanh, f64::NEG_INFINITY, f64::INFINITY)
+            .restrict(-1.0, 1.0)
+            .unwrap_or(Self::NAN)
+    }
+
+    fn atan(&self) -> Self {
+        self.increasing(f64::atan, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    fn atanh(&self) -> Self {
+        self.increasing(f64::atanh, -1.0, 1.0)
+    }
+
+    /// Results in `[-π, π]` if the interval `x` reaches the negative real axis or zero.
+    fn atan2(&self, x: &Self) -> Self {
+        if self.is_nan_interval() || x.is_nan_interval() {
+            Self::NAN
+        } else if x.lo <= 0.0 && self.contains(0.0) {
+            Self::outward(-PI, PI)
+        } else {
+            let angles = [
+                self.lo.atan2(x.lo),
+                self.lo.atan2(x.hi),
+                self.hi.atan2(x.lo),
+                self.hi.atan2(x.hi),
+            ];
+            Self::outward(min(angles), max(angles))
+        }
+    }
+
+    fn sqrt(&self) -> Self {
+        match self.restrict(0.0, f64::INFINITY) {
+            Some(x) => Interval {
+                lo: round_down(square_root(x.lo)),
+                hi: round_up(square_root(x.hi)),
+            },
+            None => Self::NAN,
+        }
+    }
+
+    fn cbrt(&self) -> Self {
+        self.increasing(f64::cbrt, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    fn hypot(&self, other: &Self) -> Self {
+        let (a, b) = (
+            <Self as EvalexprFloat<NumericTypes>>::abs(self),
+            <Self as EvalexprFloat<NumericTypes>>::abs(other),
+        );
+        if a.is_nan_interval() || b.is_nan_interval() {
+            Self::NAN
+        } else {
+            Self::outward(a.lo.hypot(b.lo), a.hi.hypot(b.hi))
+                .restrict(0.0, f64::INFINITY)
+                .unwrap_or(Self::NAN)
+        }
+    }
+
+    fn floor(&self) -> Self {
+        Interval {
+            lo: self.lo.floor(),
+            hi: self.hi.floor(),
+        }
+    }
+
+    fn round(&self) -> Self {
+        Interval {
+            lo: self.lo.round(),
+            hi: self.hi.round(),
+        }
+    }
+
+    fn ceil(&self) -> Self {
+        Interval {
+            lo: self.lo.ceil(),
+            hi: self.hi.ceil(),
+        }
+    }
+
+    fn trunc(&self) -> Self {
+        Interval {
+            lo: self.lo.trunc(),
+            hi: self.hi.trunc(),
+        }
+    }
+
+    fn fract(&self) -> Self {
+        if self.is_nan_interval() {
+            Self::NAN
+        } else if self.lo.trunc() == self.hi.trunc() && (self.lo >= 0.0 || self.hi <= 0.0) {
+            Interval {
+                lo: self.lo.fract(),
+                hi: self.hi.fract(),
+            }
+        } else {
+            Interval {
+                lo: if self.lo < 0.0 { -1.0 } else { 0.0 },
+                hi: if self.hi > 0.0 { 1.0 } else { 0.0 },
+            }
+        }
+    }
+
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self * Self::outward(PI / 180.0, PI / 180.0))
+    }
+
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self * Self::outward(180.0 / PI, 180.0 / PI))
+    }
+
+    fn is_nan(&self) -> bool {
+        self.is_nan_interval()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    fn is_infinite(&self) -> bool {
+        self.lo.is_infinite() || self.hi.is_infinite()
+    }
+
+    /// Returns true if both bounds are normal and have the same sign, so every float in the interval is normal.
+    fn is_normal(&self) -> bool {
+        self.lo.is_normal() && self.hi.is_normal() && (self.lo > 0.0) == (self.hi > 0.0)
+    }
+
+    fn abs(&self) -> Self {
+        if self.lo >= 0.0 {
+            *self
+        } else if self.hi <= 0.0 {
+            -*self
+        } else {
+            Interval::point(0.0).including(-self.lo).including(self.hi)
+        }
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.min(other.hi),
+        }
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.max(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    fn random() -> EvalexprResult<Self, NumericTypes> {
+        <f64 as EvalexprFloat<crate::DefaultNumericTypes>>::random()
+            .map(Interval::point)
+            .map_err(|_| EvalexprError::RandNotEnabled)
+    }
+
+    /// Returns an error if the divisor contains zero.
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.contains(0.0) {
+            Err(EvalexprError::modulation_error(
+                Value::Float(*self),
+                Value::Float(*rhs),
+            ))
+        } else {
+            Ok(*self % *rhs)
+        }
+    }
+
+    /// Returns an error if the intervals overlap, unless they are the same point.
+    fn checked_partial_cmp(&self, rhs: &Self) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+        if self.is_nan_interval() || rhs.is_nan_interval() {
+            Ok(None)
+        } else {
+            self.partial_cmp(rhs).map(Some).ok_or_else(|| {
+                EvalexprError::comparison_error(Value::Float(*self), Value::Float(*rhs))
+            })
+        }
+    }
+
+    /// Returns an error if the divisor contains zero.
+    /// Intervals are always rounded outwards, so the rounding mode is ignored.
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        _rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        if rhs.contains(0.0) {
+            Err(EvalexprError::division_error(
+                Value::Float(*self),
+                Value::Float(*rhs),
+            ))
+        } else {
+            Ok(*self / *rhs)
+        }
+    }
+
+    /// Accepts only single decimals, which become the smallest interval containing them.
+    fn from_literal(literal: &str) -> Result<Self, ()> {
+        Interval::from_decimal(literal)
+    }
+}
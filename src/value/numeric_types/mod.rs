@@ -2,6 +2,8 @@
 use std::fmt::Debug;
 use cfg_if::cfg_if;
 
+use crate::Function;
+
 cfg_if! {
     if #[cfg(feature = "num_primitive")] {
         mod with_num_primitive;
@@ -18,6 +20,54 @@ cfg_if! {
     }
 }
 
+// Arbitrary-precision integers can't implement the primitive integer traits required with `num_primitive`.
+cfg_if! {
+    if #[cfg(all(feature = "bigint", not(feature = "num_primitive")))] {
+        mod bigint;
+        pub use bigint::{BigIntNumericTypes, BIGINT_MAXIMUM_LEFT_SHIFT, BIGINT_MAXIMUM_PRODUCT_BITS};
+    }
+}
+
+// Decimals have no NaN or infinities, so they can't implement the primitive float traits required with `num_primitive`.
+cfg_if! {
+    if #[cfg(all(feature = "decimal", not(feature = "num_primitive")))] {
+        mod decimal;
+        pub use decimal::DecimalNumericTypes;
+    }
+}
+
+// Like decimals, fractions are not primitive floats.
+cfg_if! {
+    if #[cfg(all(feature = "rational", not(feature = "num_primitive")))] {
+        mod rational;
+        pub use rational::{Rational, RationalNumericTypes};
+    }
+}
+
+// Complex numbers can't be ordered, so they are not primitive floats either.
+cfg_if! {
+    if #[cfg(all(feature = "complex", not(feature = "num_primitive")))] {
+        mod complex;
+        pub use complex::{Complex, ComplexNumericTypes};
+    }
+}
+
+// Intervals only have a partial order, so the same applies to them.
+cfg_if! {
+    if #[cfg(all(feature = "interval", not(feature = "num_primitive")))] {
+        mod interval;
+        pub use interval::{Interval, IntervalNumericTypes};
+    }
+}
+
+// Dual numbers carry a gradient, so they are not primitive floats.
+cfg_if! {
+    if #[cfg(all(feature = "dual", not(feature = "num_primitive")))] {
+        mod dual;
+        pub use dual::{Dual, DualNumericTypes};
+    }
+}
+
 
 /// A trait to parameterise `evalexpr` with an int type and a float type.
 ///
@@ -44,6 +94,67 @@ pub trait EvalexprNumericTypes: 'static + Sized + Debug + Clone + PartialEq + Sy
 
     /// Convert a float to an integer using the `as` operator or a similar mechanic.
     fn float_as_int(float: &Self::Float) -> Self::Int;
+
+    /// Returns true if dividing two integers that are not divisible results in an exact float
+    /// instead of an integer rounded towards zero.
+    ///
+    /// The default implementation returns false, so for example `7 / 2` evaluates to `3`.
+    fn exact_int_division() -> bool {
+        false
+    }
+
+    /// Returns the builtin function with the given identifier that is specific to these numeric types,
+    /// like `re` for complex numbers.
+    /// These functions take precedence over the generic builtin functions.
+    ///
+    /// The default implementation returns `None`.
+    fn builtin_function(_identifier: &str) -> Option<Function<Self>> {
+        None
+    }
+}
+
+/// How the result of an arithmetic operation is rounded if it cannot be represented exactly.
+///
+/// The rounding mode of an evaluation is given by [`Context::rounding_mode`](crate::Context::rounding_mode).
+/// Float types that round according to their own rules, like [`f64`], ignore it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+    /// Round to the nearest value, and to the value with an even last digit if both are equally near.
+    /// This is also known as banker's rounding.
+    #[default]
+    NearestEven,
+    /// Round to the nearest value, and away from zero if both are equally near.
+    NearestAwayFromZero,
+    /// Round to the nearest value, and towards zero if both are equally near.
+    NearestTowardZero,
+    /// Round towards zero.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+}
+
+/// What happens if the result of an integer operation does not fit into the integer type.
+///
+/// The policy of an evaluation is given by [`Context::int_overflow_policy`](crate::Context::int_overflow_policy).
+/// It applies to the integer operators `+`, `-`, `*`, `/` and `%` and their assignment variants, and to negation.
+/// Dividing by zero is not an overflow, so it always results in an error.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntOverflowPolicy {
+    /// Return an error like [`EvalexprError::AdditionError`](crate::EvalexprError::AdditionError).
+    #[default]
+    Error,
+    /// Wrap around at the boundaries of the integer type, like two's complement arithmetic does.
+    Wrap,
+    /// Return the minimum or maximum value of the integer type, whichever is closer to the exact result.
+    Saturate,
+    /// Convert the operands to floats and return the result of the float operation.
+    PromoteToFloat,
 }
 
 /// See [`EvalexprNumericTypes`].
@@ -65,3 +176,64 @@ impl EvalexprNumericTypes for DefaultNumericTypes {
     }
 }
 
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i32`] as its integer type and [`f32`] as its float type,
+/// for targets where 64-bit arithmetic is expensive or where the data is 32-bit anyway.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct I32F32NumericTypes;
+
+impl EvalexprNumericTypes for I32F32NumericTypes {
+    type Int = i32;
+    type Float = f32;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        *int as Self::Float
+    }
+
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        *float as Self::Int
+    }
+}
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`u64`] as its integer type and [`f64`] as its float type.
+/// Negating a positive integer results in [`EvalexprError::NegationError`](crate::EvalexprError::NegationError).
+/// Unsuffixed integer literals in [`context_map!`](crate::context_map) are [`i32`]s, which don't convert into [`u64`],
+/// so they need a suffix like `"x" => int 5u64`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct U64F64NumericTypes;
+
+impl EvalexprNumericTypes for U64F64NumericTypes {
+    type Int = u64;
+    type Float = f64;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        *int as Self::Float
+    }
+
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        *float as Self::Int
+    }
+}
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i128`] as its integer type and [`f64`] as its float type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct I128F64NumericTypes;
+
+impl EvalexprNumericTypes for I128F64NumericTypes {
+    type Int = i128;
+    type Float = f64;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        *int as Self::Float
+    }
+
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        *float as Self::Int
+    }
+}
+
@@ -0,0 +1,653 @@
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    EvalexprError, EvalexprFloat, EvalexprNumericTypes, EvalexprResult, RoundingMode, Value,
+};
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, ToPrimitive, Zero};
+
+/// See [`EvalexprNumericTypes`].
+///
+/// This empty struct uses [`i64`] as its integer type and the exact fraction [`Rational`] as its float type.
+/// Dividing two integers that are not divisible results in a fraction, so `1/3 + 1/6` evaluates to `1/2`.
+/// Float literals like `0.1` are converted into fractions exactly, if numerator and denominator fit into an [`i64`].
+///
+/// Powers with integer exponents, square roots of perfect squares and rounding are computed exactly.
+/// All other functions, like logarithms and trigonometric functions, are approximated with [`f64`].
+/// Fractions have no NaN and no infinities, so math functions like `math::sqrt(-1.0)` or `math::exp(100.0)` whose results
+/// are undefined or out of the range of [`i64`] result in a [`EvalexprError::MathFunctionError`].
+/// When called directly, the infallible [`EvalexprFloat`] methods map such results to zero or the largest or smallest integer instead.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RationalNumericTypes;
+
+impl EvalexprNumericTypes for RationalNumericTypes {
+    type Int = i64;
+    type Float = Rational;
+
+    fn int_as_float(int: &Self::Int) -> Self::Float {
+        Rational(Ratio::from_integer(*int))
+    }
+
+    /// Truncates the fraction towards zero.
+    fn float_as_int(float: &Self::Float) -> Self::Int {
+        float.0.to_integer()
+    }
+
+    fn exact_int_division() -> bool {
+        true
+    }
+}
+
+/// An exact fraction of two [`i64`]s, used as the float type of [`RationalNumericTypes`].
+///
+/// It is displayed as a fraction like `1/2`, or as a decimal like `0.50` if a precision is given,
+/// for example in the format string `f"{x:.2}"`.
+/// The arithmetic operators of this type approximate results that overflow with [`f64`],
+/// while the operators of expressions return an error instead.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Rational(pub Ratio<i64>);
+
+impl Rational {
+    /// The largest fraction used in place of positive infinity.
+    const MAX: Self = Rational(Ratio::new_raw(i64::MAX, 1));
+
+    /// The smallest fraction used in place of negative infinity.
+    const MIN: Self = Rational(Ratio::new_raw(-i64::MAX, 1));
+
+    /// Creates the reduced fraction `numer / denom`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero.
+    pub fn new(numer: i64, denom: i64) -> Self {
+        Rational(Ratio::new(numer, denom))
+    }
+
+    /// Returns the numerator of the reduced fraction.
+    pub fn numer(&self) -> i64 {
+        *self.0.numer()
+    }
+
+    /// Returns the denominator of the reduced fraction, which is always positive.
+    pub fn denom(&self) -> i64 {
+        *self.0.denom()
+    }
+
+    /// Converts the given float into a fraction, mapping NaN to zero and saturating at the bounds of [`i64`].
+    fn from_f64(float: f64) -> Self {
+        if float.is_nan() {
+            Rational(Ratio::zero())
+        } else if float >= i64::MAX as f64 {
+            Self::MAX
+        } else if float <= -i64::MAX as f64 {
+            Self::MIN
+        } else {
+            Ratio::approximate_float(float)
+                .map(Rational)
+                .unwrap_or_else(|| Rational(Ratio::zero()))
+        }
+    }
+
+    /// Converts the given float into a fraction, or returns `None` if it is NaN, infinite or out of the range of [`i64`].
+    fn checked_from_f64(float: f64) -> Option<Self> {
+        if float.is_nan() || float >= i64::MAX as f64 || float <= -i64::MAX as f64 {
+            None
+        } else {
+            Some(Self::from_f64(float))
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0.to_f64().unwrap_or_default()
+    }
+
+    /// Approximates the given function of a fraction with [`f64`].
+    fn approximate(self, function: impl FnOnce(f64) -> f64) -> Self {
+        Self::from_f64(function(self.to_f64()))
+    }
+
+    /// Approximates the given function of two fractions with [`f64`].
+    fn approximate2(self, other: Self, function: impl FnOnce(f64, f64) -> f64) -> Self {
+        Self::from_f64(function(self.to_f64(), other.to_f64()))
+    }
+
+    /// Approximates the math function `name` of a fraction with [`f64`],
+    /// returning an error if the result is NaN, infinite or out of the range of [`i64`].
+    fn checked_approximate<NumericTypes: EvalexprNumericTypes<Float = Self>>(
+        self,
+        name: &str,
+        function: impl FnOnce(f64) -> f64,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        Self::checked_from_f64(function(self.to_f64()))
+            .ok_or_else(|| EvalexprError::math_function_error(name, Value::Float(self)))
+    }
+
+    /// Approximates the math function `name` of two fractions with [`f64`],
+    /// returning an error if the result is NaN, infinite or out of the range of [`i64`].
+    fn checked_approximate2<NumericTypes: EvalexprNumericTypes<Float = Self>>(
+        self,
+        other: Self,
+        name: &str,
+        function: impl FnOnce(f64, f64) -> f64,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        Self::checked_from_f64(function(self.to_f64(), other.to_f64())).ok_or_else(|| {
+            EvalexprError::math_function_error(
+                name,
+                Value::Tuple(vec![Value::Float(self), Value::Float(other)]),
+            )
+        })
+    }
+
+    /// Computes the remainder of the division truncated towards zero, like the `%` operator of primitive types.
+    fn checked_remainder(self, rhs: Self) -> Option<Self> {
+        let quotient = self.0.checked_div(&rhs.0)?.trunc();
+        Some(Rational(
+            self.0.checked_sub(&rhs.0.checked_mul(&quotient)?)?,
+        ))
+    }
+
+    /// Raises the fraction to the given integer power.
+    fn checked_powi(self, exponent: i64) -> Option<Self> {
+        let mut base = self.0;
+        let mut remaining = exponent.unsigned_abs();
+        let mut result = Ratio::from_integer(1);
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result.checked_mul(&base)?;
+            }
+            remaining /= 2;
+            if remaining > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+
+        if exponent < 0 {
+            Ratio::from_integer(1).checked_div(&result).map(Rational)
+        } else {
+            Some(Rational(result))
+        }
+    }
+
+    /// Returns the square root of the fraction if numerator and denominator are perfect squares.
+    fn exact_sqrt(self) -> Option<Self> {
+        fn integer_sqrt(int: i64) -> Option<i64> {
+            let estimate = (int as f64).sqrt() as i64;
+            (estimate.saturating_sub(1)..=estimate.saturating_add(1))
+                .find(|root| i64::checked_mul(*root, *root) == Some(int))
+        }
+
+        if self.0.is_negative() {
+            return None;
+        }
+        Some(Rational(Ratio::new_raw(
+            integer_sqrt(self.numer())?,
+            integer_sqrt(self.denom())?,
+        )))
+    }
+
+    /// Writes the fraction as a decimal with `precision` digits after the decimal point,
+    /// rounding to the nearest decimal and to an even last digit if both are equally near.
+    fn to_decimal_string(self, precision: usize) -> String {
+        let numer = u128::from(self.numer().unsigned_abs());
+        let denom = u128::from(self.denom().unsigned_abs());
+        let mut integer = numer / denom;
+        let mut remainder = numer % denom;
+        let mut fraction = Vec::with_capacity(precision);
+        for _ in 0..precision {
+            remainder *= 10;
+            fraction.push((remainder / denom) as u8);
+            remainder %= denom;
+        }
+
+        let last_digit_odd = fraction
+            .last()
+            .map_or(integer % 2 == 1, |digit| digit % 2 == 1);
+        if remainder * 2 > denom || (remainder * 2 == denom && last_digit_odd) {
+            // Round up, carrying over nines.
+            let mut carry = true;
+            for digit in fraction.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                integer += 1;
+            }
+        }
+
+        let mut result = integer.to_string();
+        if precision > 0 {
+            result.push('.');
+            result.extend(fraction.iter().map(|digit| char::from(b'0' + digit)));
+        }
+        result
+    }
+}
+
+/// Parses a decimal literal like `-12.5` or `6.02e-3` into a fraction,
+/// or returns `None` if it is malformed or the fraction does not fit into an [`i64`].
+fn parse_decimal(literal: &str) -> Option<Ratio<i64>> {
+    let (mantissa, exponent) = match literal.find(['e', 'E']) {
+        Some(index) => (&literal[..index], literal[index + 1..].parse::<i64>().ok()?),
+        None => (literal, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integer}{fraction}");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let numer = digits.parse::<i64>().ok()?;
+    let numer = if negative { -numer } else { numer };
+    let scale = i64::try_from(fraction.len()).ok()?.checked_sub(exponent)?;
+    let power = 10i64.checked_pow(u32::try_from(scale.unsigned_abs()).ok()?)?;
+    if scale >= 0 {
+        Some(Ratio::new(numer, power))
+    } else {
+        numer.checked_mul(power).map(Ratio::from_integer)
+    }
+}
+
+/// Parses a fraction like `1/3`, or a decimal like `0.25` or `1e-3`.
+/// Decimals whose exact fraction does not fit into [`i64`]s are approximated.
+impl FromStr for Rational {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some((numer, denom)) = string.split_once('/') {
+            let numer = numer.trim().parse::<i64>().map_err(|_| ())?;
+            let denom = denom.trim().parse::<i64>().map_err(|_| ())?;
+            if denom == 0 {
+                Err(())
+            } else {
+                Ok(Rational::new(numer, denom))
+            }
+        } else if let Some(ratio) = parse_decimal(string) {
+            Ok(Rational(ratio))
+        } else {
+            match string.parse::<f64>() {
+                Ok(float) if float.is_finite() && float.abs() < i64::MAX as f64 => {
+                    Ok(Rational::from_f64(float))
+                },
+                _ => Err(()),
+            }
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => f.pad_integral(
+                !self.0.is_negative(),
+                "",
+                &self.to_decimal_string(precision),
+            ),
+            None => fmt::Display::fmt(&self.0, f),
+        }
+    }
+}
+
+macro_rules! impl_operator {
+    ($trait:ident, $method:ident, $checked:expr, $approximate:expr) => {
+        impl $trait for Rational {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                $checked(self, rhs).unwrap_or_else(|| self.approximate2(rhs, $approximate))
+            }
+        }
+    };
+}
+
+impl_operator!(
+    Add,
+    add,
+    |a: Self, b: Self| a.0.checked_add(&b.0).map(Rational),
+    |a, b| a + b
+);
+impl_operator!(
+    Sub,
+    sub,
+    |a: Self, b: Self| a.0.checked_sub(&b.0).map(Rational),
+    |a, b| a - b
+);
+impl_operator!(
+    Mul,
+    mul,
+    |a: Self, b: Self| a.0.checked_mul(&b.0).map(Rational),
+    |a, b| a * b
+);
+impl_operator!(
+    Div,
+    div,
+    |a: Self, b: Self| a.0.checked_div(&b.0).map(Rational),
+    |a, b| a / b
+);
+impl_operator!(Rem, rem, Self::checked_remainder, |a, b| a % b);
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Rational(Ratio::new_raw(
+            self.numer().checked_neg().unwrap_or(i64::MAX),
+            self.denom(),
+        ))
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for Rational {
+    fn neg_infinity() -> Self {
+        Self::MIN
+    }
+
+    fn infinity() -> Self {
+        Self::MAX
+    }
+
+    /// Computed exactly if the exponent is an integer.
+    fn pow(&self, exponent: &Self) -> Self {
+        if exponent.0.is_integer() {
+            self.checked_powi(exponent.numer())
+        } else {
+            None
+        }
+        .unwrap_or_else(|| self.approximate2(*exponent, f64::powf))
+    }
+
+    fn ln(&self) -> Self {
+        self.approximate(f64::ln)
+    }
+
+    fn log(&self, base: &Self) -> Self {
+        self.approximate2(*base, f64::log)
+    }
+
+    fn log2(&self) -> Self {
+        self.approximate(f64::log2)
+    }
+
+    fn log10(&self) -> Self {
+        self.approximate(f64::log10)
+    }
+
+    fn exp(&self) -> Self {
+        self.approximate(f64::exp)
+    }
+
+    fn exp2(&self) -> Self {
+        <Self as EvalexprFloat<NumericTypes>>::pow(&Rational(Ratio::from_integer(2)), self)
+    }
+
+    fn cos(&self) -> Self {
+        self.approximate(f64::cos)
+    }
+
+    fn cosh(&self) -> Self {
+        self.approximate(f64::cosh)
+    }
+
+    fn acos(&self) -> Self {
+        self.approximate(f64::acos)
+    }
+
+    fn acosh(&self) -> Self {
+        self.approximate(f64::acosh)
+    }
+
+    fn sin(&self) -> Self {
+        self.approximate(f64::sin)
+    }
+
+    fn sinh(&self) -> Self {
+        self.approximate(f64::sinh)
+    }
+
+    fn asin(&self) -> Self {
+        self.approximate(f64::asin)
+    }
+
+    fn asinh(&self) -> Self {
+        self.approximate(f64::asinh)
+    }
+
+    fn tan(&self) -> Self {
+        self.approximate(f64::tan)
+    }
+
+    fn tanh(&self) -> Self {
+        self.approximate(f64::tanh)
+    }
+
+    fn atan(&self) -> Self {
+        self.approximate(f64::atan)
+    }
+
+    fn atanh(&self) -> Self {
+        self.approximate(f64::atanh)
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        self.approximate2(*x, f64::atan2)
+    }
+
+    /// Computed exactly if numerator and denominator are perfect squares.
+    fn sqrt(&self) -> Self {
+        self.exact_sqrt()
+            .unwrap_or_else(|| self.approximate(f64::sqrt))
+    }
+
+    fn cbrt(&self) -> Self {
+        self.approximate(f64::cbrt)
+    }
+
+    fn hypot(&self, other: &Self) -> Self {
+        self.0
+            .checked_mul(&self.0)
+            .zip(other.0.checked_mul(&other.0))
+            .and_then(|(a, b)| a.checked_add(&b))
+            .and_then(|sum| Rational(sum).exact_sqrt())
+            .unwrap_or_else(|| self.approximate2(*other, f64::hypot))
+    }
+
+    fn floor(&self) -> Self {
+        Rational(self.0.floor())
+    }
+
+    fn round(&self) -> Self {
+        Rational(self.0.round())
+    }
+
+    fn ceil(&self) -> Self {
+        Rational(self.0.ceil())
+    }
+
+    fn trunc(&self) -> Self {
+        Rational(self.0.trunc())
+    }
+
+    fn fract(&self) -> Self {
+        Rational(self.0.fract())
+    }
+
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.approximate(f64::to_radians))
+    }
+
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.approximate(f64::to_degrees))
+    }
+
+    /// Fractions are never NaN.
+    fn is_nan(&self) -> bool {
+        false
+    }
+
+    /// Fractions are always finite.
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Fractions are never infinite.
+    fn is_infinite(&self) -> bool {
+        false
+    }
+
+    /// Returns true if `self` is not zero.
+    fn is_normal(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn abs(&self) -> Self {
+        if self.0.is_negative() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        Ord::min(*self, *other)
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        Ord::max(*self, *other)
+    }
+
+    fn random() -> EvalexprResult<Self, NumericTypes> {
+        <f64 as EvalexprFloat<crate::DefaultNumericTypes>>::random()
+            .map(Rational::from_f64)
+            .map_err(|_| EvalexprError::RandNotEnabled)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.0
+            .checked_add(&rhs.0)
+            .map(Rational)
+            .ok_or_else(|| EvalexprError::addition_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.0.checked_sub(&rhs.0).map(Rational).ok_or_else(|| {
+            EvalexprError::subtraction_error(Value::Float(*self), Value::Float(*rhs))
+        })
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.0.checked_mul(&rhs.0).map(Rational).ok_or_else(|| {
+            EvalexprError::multiplication_error(Value::Float(*self), Value::Float(*rhs))
+        })
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_remainder(*rhs)
+            .ok_or_else(|| EvalexprError::modulation_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    /// Fractions are divided exactly, so the rounding mode is ignored.
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        _rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        self.0
+            .checked_div(&rhs.0)
+            .map(Rational)
+            .ok_or_else(|| EvalexprError::division_error(Value::Float(*self), Value::Float(*rhs)))
+    }
+
+    /// Computed exactly if the exponent is an integer.
+    fn checked_pow(&self, exponent: &Self) -> EvalexprResult<Self, NumericTypes> {
+        if exponent.0.is_integer() {
+            self.checked_powi(exponent.numer())
+        } else {
+            None
+        }
+        .map_or_else(
+            || self.checked_approximate2(*exponent, "math::pow", f64::powf),
+            Ok,
+        )
+    }
+
+    fn checked_ln(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::ln", f64::ln)
+    }
+
+    fn checked_log(&self, base: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate2(*base, "math::log", f64::log)
+    }
+
+    fn checked_log2(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::log2", f64::log2)
+    }
+
+    fn checked_log10(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::log10", f64::log10)
+    }
+
+    fn checked_exp(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::exp", f64::exp)
+    }
+
+    fn checked_exp2(&self) -> EvalexprResult<Self, NumericTypes> {
+        <Self as EvalexprFloat<NumericTypes>>::checked_pow(&Rational(Ratio::from_integer(2)), self)
+            .map_err(|_| EvalexprError::math_function_error("math::exp2", Value::Float(*self)))
+    }
+
+    fn checked_cosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::cosh", f64::cosh)
+    }
+
+    fn checked_acos(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::acos", f64::acos)
+    }
+
+    fn checked_acosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::acosh", f64::acosh)
+    }
+
+    fn checked_sinh(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::sinh", f64::sinh)
+    }
+
+    fn checked_asin(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::asin", f64::asin)
+    }
+
+    fn checked_atanh(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_approximate("math::atanh", f64::atanh)
+    }
+
+    /// Computed exactly if numerator and denominator are perfect squares.
+    fn checked_sqrt(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.exact_sqrt()
+            .map_or_else(|| self.checked_approximate("math::sqrt", f64::sqrt), Ok)
+    }
+
+    fn checked_hypot(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.0
+            .checked_mul(&self.0)
+            .zip(other.0.checked_mul(&other.0))
+            .and_then(|(a, b)| a.checked_add(&b))
+            .and_then(|sum| Rational(sum).exact_sqrt())
+            .map_or_else(
+                || self.checked_approximate2(*other, "math::hypot", f64::hypot),
+                Ok,
+            )
+    }
+}
@@ -194,6 +194,19 @@ impl<NumericType: EvalexprNumericTypesConvert> ConvertibleWithEvalexprNumericTyp
             Value::Empty => {
                 Some(Value::Empty)
             }
+            #[cfg(feature = "datetime")]
+            Value::DateTime(value) => {
+                Some(Value::DateTime(value))
+            }
+            #[cfg(feature = "datetime")]
+            Value::Duration(value) => {
+                Some(Value::Duration(value))
+            }
+            #[cfg(feature = "units")]
+            Value::Quantity(value) => {
+                let (value, unit) = value.into_parts();
+                Some(Value::Quantity(crate::value::units::Quantity::new(V::num_to_float(value)?, unit)))
+            }
         }
     }
 }
@@ -1,8 +1,9 @@
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
 use std::ops::{Shl, Shr};
 use std::str::FromStr;
 use num_traits::{CheckedNeg, CheckedRem, Float, Pow, PrimInt, Signed};
-use crate::{EvalexprError, EvalexprNumericTypes, EvalexprResult};
+use crate::{EvalexprError, EvalexprNumericTypes, EvalexprResult, RoundingMode, Value};
 
 /// An integer type that can be used by `evalexpr`.
 pub trait EvalexprInt<NumericTypes: EvalexprNumericTypes<Int = Self>>:
@@ -37,6 +38,67 @@ PrimInt
         Self::from_str_radix(literal, 16).map_err(|_| ())
     }
 
+    /// Perform a Euclidean division operation, returning an error on overflow.
+    fn checked_div_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let error = || EvalexprError::division_error(Value::Int(*self), Value::Int(*rhs));
+        let quotient = self.checked_div(rhs).ok_or_else(error)?;
+        if self.checked_rem(rhs).ok_or_else(error)? < Self::zero() {
+            if *rhs > Self::zero() {
+                quotient.checked_sub(&Self::one()).ok_or_else(error)
+            } else {
+                quotient.checked_add(&Self::one()).ok_or_else(error)
+            }
+        } else {
+            Ok(quotient)
+        }
+    }
+
+    /// Perform a Euclidean remainder operation, returning an error on overflow.
+    fn checked_rem_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let remainder = self.checked_rem(rhs).ok_or_else(|| {
+            EvalexprError::modulation_error(Value::Int(*self), Value::Int(*rhs))
+        })?;
+        if remainder < Self::zero() {
+            if *rhs < Self::zero() {
+                Ok(remainder - *rhs)
+            } else {
+                Ok(remainder + *rhs)
+            }
+        } else {
+            Ok(remainder)
+        }
+    }
+
+    /// Perform a shl operation, returning an error if `rhs` is negative or not smaller than the number of bits of `Self`.
+    fn checked_shl(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let bits = self.count_ones() + self.count_zeros();
+        match num_traits::cast::<Self, u32>(*rhs) {
+            Some(shift) if shift < bits => Ok(*self << *rhs),
+            _ => Err(EvalexprError::shift_error(Value::Int(*self), Value::Int(*rhs))),
+        }
+    }
+
+    /// Perform a shr operation, returning an error if `rhs` is negative or not smaller than the number of bits of `Self`.
+    fn checked_shr(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let bits = self.count_ones() + self.count_zeros();
+        match num_traits::cast::<Self, u32>(*rhs) {
+            Some(shift) if shift < bits => Ok(*self >> *rhs),
+            _ => Err(EvalexprError::shift_error(Value::Int(*self), Value::Int(*rhs))),
+        }
+    }
+
+    /// Perform an addition operation, wrapping around at the boundaries of the type on overflow.
+    fn wrapping_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes>;
+
+    /// Perform a subtraction operation, wrapping around at the boundaries of the type on overflow.
+    fn wrapping_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes>;
+
+    /// Perform a negation operation, wrapping around at the boundaries of the type on overflow.
+    fn wrapping_neg(&self) -> EvalexprResult<Self, NumericTypes>;
+
+    /// Perform a multiplication operation, wrapping around at the boundaries of the type on overflow.
+    fn wrapping_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes>;
+
     /// Compute the absolute value, returning an error on overflow.
     fn abs(&self) -> EvalexprResult<Self, NumericTypes>;
 }
@@ -58,6 +120,73 @@ Float
     ///
     /// If the feature `rand` is not enabled, then this method always returns [`EvalexprError::RandNotEnabled`].
     fn random() -> EvalexprResult<Self, NumericTypes>;
+
+    /// Parse a float literal like `3.5` or `6.02e23`, with digit separators already removed.
+    ///
+    /// The default implementation uses [`FromStr`].
+    #[expect(clippy::result_unit_err)]
+    fn from_literal(literal: &str) -> Result<Self, ()> {
+        literal.parse().map_err(|_| ())
+    }
+
+    /// Perform an addition operation, returning an error if the sum cannot be represented.
+    ///
+    /// The default implementation uses the `+` operator and never fails.
+    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self + *rhs)
+    }
+
+    /// Perform a subtraction operation, returning an error if the difference cannot be represented.
+    ///
+    /// The default implementation uses the `-` operator and never fails.
+    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self - *rhs)
+    }
+
+    /// Perform a multiplication operation, returning an error if the product cannot be represented.
+    ///
+    /// The default implementation uses the `*` operator and never fails.
+    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self * *rhs)
+    }
+
+    /// Perform a remainder operation, returning an error if the remainder cannot be represented.
+    ///
+    /// The default implementation uses the `%` operator and never fails.
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self % *rhs)
+    }
+
+    /// Compare two floats, returning an error if their ordering cannot be decided.
+    ///
+    /// The default implementation uses [`PartialOrd`] and never fails, so for example NaN is incomparable and results in `None`.
+    fn checked_partial_cmp(&self, rhs: &Self) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+        Ok(self.partial_cmp(rhs))
+    }
+
+    /// Perform a division operation, returning an error if the quotient cannot be represented.
+    /// A quotient that cannot be represented exactly is rounded according to `rounding_mode`.
+    ///
+    /// The default implementation uses the `/` operator, ignores `rounding_mode` and never fails.
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        _rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        Ok(*self / *rhs)
+    }
+
+    /// Rounds the float to the given amount of decimal places according to `rounding_mode`,
+    /// before it is formatted with that precision, like in `f"{price:.2}"`.
+    ///
+    /// The default implementation returns the float unchanged, leaving the rounding to its [`Display`] implementation.
+    fn round_to_decimal_places(
+        &self,
+        _decimal_places: usize,
+        _rounding_mode: RoundingMode,
+    ) -> Self {
+        *self
+    }
 }
 
 
@@ -72,6 +201,22 @@ macro_rules! impl_defaults {
             fn abs(&self) -> EvalexprResult<Self, NumericTypes> {
                 Ok(*self)
             }
+
+            fn wrapping_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_add(*self, *rhs))
+            }
+
+            fn wrapping_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_sub(*self, *rhs))
+            }
+
+            fn wrapping_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_neg(*self))
+            }
+
+            fn wrapping_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_mul(*self, *rhs))
+            }
         }
         )+
     };
@@ -80,7 +225,24 @@ macro_rules! impl_defaults {
         impl<NumericTypes: EvalexprNumericTypes<Int = $ty>> EvalexprInt<NumericTypes> for $ty
         {
             fn abs(&self) -> EvalexprResult<Self, NumericTypes> {
-                Ok($ty::abs(*self))
+                $ty::checked_abs(*self)
+                    .ok_or_else(|| EvalexprError::negation_error(Value::Int(*self)))
+            }
+
+            fn wrapping_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_add(*self, *rhs))
+            }
+
+            fn wrapping_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_sub(*self, *rhs))
+            }
+
+            fn wrapping_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_neg(*self))
+            }
+
+            fn wrapping_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_mul(*self, *rhs))
             }
         }
         )+
@@ -1,5 +1,6 @@
-use crate::{EvalexprError, EvalexprNumericTypes, EvalexprResult, Value};
+use crate::{EvalexprError, EvalexprNumericTypes, EvalexprResult, RoundingMode, Value};
 use std::{
+    cmp::Ordering,
     convert::TryInto,
     fmt::{Debug, Display},
     ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub},
@@ -26,6 +27,18 @@ Clone + Debug + Display + FromStr + Eq + Ord + Send + Sync
     #[expect(clippy::result_unit_err)]
     fn from_hex_str(literal: &str) -> Result<Self, ()>;
 
+    /// Parse `Self` from a string in the given radix, which must be between 2 and 36.
+    ///
+    /// The default implementation supports only radix 16, via [`from_hex_str`](Self::from_hex_str).
+    #[expect(clippy::result_unit_err)]
+    fn from_str_radix(literal: &str, radix: u32) -> Result<Self, ()> {
+        if radix == 16 {
+            Self::from_hex_str(literal)
+        } else {
+            Err(())
+        }
+    }
+
     /// Perform an addition operation, returning an error on overflow.
     fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes>;
 
@@ -44,6 +57,69 @@ Clone + Debug + Display + FromStr + Eq + Ord + Send + Sync
     /// Perform a remainder operation, returning an error on overflow.
     fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes>;
 
+    /// Perform an addition operation, wrapping around at the boundaries of the type on overflow.
+    ///
+    /// The default implementation uses [`checked_add`](Self::checked_add), for types that cannot overflow.
+    fn wrapping_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_add(rhs)
+    }
+
+    /// Perform a subtraction operation, wrapping around at the boundaries of the type on overflow.
+    ///
+    /// The default implementation uses [`checked_sub`](Self::checked_sub), for types that cannot overflow.
+    fn wrapping_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_sub(rhs)
+    }
+
+    /// Perform a negation operation, wrapping around at the boundaries of the type on overflow.
+    ///
+    /// The default implementation uses [`checked_neg`](Self::checked_neg), for types that cannot overflow.
+    fn wrapping_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_neg()
+    }
+
+    /// Perform a multiplication operation, wrapping around at the boundaries of the type on overflow.
+    ///
+    /// The default implementation uses [`checked_mul`](Self::checked_mul), for types that cannot overflow.
+    fn wrapping_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        self.checked_mul(rhs)
+    }
+
+    /// Perform a Euclidean division operation, returning an error on overflow.
+    ///
+    /// The default implementation corrects the result of [`checked_div`](Self::checked_div) by one if the remainder is negative.
+    fn checked_div_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let zero = Self::from_usize(0)?;
+        let one = Self::from_usize(1)?;
+        let quotient = self.checked_div(rhs)?;
+        if self.checked_rem(rhs)? < zero {
+            if *rhs > zero {
+                quotient.checked_sub(&one)
+            } else {
+                quotient.checked_add(&one)
+            }
+        } else {
+            Ok(quotient)
+        }
+    }
+
+    /// Perform a Euclidean remainder operation, returning an error on overflow.
+    ///
+    /// The default implementation shifts a negative result of [`checked_rem`](Self::checked_rem) by the absolute value of `rhs`.
+    fn checked_rem_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let zero = Self::from_usize(0)?;
+        let remainder = self.checked_rem(rhs)?;
+        if remainder < zero {
+            if *rhs < zero {
+                remainder.checked_sub(rhs)
+            } else {
+                remainder.checked_add(rhs)
+            }
+        } else {
+            Ok(remainder)
+        }
+    }
+
     /// Compute the absolute value, returning an error on overflow.
     fn abs(&self) -> EvalexprResult<Self, NumericTypes>;
 
@@ -64,6 +140,56 @@ Clone + Debug + Display + FromStr + Eq + Ord + Send + Sync
 
     /// Perform a shr operation.
     fn shr(&self, rhs: &Self) -> Self;
+
+    /// Perform a shl operation, returning an error if `rhs` is negative or not smaller than the number of bits of `Self`.
+    ///
+    /// The default implementation doubles `self` `rhs` times with [`checked_mul`](Self::checked_mul),
+    /// so it instead returns an error if `rhs` is negative or if the result overflows.
+    fn checked_shl(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let zero = Self::from_usize(0)?;
+        let one = Self::from_usize(1)?;
+        let two = Self::from_usize(2)?;
+        let error = || EvalexprError::shift_error(Value::Int(self.clone()), Value::Int(rhs.clone()));
+        if *rhs < zero {
+            return Err(error());
+        }
+
+        let mut result = self.clone();
+        let mut shift = zero.clone();
+        while shift < *rhs && result != zero {
+            result = result.checked_mul(&two).map_err(|_| error())?;
+            shift = shift.checked_add(&one)?;
+        }
+        Ok(result)
+    }
+
+    /// Perform a shr operation, returning an error if `rhs` is negative or not smaller than the number of bits of `Self`.
+    ///
+    /// The default implementation halves `self` `rhs` times with [`checked_div_euclid`](Self::checked_div_euclid),
+    /// so it instead returns an error only if `rhs` is negative, and shifting by any larger amount results in `0` or `-1`.
+    fn checked_shr(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let zero = Self::from_usize(0)?;
+        let one = Self::from_usize(1)?;
+        let two = Self::from_usize(2)?;
+        if *rhs < zero {
+            return Err(EvalexprError::shift_error(
+                Value::Int(self.clone()),
+                Value::Int(rhs.clone()),
+            ));
+        }
+
+        let mut result = self.clone();
+        let mut shift = zero;
+        while shift < *rhs {
+            let halved = result.checked_div_euclid(&two)?;
+            if halved == result {
+                break;
+            }
+            result = halved;
+            shift = shift.checked_add(&one)?;
+        }
+        Ok(result)
+    }
 }
 
 /// A float type that can be used by `evalexpr`.
@@ -171,6 +297,42 @@ pub trait EvalexprFloat<NumericTypes: EvalexprNumericTypes<Float = Self>>:
     /// Compute the largest integer greater than or equal to `self`.
     fn ceil(&self) -> Self;
 
+    /// Returns the integer part of `self`, rounding towards `0.0`.
+    ///
+    /// The default implementation uses [`floor`](Self::floor) for non-negative and [`ceil`](Self::ceil) for negative values.
+    fn trunc(&self) -> Self {
+        if self.abs() == *self {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    /// Returns the fractional part of `self`.
+    ///
+    /// The default implementation subtracts [`trunc`](Self::trunc) from `self`.
+    fn fract(&self) -> Self {
+        self.clone() - self.trunc()
+    }
+
+    /// Converts `self` from degrees to radians, returning an error if the result cannot be represented.
+    ///
+    /// The default implementation multiplies by π and divides by 180, both parsed with [`from_literal`](Self::from_literal),
+    /// and fails if `from_literal` cannot parse these constants.
+    fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+        let (pi, half_turn) = half_turn_constants("math::to_radians", self)?;
+        Ok(self.clone() * pi / half_turn)
+    }
+
+    /// Converts `self` from radians to degrees, returning an error if the result cannot be represented.
+    ///
+    /// The default implementation multiplies by 180 and divides by π, both parsed with [`from_literal`](Self::from_literal),
+    /// and fails if `from_literal` cannot parse these constants.
+    fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+        let (pi, half_turn) = half_turn_constants("math::to_degrees", self)?;
+        Ok(self.clone() * half_turn / pi)
+    }
+
     /// Returns true if `self` is not a number.
     fn is_nan(&self) -> bool;
 
@@ -196,289 +358,587 @@ pub trait EvalexprFloat<NumericTypes: EvalexprNumericTypes<Float = Self>>:
     ///
     /// If the feature `rand` is not enabled, then this method always returns [`EvalexprError::RandNotEnabled`].
     fn random() -> EvalexprResult<Self, NumericTypes>;
-}
 
-
-impl<NumericTypes: EvalexprNumericTypes<Int = Self>> EvalexprInt<NumericTypes> for i64 {
-    #[inline(always)]
-    fn min_value() -> Self {
-        Self::MIN
+    /// Parse a float literal like `3.5` or `6.02e23`, with digit separators already removed.
+    ///
+    /// The default implementation uses [`FromStr`].
+    #[expect(clippy::result_unit_err)]
+    fn from_literal(literal: &str) -> Result<Self, ()> {
+        literal.parse().map_err(|_| ())
     }
 
-    #[inline(always)]
-    fn max_value() -> Self {
-        Self::MAX
+    /// Perform an addition operation, returning an error if the sum cannot be represented.
+    ///
+    /// The default implementation uses the `+` operator and never fails.
+    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.clone() + rhs.clone())
     }
 
-    fn from_usize(int: usize) -> EvalexprResult<Self, NumericTypes> {
-        int.try_into()
-            .map_err(|_| EvalexprError::IntFromUsize { usize_int: int })
+    /// Perform a subtraction operation, returning an error if the difference cannot be represented.
+    ///
+    /// The default implementation uses the `-` operator and never fails.
+    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.clone() - rhs.clone())
     }
 
-    fn into_usize(self) -> EvalexprResult<usize, NumericTypes> {
-        if self >= 0 {
-            (self as u64)
-                .try_into()
-                .map_err(|_| EvalexprError::IntIntoUsize { int: self })
-        } else {
-            Err(EvalexprError::IntIntoUsize { int: self })
-        }
+    /// Perform a multiplication operation, returning an error if the product cannot be represented.
+    ///
+    /// The default implementation uses the `*` operator and never fails.
+    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.clone() * rhs.clone())
     }
 
-    fn from_hex_str(literal: &str) -> Result<Self, ()> {
-        Self::from_str_radix(literal, 16).map_err(|_| ())
+    /// Perform a remainder operation, returning an error if the remainder cannot be represented.
+    ///
+    /// The default implementation uses the `%` operator and never fails.
+    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.clone() % rhs.clone())
     }
 
-    fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_add(*rhs);
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::addition_error(
-                Value::<NumericTypes>::from_int(*self),
-                Value::<NumericTypes>::from_int(*rhs),
-            ))
-        }
+    /// Compare two floats, returning an error if their ordering cannot be decided.
+    ///
+    /// The default implementation uses [`PartialOrd`] and never fails, so for example NaN is incomparable and results in `None`.
+    fn checked_partial_cmp(&self, rhs: &Self) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+        Ok(self.partial_cmp(rhs))
     }
 
-    fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_sub(*rhs);
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::subtraction_error(
-                Value::<NumericTypes>::from_int(*self),
-                Value::<NumericTypes>::from_int(*rhs),
-            ))
-        }
+    /// Perform a division operation, returning an error if the quotient cannot be represented.
+    /// A quotient that cannot be represented exactly is rounded according to `rounding_mode`.
+    ///
+    /// The default implementation uses the `/` operator, ignores `rounding_mode` and never fails.
+    fn checked_div(
+        &self,
+        rhs: &Self,
+        _rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.clone() / rhs.clone())
     }
 
-    fn checked_neg(&self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_neg();
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::negation_error(
-                Value::<NumericTypes>::from_int(*self),
-            ))
-        }
+    /// Returns the minimum of the two floats, returning an error if their ordering cannot be decided.
+    ///
+    /// The default implementation uses [`min`](Self::min) and never fails.
+    fn checked_min(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.min(other))
     }
 
-    fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_mul(*rhs);
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::multiplication_error(
-                Value::<NumericTypes>::from_int(*self),
-                Value::<NumericTypes>::from_int(*rhs),
-            ))
-        }
+    /// Returns the maximum of the two floats, returning an error if their ordering cannot be decided.
+    ///
+    /// The default implementation uses [`max`](Self::max) and never fails.
+    fn checked_max(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.max(other))
     }
 
-    fn checked_div(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_div(*rhs);
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::division_error(
-                Value::<NumericTypes>::from_int(*self),
-                Value::<NumericTypes>::from_int(*rhs),
-            ))
-        }
+    /// Perform a power operation, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`pow`](Self::pow) and never fails.
+    fn checked_pow(&self, exponent: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.pow(exponent))
     }
 
-    fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
-        let result = (*self).checked_rem(*rhs);
-        if let Some(result) = result {
-            Ok(result)
-        } else {
-            Err(EvalexprError::modulation_error(
-                Value::<NumericTypes>::from_int(*self),
-                Value::<NumericTypes>::from_int(*rhs),
-            ))
-        }
+    /// Compute the natural logarithm, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`ln`](Self::ln) and never fails.
+    fn checked_ln(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.ln())
     }
 
-    fn abs(&self) -> EvalexprResult<Self, NumericTypes> {
-        Ok((*self).abs())
+    /// Compute the logarithm to a certain base, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`log`](Self::log) and never fails.
+    fn checked_log(&self, base: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.log(base))
     }
 
-    fn bitand(&self, rhs: &Self) -> Self {
-        BitAnd::bitand(*self, *rhs)
+    /// Compute the logarithm base 2, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`log2`](Self::log2) and never fails.
+    fn checked_log2(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.log2())
     }
 
-    fn bitor(&self, rhs: &Self) -> Self {
-        BitOr::bitor(*self, *rhs)
+    /// Compute the logarithm base 10, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`log10`](Self::log10) and never fails.
+    fn checked_log10(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.log10())
     }
 
-    fn bitxor(&self, rhs: &Self) -> Self {
-        BitXor::bitxor(*self, *rhs)
+    /// Exponentiate with base `e`, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`exp`](Self::exp) and never fails.
+    fn checked_exp(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.exp())
     }
 
-    fn not(&self) -> Self {
-        Not::not(*self)
+    /// Exponentiate with base 2, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`exp2`](Self::exp2) and never fails.
+    fn checked_exp2(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.exp2())
     }
 
-    fn shl(&self, rhs: &Self) -> Self {
-        Shl::shl(*self, *rhs)
+    /// Compute the hyperbolic cosine, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`cosh`](Self::cosh) and never fails.
+    fn checked_cosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.cosh())
     }
 
-    fn shr(&self, rhs: &Self) -> Self {
-        Shr::shr(*self, *rhs)
+    /// Compute the arccosine, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`acos`](Self::acos) and never fails.
+    fn checked_acos(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.acos())
     }
-}
 
-impl<NumericTypes: EvalexprNumericTypes<Float = Self>> EvalexprFloat<NumericTypes> for f64 {
-    #[inline(always)]
-    fn infinity() -> Self {
-        Self::INFINITY
+    /// Compute the hyperbolic arccosine, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`acosh`](Self::acosh) and never fails.
+    fn checked_acosh(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.acosh())
     }
 
-    #[inline(always)]
-    fn neg_infinity() -> Self {
-        Self::NEG_INFINITY
+    /// Compute the hyperbolic sine, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`sinh`](Self::sinh) and never fails.
+    fn checked_sinh(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.sinh())
     }
 
-    fn pow(&self, exponent: &Self) -> Self {
-        (*self).powf(*exponent)
+    /// Compute the arcsine, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`asin`](Self::asin) and never fails.
+    fn checked_asin(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.asin())
     }
 
-    fn ln(&self) -> Self {
-        (*self).ln()
+    /// Compute the hyperbolic arctangent, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`atanh`](Self::atanh) and never fails.
+    fn checked_atanh(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.atanh())
     }
 
-    fn log(&self, base: &Self) -> Self {
-        (*self).log(*base)
+    /// Compute the square root, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`sqrt`](Self::sqrt) and never fails.
+    fn checked_sqrt(&self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.sqrt())
     }
 
-    fn log2(&self) -> Self {
-        (*self).log2()
+    /// Compute the distance between the origin and a point (`self`, `other`) on the Euclidean plane, returning an error if the result is undefined or cannot be represented.
+    ///
+    /// The default implementation uses [`hypot`](Self::hypot) and never fails.
+    fn checked_hypot(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        Ok(self.hypot(other))
     }
 
-    fn log10(&self) -> Self {
-        (*self).log10()
+    /// Rounds the float to the given amount of decimal places according to `rounding_mode`,
+    /// before it is formatted with that precision, like in `f"{price:.2}"`.
+    ///
+    /// The default implementation returns the float unchanged, leaving the rounding to its [`Display`] implementation.
+    fn round_to_decimal_places(
+        &self,
+        _decimal_places: usize,
+        _rounding_mode: RoundingMode,
+    ) -> Self {
+        self.clone()
     }
+}
 
-    fn exp(&self) -> Self {
-        (*self).exp()
-    }
+/// Returns π and 180, the size of a half turn in radians and in degrees,
+/// or a [`EvalexprError::MathFunctionError`] for `function` and `argument` if the float type cannot parse them.
+fn half_turn_constants<NumericTypes: EvalexprNumericTypes>(
+    function: &str,
+    argument: &NumericTypes::Float,
+) -> EvalexprResult<(NumericTypes::Float, NumericTypes::Float), NumericTypes> {
+    let parse = |literal| {
+        NumericTypes::Float::from_literal(literal).map_err(|()| {
+            EvalexprError::math_function_error(function, Value::Float(argument.clone()))
+        })
+    };
+    Ok((
+        parse("3.14159265358979323846264338327950288")?,
+        parse("180")?,
+    ))
+}
 
-    fn exp2(&self) -> Self {
-        (*self).exp2()
-    }
+macro_rules! impl_int {
+    (unsigned: $($ty:ident),+ $(,)?) => {
+        $(
+        impl_int!($ty, checked_abs: Some);
+        )+
+    };
+    (signed: $($ty:ident),+ $(,)?) => {
+        $(
+        impl_int!($ty, checked_abs: $ty::checked_abs);
+        )+
+    };
+    ($ty:ident, checked_abs: $checked_abs:path) => {
+        impl<NumericTypes: EvalexprNumericTypes<Int = $ty>> EvalexprInt<NumericTypes> for $ty {
+            #[inline(always)]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline(always)]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+
+            fn from_usize(int: usize) -> EvalexprResult<Self, NumericTypes> {
+                int.try_into()
+                    .map_err(|_| EvalexprError::IntFromUsize { usize_int: int })
+            }
+
+            fn into_usize(self) -> EvalexprResult<usize, NumericTypes> {
+                self.try_into()
+                    .map_err(|_| EvalexprError::IntIntoUsize { int: self })
+            }
+
+            fn from_hex_str(literal: &str) -> Result<Self, ()> {
+                Self::from_str_radix(literal, 16).map_err(|_| ())
+            }
+
+            fn from_str_radix(literal: &str, radix: u32) -> Result<Self, ()> {
+                $ty::from_str_radix(literal, radix).map_err(|_| ())
+            }
+
+            fn checked_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_add(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::addition_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_sub(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::subtraction_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn checked_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_neg();
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::negation_error(
+                        Value::<NumericTypes>::from_int(*self),
+                    ))
+                }
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_mul(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::multiplication_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn checked_div(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_div(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::division_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn checked_rem(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_rem(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::modulation_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn wrapping_add(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_add(*self, *rhs))
+            }
+
+            fn wrapping_sub(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_sub(*self, *rhs))
+            }
+
+            fn wrapping_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_neg(*self))
+            }
+
+            fn wrapping_mul(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                Ok($ty::wrapping_mul(*self, *rhs))
+            }
+
+            fn checked_div_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_div_euclid(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::division_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn checked_rem_euclid(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                let result = (*self).checked_rem_euclid(*rhs);
+                if let Some(result) = result {
+                    Ok(result)
+                } else {
+                    Err(EvalexprError::modulation_error(
+                        Value::<NumericTypes>::from_int(*self),
+                        Value::<NumericTypes>::from_int(*rhs),
+                    ))
+                }
+            }
+
+            fn abs(&self) -> EvalexprResult<Self, NumericTypes> {
+                $checked_abs(*self).ok_or_else(|| {
+                    EvalexprError::negation_error(Value::<NumericTypes>::from_int(*self))
+                })
+            }
+
+            fn bitand(&self, rhs: &Self) -> Self {
+                BitAnd::bitand(*self, *rhs)
+            }
+
+            fn bitor(&self, rhs: &Self) -> Self {
+                BitOr::bitor(*self, *rhs)
+            }
+
+            fn bitxor(&self, rhs: &Self) -> Self {
+                BitXor::bitxor(*self, *rhs)
+            }
+
+            fn not(&self) -> Self {
+                Not::not(*self)
+            }
+
+            fn shl(&self, rhs: &Self) -> Self {
+                Shl::shl(*self, *rhs)
+            }
+
+            fn shr(&self, rhs: &Self) -> Self {
+                Shr::shr(*self, *rhs)
+            }
+
+            fn checked_shl(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                u32::try_from(*rhs)
+                    .ok()
+                    .and_then(|shift| $ty::checked_shl(*self, shift))
+                    .ok_or_else(|| EvalexprError::shift_error(Value::Int(*self), Value::Int(*rhs)))
+            }
+
+            fn checked_shr(&self, rhs: &Self) -> EvalexprResult<Self, NumericTypes> {
+                u32::try_from(*rhs)
+                    .ok()
+                    .and_then(|shift| $ty::checked_shr(*self, shift))
+                    .ok_or_else(|| EvalexprError::shift_error(Value::Int(*self), Value::Int(*rhs)))
+            }
+        }
+    };
+}
 
-    fn cos(&self) -> Self {
-        (*self).cos()
-    }
+impl_int!(signed: i32, i64, i128);
+impl_int!(unsigned: u64);
+
+macro_rules! impl_float {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+        impl<NumericTypes: EvalexprNumericTypes<Float = $ty>> EvalexprFloat<NumericTypes> for $ty {
+            #[inline(always)]
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
+
+            #[inline(always)]
+            fn neg_infinity() -> Self {
+                Self::NEG_INFINITY
+            }
 
-    fn cosh(&self) -> Self {
-        (*self).cosh()
-    }
+            fn pow(&self, exponent: &Self) -> Self {
+                (*self).powf(*exponent)
+            }
 
-    fn acos(&self) -> Self {
-        (*self).acos()
-    }
+            fn ln(&self) -> Self {
+                (*self).ln()
+            }
 
-    fn acosh(&self) -> Self {
-        (*self).acosh()
-    }
+            fn log(&self, base: &Self) -> Self {
+                (*self).log(*base)
+            }
 
-    fn sin(&self) -> Self {
-        (*self).sin()
-    }
+            fn log2(&self) -> Self {
+                (*self).log2()
+            }
 
-    fn sinh(&self) -> Self {
-        (*self).sinh()
-    }
+            fn log10(&self) -> Self {
+                (*self).log10()
+            }
 
-    fn asin(&self) -> Self {
-        (*self).asin()
-    }
+            fn exp(&self) -> Self {
+                (*self).exp()
+            }
 
-    fn asinh(&self) -> Self {
-        (*self).asinh()
-    }
+            fn exp2(&self) -> Self {
+                (*self).exp2()
+            }
 
-    fn tan(&self) -> Self {
-        (*self).tan()
-    }
+            fn cos(&self) -> Self {
+                (*self).cos()
+            }
 
-    fn tanh(&self) -> Self {
-        (*self).tanh()
-    }
+            fn cosh(&self) -> Self {
+                (*self).cosh()
+            }
 
-    fn atan(&self) -> Self {
-        (*self).atan()
-    }
+            fn acos(&self) -> Self {
+                (*self).acos()
+            }
 
-    fn atanh(&self) -> Self {
-        (*self).atanh()
-    }
+            fn acosh(&self) -> Self {
+                (*self).acosh()
+            }
 
-    fn atan2(&self, x: &Self) -> Self {
-        (*self).atan2(*x)
-    }
+            fn sin(&self) -> Self {
+                (*self).sin()
+            }
 
-    fn sqrt(&self) -> Self {
-        (*self).sqrt()
-    }
+            fn sinh(&self) -> Self {
+                (*self).sinh()
+            }
 
-    fn cbrt(&self) -> Self {
-        (*self).cbrt()
-    }
+            fn asin(&self) -> Self {
+                (*self).asin()
+            }
 
-    fn hypot(&self, other: &Self) -> Self {
-        (*self).hypot(*other)
-    }
+            fn asinh(&self) -> Self {
+                (*self).asinh()
+            }
 
-    fn floor(&self) -> Self {
-        (*self).floor()
-    }
+            fn tan(&self) -> Self {
+                (*self).tan()
+            }
 
-    fn round(&self) -> Self {
-        (*self).round()
-    }
+            fn tanh(&self) -> Self {
+                (*self).tanh()
+            }
 
-    fn ceil(&self) -> Self {
-        (*self).ceil()
-    }
+            fn atan(&self) -> Self {
+                (*self).atan()
+            }
 
-    fn is_nan(&self) -> bool {
-        (*self).is_nan()
-    }
+            fn atanh(&self) -> Self {
+                (*self).atanh()
+            }
 
-    fn is_finite(&self) -> bool {
-        (*self).is_finite()
-    }
+            fn atan2(&self, x: &Self) -> Self {
+                (*self).atan2(*x)
+            }
 
-    fn is_infinite(&self) -> bool {
-        (*self).is_infinite()
-    }
+            fn sqrt(&self) -> Self {
+                (*self).sqrt()
+            }
+
+            fn cbrt(&self) -> Self {
+                (*self).cbrt()
+            }
+
+            fn hypot(&self, other: &Self) -> Self {
+                (*self).hypot(*other)
+            }
 
-    fn is_normal(&self) -> bool {
-        (*self).is_normal()
-    }
+            fn floor(&self) -> Self {
+                (*self).floor()
+            }
 
-    fn abs(&self) -> Self {
-        (*self).abs()
-    }
+            fn round(&self) -> Self {
+                (*self).round()
+            }
 
-    fn min(&self, other: &Self) -> Self {
-        (*self).min(*other)
-    }
+            fn ceil(&self) -> Self {
+                (*self).ceil()
+            }
 
-    fn max(&self, other: &Self) -> Self {
-        (*self).max(*other)
-    }
+            fn trunc(&self) -> Self {
+                (*self).trunc()
+            }
 
-    fn random() -> EvalexprResult<Self, NumericTypes> {
-        #[cfg(feature = "rand")]
-        let result = Ok(rand::random());
+            fn fract(&self) -> Self {
+                (*self).fract()
+            }
 
-        #[cfg(not(feature = "rand"))]
-        let result = Err(EvalexprError::RandNotEnabled);
+            fn to_radians(&self) -> EvalexprResult<Self, NumericTypes> {
+                Ok((*self).to_radians())
+            }
 
-        result
-    }
+            fn to_degrees(&self) -> EvalexprResult<Self, NumericTypes> {
+                Ok((*self).to_degrees())
+            }
+
+            fn is_nan(&self) -> bool {
+                (*self).is_nan()
+            }
+
+            fn is_finite(&self) -> bool {
+                (*self).is_finite()
+            }
+
+            fn is_infinite(&self) -> bool {
+                (*self).is_infinite()
+            }
+
+            fn is_normal(&self) -> bool {
+                (*self).is_normal()
+            }
+
+            fn abs(&self) -> Self {
+                (*self).abs()
+            }
+
+            fn min(&self, other: &Self) -> Self {
+                (*self).min(*other)
+            }
+
+            fn max(&self, other: &Self) -> Self {
+                (*self).max(*other)
+            }
+
+            fn random() -> EvalexprResult<Self, NumericTypes> {
+                #[cfg(feature = "rand")]
+                let result = Ok(rand::random());
+
+                #[cfg(not(feature = "rand"))]
+                let result = Err(EvalexprError::RandNotEnabled);
+
+                result
+            }
+        }
+        )+
+    };
 }
+
+impl_float!(f64, f32);
@@ -0,0 +1,472 @@
+use std::{any::type_name, cmp::Ordering, fmt, str::FromStr};
+
+use crate::{
+    error::EvalexprResultValue,
+    value::{
+        numeric_types::{DefaultNumericTypes, EvalexprFloat, EvalexprNumericTypes, RoundingMode},
+        value_type::ValueType,
+    },
+    EvalexprError, EvalexprResult, Value,
+};
+
+/// The exponents of the SI base dimensions length, mass, time, electric current, temperature, amount of substance and luminous intensity.
+type Dimension = [i64; 7];
+
+/// Returns the factor to SI base units and the dimension of a unit symbol, and whether the symbol accepts an SI prefix.
+fn lookup_symbol(symbol: &str) -> Option<(f64, Dimension, bool)> {
+    Some(match symbol {
+        // SI base units, with the gram instead of the kilogram to allow prefixes
+        "m" => (1.0, [1, 0, 0, 0, 0, 0, 0], true),
+        "g" => (1e-3, [0, 1, 0, 0, 0, 0, 0], true),
+        "s" => (1.0, [0, 0, 1, 0, 0, 0, 0], true),
+        "A" => (1.0, [0, 0, 0, 1, 0, 0, 0], true),
+        "K" => (1.0, [0, 0, 0, 0, 1, 0, 0], true),
+        "mol" => (1.0, [0, 0, 0, 0, 0, 1, 0], true),
+        "cd" => (1.0, [0, 0, 0, 0, 0, 0, 1], true),
+        // Derived SI units
+        "Hz" => (1.0, [0, 0, -1, 0, 0, 0, 0], true),
+        "N" => (1.0, [1, 1, -2, 0, 0, 0, 0], true),
+        "Pa" => (1.0, [-1, 1, -2, 0, 0, 0, 0], true),
+        "J" => (1.0, [2, 1, -2, 0, 0, 0, 0], true),
+        "W" => (1.0, [2, 1, -3, 0, 0, 0, 0], true),
+        "C" => (1.0, [0, 0, 1, 1, 0, 0, 0], true),
+        "V" => (1.0, [2, 1, -3, -1, 0, 0, 0], true),
+        "ohm" | "Ω" => (1.0, [2, 1, -3, -2, 0, 0, 0], true),
+        // Units accepted for use with the SI
+        "L" => (1e-3, [3, 0, 0, 0, 0, 0, 0], true),
+        "t" => (1e3, [0, 1, 0, 0, 0, 0, 0], false),
+        "min" => (60.0, [0, 0, 1, 0, 0, 0, 0], false),
+        "h" => (3600.0, [0, 0, 1, 0, 0, 0, 0], false),
+        "d" => (86400.0, [0, 0, 1, 0, 0, 0, 0], false),
+        "Wh" => (3600.0, [2, 1, -2, 0, 0, 0, 0], true),
+        "bar" => (1e5, [-1, 1, -2, 0, 0, 0, 0], true),
+        // Imperial and US customary units
+        "in" => (0.0254, [1, 0, 0, 0, 0, 0, 0], false),
+        "ft" => (0.3048, [1, 0, 0, 0, 0, 0, 0], false),
+        "yd" => (0.9144, [1, 0, 0, 0, 0, 0, 0], false),
+        "mi" => (1609.344, [1, 0, 0, 0, 0, 0, 0], false),
+        "nmi" => (1852.0, [1, 0, 0, 0, 0, 0, 0], false),
+        "gal" => (3.785411784e-3, [3, 0, 0, 0, 0, 0, 0], false),
+        "lb" => (0.45359237, [0, 1, 0, 0, 0, 0, 0], false),
+        "oz" => (0.028349523125, [0, 1, 0, 0, 0, 0, 0], false),
+        "mph" => (0.44704, [1, 0, -1, 0, 0, 0, 0], false),
+        "kn" => (1852.0 / 3600.0, [1, 0, -1, 0, 0, 0, 0], false),
+        "psi" => (6894.757293168361, [-1, 1, -2, 0, 0, 0, 0], false),
+        _ => return None,
+    })
+}
+
+/// The SI prefixes with their factors.
+const PREFIXES: &[(&str, f64)] = &[
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("da", 1e1),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("µ", 1e-6),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+];
+
+/// Returns the factor to SI base units and the dimension of a unit symbol, which may start with an SI prefix like in `km`.
+/// Symbols without prefix take precedence, such that `min` is a minute and not a milli-inch.
+fn resolve_symbol(symbol: &str) -> Option<(f64, Dimension)> {
+    if let Some((factor, dimension, _)) = lookup_symbol(symbol) {
+        return Some((factor, dimension));
+    }
+    PREFIXES.iter().find_map(|(prefix, prefix_factor)| {
+        let (factor, dimension, accepts_prefix) = lookup_symbol(symbol.strip_prefix(prefix)?)?;
+        accepts_prefix.then_some((prefix_factor * factor, dimension))
+    })
+}
+
+/// A unit symbol raised to an exponent, as part of a [`Unit`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Term {
+    symbol: String,
+    exponent: i32,
+    factor: f64,
+    dimension: Dimension,
+}
+
+/// A unit of measure like `m`, `km/h` or `kg*m/s^2`, which is a product of unit symbols raised to integer exponents.
+///
+/// Units are parsed from strings, where symbols are joined with `*` and `/` and optionally raised to an exponent with `^`.
+/// Each symbol may start with an SI prefix, like `k` in `km`, if it is an SI unit.
+///
+/// ```rust
+/// # use evalexpr::*;
+///
+/// let unit: Unit = "km/h".parse().unwrap();
+/// assert!(unit.is_compatible(&"m/s".parse().unwrap()));
+/// assert_eq!(unit.factor(), 1000.0 / 3600.0);
+/// assert_eq!("kg*m/s^2".parse::<Unit>().unwrap().to_string(), "kg*m/s^2");
+/// assert!("furlong".parse::<Unit>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unit {
+    terms: Vec<Term>,
+}
+
+impl Unit {
+    /// Returns the unit of plain numbers, which has no dimension.
+    pub fn dimensionless() -> Self {
+        Unit { terms: Vec::new() }
+    }
+
+    /// Returns the factor that converts an amount of this unit into SI base units.
+    pub fn factor(&self) -> f64 {
+        self.terms
+            .iter()
+            .map(|term| term.factor.powi(term.exponent))
+            .product()
+    }
+
+    /// Returns true if this unit has no dimension, like the unit `m/km`.
+    pub fn is_dimensionless(&self) -> bool {
+        self.dimension() == [0; 7]
+    }
+
+    /// Returns true if amounts of this unit can be converted to `other`, because both units measure the same dimension.
+    pub fn is_compatible(&self, other: &Unit) -> bool {
+        self.dimension() == other.dimension()
+    }
+
+    fn dimension(&self) -> Dimension {
+        let mut dimension = [0; 7];
+        for term in &self.terms {
+            for (total, exponent) in dimension.iter_mut().zip(term.dimension) {
+                *total += exponent * i64::from(term.exponent);
+            }
+        }
+        dimension
+    }
+
+    /// Parses a unit like `km/h`, or returns `None` if it contains an unknown symbol.
+    /// A `1` in place of a symbol stands for no unit, such that `1/s` is a frequency.
+    pub(crate) fn parse(string: &str) -> Option<Self> {
+        let mut unit = Unit::dimensionless();
+        let mut rest = string;
+        let mut divide = false;
+        loop {
+            let end = rest.find(['*', '/', '·']).unwrap_or(rest.len());
+            let term = Self::parse_term(rest[..end].trim())?;
+            unit = if divide {
+                unit.product(&term.powi(-1)?)?
+            } else {
+                unit.product(&term)?
+            };
+
+            match rest[end..].chars().next() {
+                Some(operator) => {
+                    divide = operator == '/';
+                    rest = &rest[end + operator.len_utf8()..];
+                },
+                None => return Some(unit),
+            }
+        }
+    }
+
+    /// Parses a single symbol like `m` or `s^-2`.
+    fn parse_term(string: &str) -> Option<Self> {
+        let (symbol, exponent) = match string.split_once('^') {
+            Some((symbol, exponent)) => (symbol.trim_end(), exponent.trim_start().parse().ok()?),
+            None => (string, 1),
+        };
+        if symbol == "1" {
+            return Some(Unit::dimensionless());
+        }
+        Self::symbol(symbol)?.powi(exponent)
+    }
+
+    /// Returns the unit of a single symbol like `km`, or `None` if the symbol is unknown.
+    pub(crate) fn symbol(symbol: &str) -> Option<Self> {
+        let (factor, dimension) = resolve_symbol(symbol)?;
+        Some(Unit {
+            terms: vec![Term {
+                symbol: symbol.to_string(),
+                exponent: 1,
+                factor,
+                dimension,
+            }],
+        })
+    }
+
+    /// Multiplies two units, merging equal symbols, or returns `None` if an exponent overflows.
+    pub(crate) fn product(&self, other: &Unit) -> Option<Self> {
+        let mut terms = self.terms.clone();
+        for term in &other.terms {
+            match terms
+                .iter_mut()
+                .find(|existing| existing.symbol == term.symbol)
+            {
+                Some(existing) => {
+                    existing.exponent = existing.exponent.checked_add(term.exponent)?
+                },
+                None => terms.push(term.clone()),
+            }
+        }
+        terms.retain(|term| term.exponent != 0);
+        Some(Unit { terms })
+    }
+
+    /// Raises this unit to an integer power, or returns `None` if an exponent overflows.
+    pub(crate) fn powi(&self, exponent: i32) -> Option<Self> {
+        if exponent == 0 {
+            return Some(Unit::dimensionless());
+        }
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                Some(Term {
+                    exponent: term.exponent.checked_mul(exponent)?,
+                    ..term.clone()
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(Unit { terms })
+    }
+}
+
+impl FromStr for Unit {
+    type Err = EvalexprError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Unit::parse(string).ok_or_else(|| EvalexprError::UnknownUnit(string.to_string()))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let write_term = |f: &mut fmt::Formatter, symbol: &str, exponent: i32| {
+            if exponent == 1 {
+                write!(f, "{}", symbol)
+            } else {
+                write!(f, "{}^{}", symbol, exponent)
+            }
+        };
+
+        let mut numerator = self
+            .terms
+            .iter()
+            .filter(|term| term.exponent > 0)
+            .peekable();
+        if numerator.peek().is_none() {
+            write!(f, "1")?;
+        }
+        for (index, term) in numerator.enumerate() {
+            if index > 0 {
+                write!(f, "*")?;
+            }
+            write_term(f, &term.symbol, term.exponent)?;
+        }
+        for term in self.terms.iter().filter(|term| term.exponent < 0) {
+            write!(f, "/")?;
+            write_term(f, &term.symbol, -term.exponent)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts an `f64` like a conversion factor into a float, through its decimal representation.
+fn float_from_f64<NumericTypes: EvalexprNumericTypes>(
+    float: f64,
+) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+    NumericTypes::Float::from_literal(&float.to_string()).map_err(|_| EvalexprError::StringParse {
+        string: float.to_string(),
+        target_type: type_name::<NumericTypes::Float>(),
+    })
+}
+
+/// A float together with a unit of measure, like `5 m` or `18 km/h`.
+///
+/// Quantities are compared and added after converting them to the same unit, so `1 km == 1000 m` holds.
+///
+/// ```rust
+/// # use evalexpr::*;
+///
+/// let speed = Quantity::<DefaultNumericTypes>::new(18.0, "km/h".parse().unwrap());
+/// assert_eq!(speed.convert(&"m/s".parse().unwrap()).unwrap().value(), &5.0);
+/// assert_eq!(Value::from(speed).to_string(), "18 km/h");
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantity<NumericTypes: EvalexprNumericTypes = DefaultNumericTypes> {
+    value: NumericTypes::Float,
+    unit: Unit,
+}
+
+impl<NumericTypes: EvalexprNumericTypes> Quantity<NumericTypes> {
+    /// Creates a quantity of `value` times `unit`.
+    pub fn new(value: NumericTypes::Float, unit: Unit) -> Self {
+        Quantity { value, unit }
+    }
+
+    /// Returns the amount of the unit.
+    pub fn value(&self) -> &NumericTypes::Float {
+        &self.value
+    }
+
+    /// Returns the unit.
+    pub fn unit(&self) -> &Unit {
+        &self.unit
+    }
+
+    /// Returns the amount of the unit and the unit.
+    pub fn into_parts(self) -> (NumericTypes::Float, Unit) {
+        (self.value, self.unit)
+    }
+
+    /// Converts this quantity to the given unit, or returns `Err` if the unit measures a different dimension.
+    pub fn convert(&self, unit: &Unit) -> EvalexprResult<Self, NumericTypes> {
+        if !self.unit.is_compatible(unit) {
+            return Err(EvalexprError::IncompatibleUnits {
+                a: self.unit.clone(),
+                b: unit.clone(),
+            });
+        }
+        let factor = float_from_f64(self.unit.factor() / unit.factor())?;
+        Ok(Quantity::new(
+            self.value.checked_mul(&factor)?,
+            unit.clone(),
+        ))
+    }
+
+    /// Returns a quantity as is, and a number as a quantity without dimension.
+    pub(crate) fn from_value(value: &Value<NumericTypes>) -> EvalexprResult<Self, NumericTypes> {
+        match value {
+            Value::Quantity(quantity) => Ok(quantity.clone()),
+            Value::Int(_) | Value::Float(_) => {
+                Ok(Quantity::new(value.as_number()?, Unit::dimensionless()))
+            },
+            _ => Err(EvalexprError::type_error(
+                value.clone(),
+                vec![ValueType::Float, ValueType::Int, ValueType::Quantity],
+            )),
+        }
+    }
+
+    /// Returns this quantity as value, where a quantity without dimension becomes a float.
+    pub(crate) fn into_value(self) -> EvalexprResultValue<NumericTypes> {
+        if self.unit.is_dimensionless() {
+            let factor = float_from_f64(self.unit.factor())?;
+            self.value.checked_mul(&factor).map(Value::Float)
+        } else {
+            Ok(Value::Quantity(self))
+        }
+    }
+
+    /// Returns the amount of the unit of `self` that equals `other`.
+    fn value_of(&self, other: &Self) -> EvalexprResult<NumericTypes::Float, NumericTypes> {
+        if !self.unit.is_compatible(&other.unit) {
+            return Err(EvalexprError::IncompatibleUnits {
+                a: self.unit.clone(),
+                b: other.unit.clone(),
+            });
+        }
+        Ok(other.convert(&self.unit)?.value)
+    }
+
+    pub(crate) fn checked_add(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let value = self.value.checked_add(&self.value_of(other)?)?;
+        Ok(Quantity::new(value, self.unit.clone()))
+    }
+
+    pub(crate) fn checked_sub(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let value = self.value.checked_sub(&self.value_of(other)?)?;
+        Ok(Quantity::new(value, self.unit.clone()))
+    }
+
+    pub(crate) fn checked_neg(&self) -> EvalexprResult<Self, NumericTypes> {
+        let value = float_from_f64::<NumericTypes>(0.0)?.checked_sub(&self.value)?;
+        Ok(Quantity::new(value, self.unit.clone()))
+    }
+
+    pub(crate) fn checked_mul(&self, other: &Self) -> EvalexprResult<Self, NumericTypes> {
+        let unit = self.unit.product(&other.unit).ok_or_else(|| {
+            EvalexprError::multiplication_error(
+                Value::Quantity(self.clone()),
+                Value::Quantity(other.clone()),
+            )
+        })?;
+        Ok(Quantity::new(self.value.checked_mul(&other.value)?, unit))
+    }
+
+    pub(crate) fn checked_div(
+        &self,
+        other: &Self,
+        rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        let unit = other
+            .unit
+            .powi(-1)
+            .and_then(|inverse| self.unit.product(&inverse))
+            .ok_or_else(|| {
+                EvalexprError::division_error(
+                    Value::Quantity(self.clone()),
+                    Value::Quantity(other.clone()),
+                )
+            })?;
+        let value = self.value.checked_div(&other.value, rounding_mode)?;
+        Ok(Quantity::new(value, unit))
+    }
+
+    /// Raises this quantity to an integer power by repeated squaring.
+    pub(crate) fn checked_powi(
+        &self,
+        exponent: i32,
+        rounding_mode: RoundingMode,
+    ) -> EvalexprResult<Self, NumericTypes> {
+        let unit = self.unit.powi(exponent).ok_or_else(|| {
+            EvalexprError::multiplication_error(
+                Value::Quantity(self.clone()),
+                Value::Quantity(self.clone()),
+            )
+        })?;
+
+        let mut value = float_from_f64::<NumericTypes>(1.0)?;
+        let mut base = self.clone().value;
+        let mut remaining = exponent.unsigned_abs();
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                value = value.checked_mul(&base)?;
+            }
+            remaining /= 2;
+            if remaining > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        if exponent < 0 {
+            value = float_from_f64::<NumericTypes>(1.0)?.checked_div(&value, rounding_mode)?;
+        }
+        Ok(Quantity::new(value, unit))
+    }
+
+    pub(crate) fn checked_partial_cmp(
+        &self,
+        other: &Self,
+    ) -> EvalexprResult<Option<Ordering>, NumericTypes> {
+        self.value.checked_partial_cmp(&self.value_of(other)?)
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes> PartialEq for Quantity<NumericTypes> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value_of(other)
+            .map_or(false, |value| self.value == value)
+    }
+}
+
+impl<NumericTypes: EvalexprNumericTypes> fmt::Display for Quantity<NumericTypes> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
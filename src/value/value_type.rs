@@ -17,6 +17,15 @@ pub enum ValueType {
     Tuple,
     /// The `Value::Empty` type.
     Empty,
+    /// The `Value::DateTime` type.
+    #[cfg(feature = "datetime")]
+    DateTime,
+    /// The `Value::Duration` type.
+    #[cfg(feature = "datetime")]
+    Duration,
+    /// The `Value::Quantity` type.
+    #[cfg(feature = "units")]
+    Quantity,
 }
 
 impl<NumericTypes: EvalexprNumericTypes> From<&Value<NumericTypes>> for ValueType {
@@ -28,6 +37,12 @@ impl<NumericTypes: EvalexprNumericTypes> From<&Value<NumericTypes>> for ValueTyp
             Value::Boolean(_) => ValueType::Boolean,
             Value::Tuple(_) => ValueType::Tuple,
             Value::Empty => ValueType::Empty,
+            #[cfg(feature = "datetime")]
+            Value::DateTime(_) => ValueType::DateTime,
+            #[cfg(feature = "datetime")]
+            Value::Duration(_) => ValueType::Duration,
+            #[cfg(feature = "units")]
+            Value::Quantity(_) => ValueType::Quantity,
         }
     }
 }
@@ -0,0 +1,232 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "bigint", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    eval_with_context, eval_with_context_mut, export::evalexpr_num_bigint::BigInt,
+    BigIntNumericTypes, ContextWithMutableVariables, EvalexprError, EvalexprInt, HashMapContext,
+    Value, BIGINT_MAXIMUM_LEFT_SHIFT, BIGINT_MAXIMUM_PRODUCT_BITS,
+};
+use std::str::FromStr;
+
+fn eval(string: &str) -> Result<Value<BigIntNumericTypes>, EvalexprError<BigIntNumericTypes>> {
+    eval_with_context(string, &HashMapContext::<BigIntNumericTypes>::new())
+}
+
+fn int(string: &str) -> Value<BigIntNumericTypes> {
+    Value::Int(BigInt::from_str(string).unwrap())
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    assert_eq!(eval("1 + 2 * 3"), Ok(int("7")));
+    assert_eq!(
+        eval("9223372036854775807 + 1"),
+        Ok(int("9223372036854775808"))
+    );
+    assert_eq!(
+        eval("-9223372036854775808 - 1"),
+        Ok(int("-9223372036854775809"))
+    );
+    assert_eq!(
+        eval("123456789012345678901234567890 * 1000000000000"),
+        Ok(int("123456789012345678901234567890000000000000"))
+    );
+    assert_eq!(
+        eval("math::factorial(25)"),
+        Ok(int("15511210043330985984000000"))
+    );
+    assert_eq!(eval("-7 / 2"), Ok(int("-3")));
+    assert_eq!(eval("-7 % 2"), Ok(int("-1")));
+    assert_eq!(eval("math::div_euclid(-7, 2)"), Ok(int("-4")));
+    assert_eq!(eval("math::rem_euclid(-7, 2)"), Ok(int("1")));
+    assert_eq!(eval("math::abs(-5)"), Ok(int("5")));
+    assert_eq!(
+        eval("1 / 0"),
+        Err(EvalexprError::DivisionError {
+            dividend: int("1"),
+            divisor: int("0"),
+        })
+    );
+    assert_eq!(
+        eval("1 % 0"),
+        Err(EvalexprError::ModulationError {
+            dividend: int("1"),
+            divisor: int("0"),
+        })
+    );
+    assert_eq!(eval("2.5 * 2"), Ok(Value::Float(5.0)));
+    assert_eq!(
+        eval("min(3, 100000000000000000000, 2.5)"),
+        Ok(Value::Float(2.5))
+    );
+    assert_eq!(
+        eval("max(3, 100000000000000000000, 2.5)"),
+        Ok(int("100000000000000000000"))
+    );
+    assert_eq!(eval("int(1e20)"), Ok(int("100000000000000000000")));
+}
+
+#[test]
+fn test_bigint_literals_and_bit_operations() {
+    assert_eq!(
+        eval("0xFFFF_FFFF_FFFF_FFFF_FFFF"),
+        Ok(int("1208925819614629174706175"))
+    );
+    assert_eq!(eval("0b1010"), Ok(int("10")));
+    assert_eq!(eval("0o17"), Ok(int("15")));
+    assert_eq!(eval("1 << 100"), Ok(int("1267650600228229401496703205376")));
+    assert_eq!(eval("(1 << 100) >> 99"), Ok(int("2")));
+    assert_eq!(eval("-5 >> 1"), Ok(int("-3")));
+    assert_eq!(eval("12 & 10"), Ok(int("8")));
+    assert_eq!(eval("12 | 10"), Ok(int("14")));
+    assert_eq!(eval("12 xor 10"), Ok(int("6")));
+    assert_eq!(eval("~5"), Ok(int("-6")));
+    assert_eq!(eval("-1 & 0xFF"), Ok(int("255")));
+    assert_eq!(
+        eval("1 << -1"),
+        Err(EvalexprError::ShiftError {
+            value: int("1"),
+            shift: int("-1"),
+        })
+    );
+}
+
+#[test]
+fn test_bigint_context() {
+    let mut context = HashMapContext::<BigIntNumericTypes>::new();
+    context
+        .set_value("total".into(), int("99999999999999999999"))
+        .unwrap();
+    assert_eq!(
+        eval_with_context_mut("total += 1; total", &mut context),
+        Ok(int("100000000000000000000"))
+    );
+    assert_eq!(
+        eval_with_context("len((1, 2, 3)) * total", &context),
+        Ok(int("300000000000000000000"))
+    );
+}
+
+#[test]
+fn test_left_shift_limit() {
+    let limit = BIGINT_MAXIMUM_LEFT_SHIFT.to_string();
+    assert_eq!(eval(&format!("(1 << {limit}) >> {limit}")), Ok(int("1")));
+    assert_eq!(
+        eval(&format!("1 << ({limit} + 1)")),
+        Err(EvalexprError::ShiftError {
+            value: int("1"),
+            shift: int(&(BIGINT_MAXIMUM_LEFT_SHIFT + 1).to_string()),
+        })
+    );
+    assert_eq!(
+        eval("1 << 100000000000"),
+        Err(EvalexprError::ShiftError {
+            value: int("1"),
+            shift: int("100000000000"),
+        })
+    );
+    assert_eq!(
+        eval("0 << 100000000000000000000000"),
+        Err(EvalexprError::ShiftError {
+            value: int("0"),
+            shift: int("100000000000000000000000"),
+        })
+    );
+}
+
+#[test]
+fn test_product_limit() {
+    let half = (BIGINT_MAXIMUM_PRODUCT_BITS / 2 - 1).to_string();
+    assert_eq!(
+        eval(&format!(
+            "(1 << {half}) * (1 << {half}) == 1 << (2 * {half})"
+        )),
+        Ok(Value::Boolean(true))
+    );
+    assert!(matches!(
+        eval(&format!("(1 << {half}) * (1 << ({half} + 1))")),
+        Err(EvalexprError::MultiplicationError { .. })
+    ));
+    assert_eq!(
+        eval("math::factorial(1000000)"),
+        Err(EvalexprError::ArgumentOutOfRange {
+            actual: int("1000000"),
+            minimum: int("0"),
+            maximum: int("52428"),
+        })
+    );
+    assert_eq!(
+        eval("math::binomial(10000000, 5000000)"),
+        Err(EvalexprError::ArgumentOutOfRange {
+            actual: int("5000000"),
+            minimum: int("0"),
+            maximum: int("43690"),
+        })
+    );
+    assert_eq!(
+        eval("math::binomial(10000000, 9999999)"),
+        Ok(int("10000000"))
+    );
+    assert_eq!(
+        eval("math::factorial(30)"),
+        Ok(int("265252859812191058636308480000000"))
+    );
+}
+
+#[test]
+fn test_right_shift_beyond_bits() {
+    assert_eq!(eval("(1 << 100) >> 1000"), Ok(int("0")));
+    assert_eq!(eval("1 >> 100000000000000000000000"), Ok(int("0")));
+    assert_eq!(eval("-(1 << 100) >> 1000"), Ok(int("-1")));
+    assert_eq!(eval("-1 >> 100000000000000000000000"), Ok(int("-1")));
+    assert_eq!(
+        eval("1 >> -1"),
+        Err(EvalexprError::ShiftError {
+            value: int("1"),
+            shift: int("-1"),
+        })
+    );
+}
+
+#[test]
+fn test_unchecked_shifts_reverse_for_negative_amounts() {
+    let shl = <BigInt as EvalexprInt<BigIntNumericTypes>>::shl;
+    let shr = <BigInt as EvalexprInt<BigIntNumericTypes>>::shr;
+    assert_eq!(shl(&BigInt::from(8), &BigInt::from(-3)), BigInt::from(1));
+    assert_eq!(shr(&BigInt::from(1), &BigInt::from(-3)), BigInt::from(8));
+    assert_eq!(
+        shl(
+            &BigInt::from(-1),
+            &BigInt::from_str("-100000000000000000000000").unwrap()
+        ),
+        BigInt::from(-1)
+    );
+}
+
+#[test]
+fn test_is_prime_beyond_u64() {
+    assert_eq!(
+        eval("math::is_prime(18446744073709551557)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("math::is_prime(18446744073709551629)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("math::is_prime((1 << 89) - 1)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("math::is_prime(((1 << 61) - 1) * ((1 << 89) - 1))"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn test_format_with_precision() {
+    assert_eq!(
+        eval("f\"{123456789012345678901234567:.1}\""),
+        Ok(Value::from("123456789012345678901234567.0"))
+    );
+}
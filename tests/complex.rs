@@ -0,0 +1,154 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "complex", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    eval_with_context, Complex, ComplexNumericTypes, EvalexprError, HashMapContext, Value,
+};
+
+fn eval(string: &str) -> Result<Value<ComplexNumericTypes>, EvalexprError<ComplexNumericTypes>> {
+    eval_with_context(string, &HashMapContext::<ComplexNumericTypes>::new())
+}
+
+fn complex(re: f64, im: f64) -> Value<ComplexNumericTypes> {
+    Value::Float(Complex::new(re, im))
+}
+
+fn assert_close(string: &str, re: f64, im: f64) {
+    let Ok(Value::Float(actual)) = eval(string) else {
+        panic!("{string} did not evaluate to a complex number");
+    };
+    assert!(
+        (actual.re() - re).abs() < 1e-12 && (actual.im() - im).abs() < 1e-12,
+        "{string} evaluated to {actual}, expected {re}+{im}i"
+    );
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    assert_eq!(eval("2i"), Ok(complex(0.0, 2.0)));
+    assert_eq!(eval("0.5i"), Ok(complex(0.0, 0.5)));
+    assert_eq!(eval("3 + 4i"), Ok(complex(3.0, 4.0)));
+    assert_eq!(eval("1i * 1i"), Ok(complex(-1.0, 0.0)));
+    assert_eq!(eval("(1 + 2i) * (3 - 1i)"), Ok(complex(5.0, 5.0)));
+    assert_eq!(eval("(3 + 4i) / 1i"), Ok(complex(4.0, -3.0)));
+    assert_eq!(eval("7 / 2"), Ok(Value::Int(3)));
+    assert_eq!(eval("(1 + 1i) == 1 + 1i"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("1.5 < 2"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("1i ^ 2"), Ok(complex(-1.0, 0.0)));
+    assert_eq!(eval("(-2) ^ 2"), Ok(complex(4.0, 0.0)));
+    assert_eq!(eval("floor(1.5 - 2.5i)"), Ok(complex(1.0, -3.0)));
+
+    assert_eq!(
+        eval("1i < 2i"),
+        Err(EvalexprError::ComparisonError {
+            a: complex(0.0, 1.0),
+            b: complex(0.0, 2.0),
+        })
+    );
+
+    // `i` on its own stays a variable.
+    assert_eq!(
+        eval("i"),
+        Err(EvalexprError::VariableIdentifierNotFound("i".into()))
+    );
+}
+
+#[test]
+fn test_complex_math() {
+    assert_eq!(eval("math::sqrt(-1)"), Ok(complex(0.0, 1.0)));
+    assert_eq!(eval("math::sqrt(-4.0)"), Ok(complex(0.0, 2.0)));
+    assert_eq!(eval("math::sqrt(4)"), Ok(complex(2.0, 0.0)));
+    assert_eq!(eval("math::cbrt(-8)"), Ok(complex(-2.0, 0.0)));
+    assert_close("math::exp(1i * 3.141592653589793)", -1.0, 0.0);
+    assert_close("math::exp(1i * 3.141592653589793) + 1", 0.0, 0.0);
+    assert_close("math::ln(-1)", 0.0, std::f64::consts::PI);
+    assert_close("math::sqrt(2i)", 1.0, 1.0);
+    assert_close("math::sin(1i)", 0.0, 1f64.sinh());
+    assert_close("(-8) ^ (1 / 3.0)", 1.0, 3f64.sqrt());
+    assert_close("math::exp(1i) ^ 2i", (-2f64).exp(), 0.0);
+}
+
+#[test]
+fn test_complex_pi() {
+    assert_eq!(
+        eval("math::acos(-1)"),
+        Ok(complex(std::f64::consts::PI, 0.0))
+    );
+    assert_close("math::exp(1i * math::acos(-1))", -1.0, 0.0);
+    assert_close("math::exp(1i * math::acos(-1)) + 1", 0.0, 0.0);
+}
+
+#[test]
+fn test_complex_builtins_and_display() {
+    assert_eq!(eval("re(3 + 4i)"), Ok(complex(3.0, 0.0)));
+    assert_eq!(eval("im(3 + 4i)"), Ok(complex(4.0, 0.0)));
+    assert_eq!(eval("im(3)"), Ok(complex(0.0, 0.0)));
+    assert_eq!(eval("conj(3 + 4i)"), Ok(complex(3.0, -4.0)));
+    assert_eq!(eval("abs(3 + 4i)"), Ok(complex(5.0, 0.0)));
+    assert_eq!(eval("math::abs(-3 - 4i)"), Ok(complex(5.0, 0.0)));
+    assert_close("arg(1i)", std::f64::consts::FRAC_PI_2, 0.0);
+    assert_close("arg(-1)", std::f64::consts::PI, 0.0);
+
+    assert_eq!(eval("3 + 4i").unwrap().to_string(), "3+4i");
+    assert_eq!(eval("3 - 4.5i").unwrap().to_string(), "3-4.5i");
+    assert_eq!(eval("2i").unwrap().to_string(), "2i");
+    assert_eq!(eval("1i * 1i").unwrap().to_string(), "-1");
+    assert_eq!(eval(r#"f"{1/3.0 + 2i:.2}""#), Ok(Value::from("0.33+2.00i")));
+    assert_eq!("3+4i".parse(), Ok(Complex::new(3.0, 4.0)));
+}
+
+#[test]
+fn test_complex_min_max_ordering_errors() {
+    assert_eq!(eval("max(1.5, 2, 0.5)"), Ok(Value::Int(2)));
+    assert_eq!(eval("min(2 + 0i, 1.5)"), Ok(complex(1.5, 0.0)));
+
+    let error = Err(EvalexprError::ComparisonError {
+        a: complex(0.0, 1.0),
+        b: complex(0.0, 2.0),
+    });
+    assert_eq!(eval("min(1i, 2i)"), error);
+    assert_eq!(eval("max(1i, 2i)"), error);
+    assert_eq!(
+        eval("max(1i, 2)"),
+        Err(EvalexprError::ComparisonError {
+            a: complex(2.0, 0.0),
+            b: complex(0.0, 1.0),
+        })
+    );
+    assert!(matches!(
+        eval("min(3, 1.5, 1i)"),
+        Err(EvalexprError::ComparisonError { .. })
+    ));
+}
+
+#[test]
+fn test_complex_division_by_zero() {
+    assert_eq!(
+        eval("(1 + 0i) / (0 + 0i)"),
+        Err(EvalexprError::DivisionError {
+            dividend: complex(1.0, 0.0),
+            divisor: complex(0.0, 0.0),
+        })
+    );
+    assert_eq!(
+        eval("5 / 0i"),
+        Err(EvalexprError::DivisionError {
+            dividend: complex(5.0, 0.0),
+            divisor: complex(0.0, 0.0),
+        })
+    );
+    assert_eq!(
+        eval("5 / 0"),
+        Err(EvalexprError::DivisionError {
+            dividend: Value::Int(5),
+            divisor: Value::Int(0),
+        })
+    );
+    assert_eq!(
+        eval("(3 + 4i) % 0.0"),
+        Err(EvalexprError::ModulationError {
+            dividend: complex(3.0, 4.0),
+            divisor: complex(0.0, 0.0),
+        })
+    );
+}
@@ -0,0 +1,179 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(feature = "datetime")]
+
+use evalexpr::{error::*, *};
+
+fn context() -> HashMapContext {
+    let mut context = HashMapContext::new();
+    context.set_clock(Clock::fixed(
+        chrono::DateTime::parse_from_rfc3339("2024-03-15T12:00:00Z").unwrap(),
+    ));
+    context
+        .set_value(
+            "order_date".into(),
+            eval("datetime(\"2024-02-20T09:30:00+01:00\")").unwrap(),
+        )
+        .unwrap();
+    context
+}
+
+#[test]
+fn test_datetime_parsing_and_formatting() {
+    let context = context();
+    let eval = |string| eval_with_context(string, &context).map(|value| value.to_string());
+
+    assert_eq!(
+        eval("datetime(\"2024-02-29\")"),
+        Ok("2024-02-29T00:00:00Z".into())
+    );
+    assert_eq!(
+        eval("datetime(\"2024-02-29T10:15:30.25\")"),
+        Ok("2024-02-29T10:15:30.250Z".into())
+    );
+    assert_eq!(eval("order_date"), Ok("2024-02-20T09:30:00+01:00".into()));
+    assert_eq!(
+        eval("datetime::format(order_date, \"%d.%m.%Y %H:%M\")"),
+        Ok("\"20.02.2024 09:30\"".into())
+    );
+    assert_eq!(
+        eval("datetime::format(duration(\"P1W2DT3H4M5.5S\"))"),
+        Ok("\"PT788645.5S\"".into())
+    );
+    assert_eq!(
+        eval("duration(\"-PT90M\") == minutes(-90)"),
+        Ok("true".into())
+    );
+    assert_eq!(eval("str::from(days(2))"), Ok("\"PT172800S\"".into()));
+    assert_eq!(
+        eval("typeof(now()) + typeof(days(1))"),
+        Ok("\"datetimeduration\"".into())
+    );
+
+    for string in ["2024-02-30", "yesterday", "P1Y", "PT", "P1.5D", "P1H"] {
+        let function = if string.starts_with('P') {
+            "duration"
+        } else {
+            "datetime"
+        };
+        assert!(matches!(
+            eval_with_context(&format!("{function}(\"{string}\")"), &context),
+            Err(EvalexprError::StringParse { .. })
+        ));
+    }
+    assert_eq!(
+        eval("datetime::format(order_date, \"%Q\")"),
+        Err(EvalexprError::IllegalFormatSpecification("%Q".into()))
+    );
+}
+
+#[test]
+fn test_datetime_arithmetic_and_comparison() {
+    let context = context();
+    let eval = |string| eval_with_context(string, &context);
+
+    assert_eq!(
+        eval("order_date + days(30) > now()"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("order_date + days(20) < now()"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("now() - order_date == days(24) + hours(3) + minutes(30)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("str::from(order_date - weeks(8))"),
+        Ok(Value::from("2023-12-26T09:30:00+01:00"))
+    );
+    assert_eq!(
+        eval("total_seconds(hours(1) - seconds(1) + milliseconds(1999))"),
+        Ok(Value::from_int(3600))
+    );
+    assert_eq!(
+        eval("datetime(\"2024-01-01T01:00:00+01:00\") == datetime(\"2024-01-01\")"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(eval("days(1) >= hours(24)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("minutes(1) < seconds(59)"), Ok(Value::Boolean(false)));
+
+    assert_eq!(
+        eval("now() + 1"),
+        Err(EvalexprError::WrongTypeCombination {
+            operator: Operator::Add,
+            actual: vec![ValueType::DateTime, ValueType::Int],
+        })
+    );
+    assert!(matches!(
+        eval("duration(\"-P1W\") - now()"),
+        Err(EvalexprError::WrongTypeCombination { .. })
+    ));
+    assert!(matches!(
+        eval("now() + weeks(20000000)"),
+        Err(EvalexprError::AdditionError { .. })
+    ));
+    assert!(matches!(
+        eval("days(9223372036854775807)"),
+        Err(EvalexprError::IntToNum { .. })
+    ));
+    assert!(matches!(
+        eval("now() > 5"),
+        Err(EvalexprError::ExpectedNumberOrString { .. })
+    ));
+
+    let mut context = context;
+    eval_with_context_mut("order_date += days(10)", &mut context).unwrap();
+    assert_eq!(
+        eval_with_context("order_date == datetime(\"2024-03-01T08:30:00Z\")", &context),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn test_datetime_components_and_clock() {
+    let mut context = context();
+
+    assert_eq!(
+        eval_with_context(
+            "(year(order_date), month(order_date), day(order_date), hour(order_date), minute(order_date), second(order_date), weekday(order_date))",
+            &context
+        ),
+        Ok(Value::from(vec![
+            Value::from_int(2024),
+            Value::from_int(2),
+            Value::from_int(20),
+            Value::from_int(9),
+            Value::from_int(30),
+            Value::from_int(0),
+            Value::from_int(2),
+        ]))
+    );
+    assert_eq!(
+        eval_with_context("weekday(now())", &context),
+        Ok(Value::from_int(5))
+    );
+    assert!(matches!(
+        eval_with_context("year(days(1))", &context),
+        Err(EvalexprError::TypeError { .. })
+    ));
+
+    // The clock can be replaced, and evaluations only read it when calling `now`.
+    let node = build_operator_tree("now() - order_date > weeks(4)").unwrap();
+    assert_eq!(node.eval_boolean_with_context(&context), Ok(false));
+    context.set_clock(Clock::new(|| {
+        chrono::DateTime::parse_from_rfc3339("2024-04-01T00:00:00+02:00").unwrap()
+    }));
+    assert_eq!(node.eval_boolean_with_context(&context), Ok(true));
+    assert_eq!(
+        eval_with_context("month(now())", &context),
+        Ok(Value::from_int(4))
+    );
+    assert!(eval_with_context("now(1)", &context).is_err());
+
+    context.set_builtin_functions_disabled(true).unwrap();
+    assert_eq!(
+        eval_with_context("now()", &context),
+        Err(EvalexprError::FunctionIdentifierNotFound("now".into()))
+    );
+}
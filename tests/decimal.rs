@@ -0,0 +1,219 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "decimal", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    eval_with_context, eval_with_context_mut, export::evalexpr_rust_decimal::Decimal, Context,
+    DecimalNumericTypes, EvalexprError, HashMapContext, RoundingMode, Value,
+};
+use std::str::FromStr;
+
+fn eval(string: &str) -> Result<Value<DecimalNumericTypes>, EvalexprError<DecimalNumericTypes>> {
+    eval_with_context(string, &HashMapContext::<DecimalNumericTypes>::new())
+}
+
+fn decimal(string: &str) -> Value<DecimalNumericTypes> {
+    Value::Float(Decimal::from_str(string).unwrap())
+}
+
+#[test]
+fn test_decimal_literals_and_arithmetic() {
+    assert_eq!(eval("19.99"), Ok(decimal("19.99")));
+    assert_eq!(eval("19.99").unwrap().to_string(), "19.99");
+    assert_eq!(eval("0.1 + 0.2 == 0.3"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("3 * 19.99"), Ok(decimal("59.97")));
+    assert_eq!(eval("1_000.5 - 0.75"), Ok(decimal("999.75")));
+    assert_eq!(eval("1.5e3"), Ok(decimal("1500")));
+    assert_eq!(eval("2.5e-3"), Ok(decimal("0.0025")));
+    assert_eq!(eval("7.5 % 2"), Ok(decimal("1.5")));
+    assert_eq!(eval("7 / 2"), Ok(Value::Int(3)));
+    assert_eq!(eval("7.0 / 2"), Ok(decimal("3.5")));
+    assert_eq!(eval("1.0 / 8"), Ok(decimal("0.125")));
+    assert_eq!(eval("round_to(2.675, 2)"), Ok(decimal("2.68")));
+    assert_eq!(eval("math::sqrt(6.25)"), Ok(decimal("2.5")));
+    assert_eq!(eval("floor(-2.5)"), Ok(decimal("-3")));
+    assert_eq!(eval("round(2.5)"), Ok(decimal("3")));
+    assert_eq!(eval("math::is_nan(1.5)"), Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn test_decimal_division_rounding() {
+    assert_eq!(
+        eval("2.0 / 3"),
+        Ok(decimal("0.6666666666666666666666666667"))
+    );
+    assert_eq!(
+        eval("-2.0 / 3"),
+        Ok(decimal("-0.6666666666666666666666666667"))
+    );
+
+    let mut context = HashMapContext::<DecimalNumericTypes>::new();
+    for (rounding_mode, positive, negative) in [
+        (
+            RoundingMode::NearestEven,
+            "0.6666666666666666666666666667",
+            "-0.6666666666666666666666666667",
+        ),
+        (
+            RoundingMode::TowardZero,
+            "0.6666666666666666666666666666",
+            "-0.6666666666666666666666666666",
+        ),
+        (
+            RoundingMode::AwayFromZero,
+            "0.6666666666666666666666666667",
+            "-0.6666666666666666666666666667",
+        ),
+        (
+            RoundingMode::Floor,
+            "0.6666666666666666666666666666",
+            "-0.6666666666666666666666666667",
+        ),
+        (
+            RoundingMode::Ceiling,
+            "0.6666666666666666666666666667",
+            "-0.6666666666666666666666666666",
+        ),
+    ] {
+        context.set_rounding_mode(rounding_mode);
+        assert_eq!(context.rounding_mode(), rounding_mode);
+        assert_eq!(
+            eval_with_context("2.0 / 3", &context),
+            Ok(decimal(positive))
+        );
+        assert_eq!(
+            eval_with_context("-2.0 / 3", &context),
+            Ok(decimal(negative))
+        );
+    }
+
+    // Ties at the last digit.
+    let tie = "0.0000000000000000000000000005 / 2";
+    for (rounding_mode, expected) in [
+        (RoundingMode::NearestEven, "0.0000000000000000000000000002"),
+        (
+            RoundingMode::NearestAwayFromZero,
+            "0.0000000000000000000000000003",
+        ),
+        (
+            RoundingMode::NearestTowardZero,
+            "0.0000000000000000000000000002",
+        ),
+    ] {
+        context.set_rounding_mode(rounding_mode);
+        assert_eq!(eval_with_context(tie, &context), Ok(decimal(expected)));
+    }
+
+    context.set_rounding_mode(RoundingMode::TowardZero);
+    eval_with_context_mut("a = 10.0; a /= 3", &mut context).unwrap();
+    assert_eq!(
+        context.get_value("a"),
+        Some(&decimal("3.3333333333333333333333333333"))
+    );
+}
+
+#[test]
+fn test_decimal_errors() {
+    assert_eq!(
+        eval("1.5 / 0"),
+        Err(EvalexprError::DivisionError {
+            dividend: decimal("1.5"),
+            divisor: decimal("0"),
+        })
+    );
+    assert!(matches!(
+        eval("1.5 % 0"),
+        Err(EvalexprError::ModulationError { .. })
+    ));
+    assert!(matches!(
+        eval("79228162514264337593543950335.0 + 1"),
+        Err(EvalexprError::AdditionError { .. })
+    ));
+    assert!(matches!(
+        eval("79228162514264337593543950335.0 * 2"),
+        Err(EvalexprError::MultiplicationError { .. })
+    ));
+    assert!(matches!(
+        eval("79228162514264337593543950335.0 / 0.5"),
+        Err(EvalexprError::DivisionError { .. })
+    ));
+}
+
+#[test]
+fn test_decimal_display_rounding() {
+    assert_eq!(eval(r#"f"{19.999:.2}""#), Ok(Value::from("20.00")));
+    assert_eq!(eval(r#"f"{-0.005:.2}""#), Ok(Value::from("0.00")));
+    assert_eq!(eval(r#"f"{2.5:.0}""#), Ok(Value::from("2")));
+    assert_eq!(eval(r#"f"{19.99:.4}""#), Ok(Value::from("19.9900")));
+
+    let mut context = HashMapContext::<DecimalNumericTypes>::new();
+    for (rounding_mode, positive, negative) in [
+        (RoundingMode::NearestAwayFromZero, "0.13", "-0.13"),
+        (RoundingMode::NearestTowardZero, "0.12", "-0.12"),
+        (RoundingMode::Floor, "0.12", "-0.13"),
+        (RoundingMode::Ceiling, "0.13", "-0.12"),
+    ] {
+        context.set_rounding_mode(rounding_mode);
+        assert_eq!(
+            eval_with_context(r#"f"{0.125:.2}""#, &context),
+            Ok(Value::from(positive))
+        );
+        assert_eq!(
+            eval_with_context(r#"f"{-0.125:.2}""#, &context),
+            Ok(Value::from(negative))
+        );
+    }
+}
+
+#[test]
+fn test_decimal_round_to_beyond_float_range() {
+    assert_eq!(eval("round_to(2.675, 40)"), Ok(decimal("2.675")));
+    assert_eq!(eval("round_to(2.675, -40)"), Ok(decimal("0")));
+}
+
+#[test]
+fn test_decimal_math_functions_without_result() {
+    for (expression, function, argument) in [
+        ("math::sqrt(-1.0)", "math::sqrt", decimal("-1")),
+        ("math::ln(0.0)", "math::ln", decimal("0")),
+        ("math::ln(-1.0)", "math::ln", decimal("-1")),
+        ("math::log2(0.0)", "math::log2", decimal("0")),
+        ("math::exp(1000.0)", "math::exp", decimal("1000")),
+        ("math::exp2(1000.0)", "math::exp2", decimal("1000")),
+        ("math::atanh(1.0)", "math::atanh", decimal("1")),
+        ("math::acos(2.0)", "math::acos", decimal("2")),
+        ("math::cosh(1000.0)", "math::cosh", decimal("1000")),
+    ] {
+        assert_eq!(
+            eval(expression),
+            Err(EvalexprError::MathFunctionError {
+                function: function.to_string(),
+                argument,
+            }),
+            "{expression}"
+        );
+    }
+    assert_eq!(
+        eval("(-1.0) ^ 0.5"),
+        Err(EvalexprError::MathFunctionError {
+            function: "math::pow".to_string(),
+            argument: Value::Tuple(vec![decimal("-1"), decimal("0.5")]),
+        })
+    );
+    assert_eq!(eval("(-2.0) ^ 3"), Ok(decimal("-8")));
+}
+
+#[test]
+fn test_decimal_out_of_range_literals() {
+    assert_eq!(eval("1e28"), Ok(decimal("10000000000000000000000000000")));
+    assert_eq!(eval("1e-28"), Ok(decimal("0.0000000000000000000000000001")));
+    for literal in ["1e30", "1e-30", "1_000e27", "79228162514264337593543950336"] {
+        assert_eq!(
+            eval(literal),
+            Err(EvalexprError::LiteralOutOfRange(literal.to_string()))
+        );
+    }
+    assert_eq!(
+        eval("2 * 1e+30"),
+        Err(EvalexprError::LiteralOutOfRange("1e+30".to_string()))
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "dual", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    build_operator_tree, eval_number_with_context, ContextWithMutableFunctions,
+    ContextWithMutableVariables, Dual, DualNumericTypes, EvalexprFloat, EvalexprNumericTypes,
+    Function, HashMapContext, Value,
+};
+
+fn context(x: f64, y: f64) -> HashMapContext<DualNumericTypes> {
+    let mut context = HashMapContext::new();
+    context
+        .set_value("x".into(), Value::Float(Dual::variable(x, 0)))
+        .unwrap();
+    context
+        .set_value("y".into(), Value::Float(Dual::variable(y, 1)))
+        .unwrap();
+    context
+}
+
+fn assert_gradient(string: &str, x: f64, y: f64, value: f64, gradient: [f64; 2]) {
+    let result = eval_number_with_context(string, &context(x, y)).unwrap();
+    assert!(
+        (result.value() - value).abs() < 1e-12
+            && (result.derivative(0) - gradient[0]).abs() < 1e-12
+            && (result.derivative(1) - gradient[1]).abs() < 1e-12,
+        "{string} evaluated to {result:?}, expected {value} with gradient {gradient:?}"
+    );
+}
+
+#[test]
+fn test_dual_arithmetic() {
+    assert_gradient("x + y", 3.0, 2.0, 5.0, [1.0, 1.0]);
+    assert_gradient("x - 2 * y", 3.0, 2.0, -1.0, [1.0, -2.0]);
+    assert_gradient("x * y", 3.0, 2.0, 6.0, [2.0, 3.0]);
+    assert_gradient("x / y", 3.0, 2.0, 1.5, [0.5, -0.75]);
+    assert_gradient("x ^ 2 * y + y", 3.0, 2.0, 20.0, [12.0, 10.0]);
+    assert_gradient("2 ^ x", 3.0, 2.0, 8.0, [8.0 * 2f64.ln(), 0.0]);
+    assert_gradient("x ^ y", 3.0, 2.0, 9.0, [6.0, 9.0 * 3f64.ln()]);
+    assert_gradient("-x % y", 3.0, 2.0, -1.0, [-1.0, 1.0]);
+    assert_gradient("if(x > y, x, y)", 3.0, 2.0, 3.0, [1.0, 0.0]);
+    assert_gradient("max(x, y)", 1.0, 2.0, 2.0, [0.0, 1.0]);
+
+    // Constants have no gradient, and comparisons only look at the value.
+    let constant = eval_number_with_context("7 / 2 + 0.5", &context(3.0, 2.0)).unwrap();
+    assert_eq!(constant.gradient(), []);
+    assert_eq!(constant, Dual::variable(3.5, 3));
+    assert_eq!(constant.to_string(), "3.5");
+}
+
+#[test]
+fn test_dual_math() {
+    assert_gradient(
+        "math::sin(x) * math::cos(y)",
+        1.0,
+        2.0,
+        1f64.sin() * 2f64.cos(),
+        [1f64.cos() * 2f64.cos(), -1f64.sin() * 2f64.sin()],
+    );
+    assert_gradient(
+        "math::exp(x * y)",
+        1.0,
+        2.0,
+        2f64.exp(),
+        [2.0 * 2f64.exp(), 2f64.exp()],
+    );
+    assert_gradient("math::ln(x) + math::sqrt(y)", 1.0, 4.0, 2.0, [1.0, 0.25]);
+    assert_gradient("math::hypot(x, y)", 3.0, 4.0, 5.0, [0.6, 0.8]);
+    assert_gradient(
+        "math::atan2(y, x)",
+        1.0,
+        1.0,
+        std::f64::consts::FRAC_PI_4,
+        [-0.5, 0.5],
+    );
+    assert_gradient("math::abs(x - y)", 1.0, 4.0, 3.0, [-1.0, 1.0]);
+    assert_gradient("floor(x) + y", 1.5, 4.0, 5.0, [0.0, 1.0]);
+}
+
+#[test]
+fn test_dual_zero_powers() {
+    // The derivative of a constant power is zero, even where the general power rule is undefined.
+    assert_gradient("x ^ 0", 0.0, 2.0, 1.0, [0.0, 0.0]);
+    assert_gradient("x ^ 2", 0.0, 2.0, 0.0, [0.0, 0.0]);
+    assert_gradient("y * x ^ 0", 0.0, 2.0, 2.0, [0.0, 1.0]);
+}
+
+/// A user function that works with any numeric types.
+fn add_square<NumericTypes: EvalexprNumericTypes>(context: &mut HashMapContext<NumericTypes>) {
+    context
+        .set_function(
+            "square".into(),
+            Function::new(|argument: &Value<NumericTypes>| {
+                let x = argument.as_number()?;
+                Ok(Value::Float(x.clone() * x))
+            }),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_dual_generic_functions_and_nodes() {
+    let mut context = context(3.0, 2.0);
+    add_square(&mut context);
+    let node = build_operator_tree::<DualNumericTypes>("square(x) + math::sin(y)").unwrap();
+
+    let result = node.eval_number_with_context(&context).unwrap();
+    assert_eq!(result.value(), 9.0 + 2f64.sin());
+    assert_eq!(result.gradient(), [6.0, 2f64.cos()]);
+
+    // The same node evaluates with other seeds.
+    context
+        .set_value("x".into(), Value::Float(Dual::constant(3.0)))
+        .unwrap();
+    let result = node.eval_number_with_context(&context).unwrap();
+    assert_eq!(result.gradient(), [0.0, 2f64.cos()]);
+    assert!(!EvalexprFloat::<DualNumericTypes>::is_nan(&result));
+}
@@ -405,6 +405,12 @@ fn test_builtin_functions() {
     assert_eq!(eval("math::abs(-15.4)"), Ok(Value::Float(15.4)));
     assert_eq!(eval("math::abs(15)"), Ok(Value::Int(15)));
     assert_eq!(eval("math::abs(-15)"), Ok(Value::Int(15)));
+    assert_eq!(
+        eval("math::abs(-9223372036854775807 - 1)"),
+        Err(EvalexprError::NegationError {
+            argument: Value::Int(i64::MIN)
+        })
+    );
     // Other
     assert_eq!(eval("typeof(4.0, 3)"), Ok(Value::String("tuple".into())));
     assert_eq!(eval("typeof(4.0)"), Ok(Value::String("float".into())));
@@ -583,6 +589,313 @@ fn test_builtin_functions() {
     );
 }
 
+#[test]
+fn test_stat_builtin_functions() {
+    assert_eq!(eval("stat::mean(1, 2, 3, 4)"), Ok(Value::Float(2.5)));
+    assert_eq!(eval("stat::mean(1.5, 2)"), Ok(Value::Float(1.75)));
+    assert_eq!(eval("stat::mean(3)"), Ok(Value::Float(3.0)));
+    assert_eq!(eval("stat::median(3, 1, 2)"), Ok(Value::Float(2.0)));
+    assert_eq!(eval("stat::median(4, 1, 3, 2)"), Ok(Value::Float(2.5)));
+    assert_eq!(eval("stat::mode(1, 2, 2, 3, 3)"), Ok(Value::Int(2)));
+    assert_eq!(eval("stat::mode(1.5, 2, 1.5)"), Ok(Value::Float(1.5)));
+    assert_eq!(
+        eval("stat::variance(2, 4, 4, 4, 5, 5, 7, 9)"),
+        Ok(Value::Float(4.0))
+    );
+    assert_eq!(
+        eval("stat::stddev(2, 4, 4, 4, 5, 5, 7, 9)"),
+        Ok(Value::Float(2.0))
+    );
+    assert_eq!(
+        eval("stat::sample_variance(1, 2, 3, 4)"),
+        Ok(Value::Float(5.0 / 3.0))
+    );
+    assert_eq!(
+        eval("stat::sample_stddev(1, 2, 3, 4)"),
+        Ok(Value::Float((5.0f64 / 3.0).sqrt()))
+    );
+    assert_eq!(
+        eval("stat::percentile((1, 2, 3, 4, 5), 50)"),
+        Ok(Value::Float(3.0))
+    );
+    assert_eq!(
+        eval("stat::percentile((1, 2, 3, 4), 100)"),
+        Ok(Value::Float(4.0))
+    );
+    assert_eq!(
+        eval("stat::percentile((4, 1, 3, 2), 0)"),
+        Ok(Value::Float(1.0))
+    );
+    assert_eq!(
+        eval("stat::quantile((10, 20, 30, 40), 0.5)"),
+        Ok(Value::Float(25.0))
+    );
+    assert_eq!(
+        eval("stat::quantile((10, 20), 0.25)"),
+        Ok(Value::Float(12.5))
+    );
+    assert_eq!(eval("stat::quantile(7, 0.9)"), Ok(Value::Float(7.0)));
+    assert_eq!(eval("stat::min_index(3, 1.5, 1.5, 8)"), Ok(Value::Int(1)));
+    assert_eq!(eval("stat::max_index(3, 9, 2.5, 9)"), Ok(Value::Int(1)));
+
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context
+        .set_value("a".into(), Value::Tuple(vec![Value::Int(1), Value::Int(5)]))
+        .unwrap();
+    assert_eq!(
+        eval_with_context("stat::mean(a)", &context),
+        Ok(Value::Float(3.0))
+    );
+
+    // Errors
+    assert_eq!(
+        eval("stat::mean()"),
+        Err(EvalexprError::expected_minimum_len_tuple(1, Value::Empty))
+    );
+    assert_eq!(
+        eval("stat::sample_variance(1)"),
+        Err(EvalexprError::expected_minimum_len_tuple(2, Value::Int(1)))
+    );
+    assert_eq!(
+        eval("stat::median(1, \"a\")"),
+        Err(EvalexprError::expected_number(Value::from("a")))
+    );
+    assert_eq!(
+        eval("stat::percentile((1, 2), 101)"),
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(101),
+            Value::Int(0),
+            Value::Int(100)
+        ))
+    );
+    assert_eq!(
+        eval("stat::quantile((1, 2), -0.5)"),
+        Err(EvalexprError::argument_out_of_range(
+            Value::Float(-0.5),
+            Value::Int(0),
+            Value::Int(1)
+        ))
+    );
+    assert_eq!(
+        eval("stat::quantile((), 0.5)"),
+        Err(EvalexprError::expected_minimum_len_tuple(1, Value::Empty))
+    );
+    assert!(eval("stat::quantile(1, 2, 0.5)").is_err());
+}
+
+#[test]
+fn test_conversion_builtin_functions() {
+    assert_eq!(eval("int(3.7)"), Ok(Value::Int(3)));
+    assert_eq!(eval("int(-3.7)"), Ok(Value::Int(-3)));
+    assert_eq!(eval("int(5)"), Ok(Value::Int(5)));
+    assert_eq!(eval("int(true)"), Ok(Value::Int(1)));
+    assert_eq!(eval("int(\"42\")"), Ok(Value::Int(42)));
+    assert_eq!(eval("int(\"-42\")"), Ok(Value::Int(-42)));
+    assert_eq!(eval("float(2)"), Ok(Value::Float(2.0)));
+    assert_eq!(eval("float(false)"), Ok(Value::Float(0.0)));
+    assert_eq!(eval("float(\"2.5\")"), Ok(Value::Float(2.5)));
+    assert_eq!(eval("float(\"1e3\")"), Ok(Value::Float(1000.0)));
+    assert_eq!(eval("bool(0)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("bool(-3)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("bool(0.0)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("bool(0.5)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("bool(\"true\")"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("str::parse_int(\"ff\", 16)"), Ok(Value::Int(255)));
+    assert_eq!(eval("str::parse_int(\"-101\", 2)"), Ok(Value::Int(-5)));
+    assert_eq!(eval("str::parse_int(\"z\", 36)"), Ok(Value::Int(35)));
+    assert_eq!(eval("str::parse_int(\"123\")"), Ok(Value::Int(123)));
+    assert_eq!(eval("str::parse_float(\"-0.25\")"), Ok(Value::Float(-0.25)));
+    assert_eq!(eval("int(str::from(17)) + 1"), Ok(Value::Int(18)));
+
+    // Errors
+    assert_eq!(
+        eval("int(\"3.7\")"),
+        Err(EvalexprError::StringParse {
+            string: "3.7".into(),
+            target_type: "i64"
+        })
+    );
+    assert_eq!(
+        eval("float(\"abc\")"),
+        Err(EvalexprError::StringParse {
+            string: "abc".into(),
+            target_type: "f64"
+        })
+    );
+    assert_eq!(
+        eval("bool(\"yes\")"),
+        Err(EvalexprError::StringParse {
+            string: "yes".into(),
+            target_type: "bool"
+        })
+    );
+    assert_eq!(
+        eval("str::parse_int(\"12\", 2)"),
+        Err(EvalexprError::StringParse {
+            string: "12".into(),
+            target_type: "i64"
+        })
+    );
+    assert_eq!(
+        eval("str::parse_int(\"12\", 37)"),
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(37),
+            Value::Int(2),
+            Value::Int(36)
+        ))
+    );
+    assert_eq!(
+        eval("int(1e19)"),
+        Err(EvalexprError::FloatToNum {
+            float: 1e19,
+            target_type: "i64"
+        })
+    );
+    assert_eq!(
+        eval("int(9223372036854775808.0)"),
+        Err(EvalexprError::FloatToNum {
+            float: 9223372036854775808.0,
+            target_type: "i64"
+        })
+    );
+    assert_eq!(
+        eval("int(-9223372036854775808.0)"),
+        Ok(Value::Int(i64::MIN))
+    );
+    assert!(matches!(
+        eval("int(math::sqrt(-1))"),
+        Err(EvalexprError::FloatToNum { .. })
+    ));
+    assert_eq!(
+        eval("int(())"),
+        Err(EvalexprError::type_error(
+            Value::Empty,
+            vec![
+                ValueType::String,
+                ValueType::Float,
+                ValueType::Int,
+                ValueType::Boolean
+            ]
+        ))
+    );
+}
+
+#[test]
+fn test_number_theory_builtin_functions() {
+    // Rounding
+    assert_eq!(eval("trunc(2.7)"), Ok(Value::Float(2.0)));
+    assert_eq!(eval("trunc(-2.7)"), Ok(Value::Float(-2.0)));
+    assert_eq!(eval("fract(2.5)"), Ok(Value::Float(0.5)));
+    assert_eq!(eval("fract(-2.5)"), Ok(Value::Float(-0.5)));
+    assert_eq!(eval("round_to(1.23456, 3)"), Ok(Value::Float(1.235)));
+    assert_eq!(eval("round_to(1234.5, -2)"), Ok(Value::Float(1200.0)));
+    assert_eq!(eval("round_to(2.5, 0)"), Ok(Value::Float(3.0)));
+    assert_eq!(eval("round_to(1.5, 400)"), Ok(Value::Float(1.5)));
+    assert_eq!(eval("round_to(1.5, 308)"), Ok(Value::Float(1.5)));
+    assert_eq!(eval("round_to(1.5, -400)"), Ok(Value::Float(0.0)));
+    assert_eq!(eval("clamp(5, 1, 3)"), Ok(Value::Int(3)));
+    assert_eq!(eval("clamp(-5, 1, 3)"), Ok(Value::Int(1)));
+    assert_eq!(eval("clamp(2, 1, 3)"), Ok(Value::Int(2)));
+    assert_eq!(eval("clamp(2.5, 1, 3)"), Ok(Value::Float(2.5)));
+    assert_eq!(eval("clamp(0.5, 1, 3)"), Ok(Value::Float(1.0)));
+
+    // Number theory
+    assert_eq!(eval("math::gcd(12, 18)"), Ok(Value::Int(6)));
+    assert_eq!(eval("math::gcd(-12, 18, 27)"), Ok(Value::Int(3)));
+    assert_eq!(eval("math::gcd(0, 0)"), Ok(Value::Int(0)));
+    assert_eq!(eval("math::lcm(4, 6)"), Ok(Value::Int(12)));
+    assert_eq!(eval("math::lcm(-4, 6, 10)"), Ok(Value::Int(60)));
+    assert_eq!(eval("math::lcm(0, 5)"), Ok(Value::Int(0)));
+    assert_eq!(eval("math::factorial(0)"), Ok(Value::Int(1)));
+    assert_eq!(eval("math::factorial(5)"), Ok(Value::Int(120)));
+    assert_eq!(
+        eval("math::factorial(20)"),
+        Ok(Value::Int(2432902008176640000))
+    );
+    assert_eq!(eval("math::binomial(5, 2)"), Ok(Value::Int(10)));
+    assert_eq!(eval("math::binomial(5, 0)"), Ok(Value::Int(1)));
+    assert_eq!(eval("math::binomial(5, 6)"), Ok(Value::Int(0)));
+    assert_eq!(eval("math::binomial(5, -1)"), Ok(Value::Int(0)));
+    assert_eq!(
+        eval("math::binomial(62, 31)"),
+        Ok(Value::Int(465428353255261088))
+    );
+    assert_eq!(eval("math::is_prime(1)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("math::is_prime(2)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("math::is_prime(25)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("math::is_prime(97)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("math::is_prime(1000000007)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("math::is_prime(561)"), Ok(Value::Boolean(false)));
+    assert_eq!(
+        eval("math::is_prime(3215031751)"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        eval("math::is_prime(9223372036854775783)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval("math::is_prime(9223372036854775807)"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(eval("math::sign(-7)"), Ok(Value::Int(-1)));
+    assert_eq!(eval("math::sign(0)"), Ok(Value::Int(0)));
+    assert_eq!(eval("math::sign(2.5)"), Ok(Value::Float(1.0)));
+    assert_eq!(eval("math::sign(0.0)"), Ok(Value::Float(0.0)));
+    assert_eq!(eval("math::div_euclid(-7, 2)"), Ok(Value::Int(-4)));
+    assert_eq!(eval("math::rem_euclid(-7, 2)"), Ok(Value::Int(1)));
+    assert_eq!(eval("math::div_euclid(7, -2)"), Ok(Value::Int(-3)));
+    assert_eq!(eval("math::rem_euclid(7, -2)"), Ok(Value::Int(1)));
+    assert_eq!(eval("math::div_euclid(-7.0, 2)"), Ok(Value::Float(-4.0)));
+    assert_eq!(eval("math::rem_euclid(-7.5, 2)"), Ok(Value::Float(0.5)));
+    assert_eq!(eval("math::rem_euclid(-7.5, -2)"), Ok(Value::Float(0.5)));
+
+    // Angles and interpolation
+    assert_eq!(
+        eval("math::to_radians(180)"),
+        Ok(Value::Float(std::f64::consts::PI))
+    );
+    assert_eq!(
+        eval(&format!("math::to_degrees({})", std::f64::consts::PI)),
+        Ok(Value::Float(180.0))
+    );
+    assert_eq!(eval("math::lerp(0, 10, 0.25)"), Ok(Value::Float(2.5)));
+    assert_eq!(eval("math::lerp(2, 4, 2)"), Ok(Value::Float(6.0)));
+
+    // Errors
+    assert_eq!(
+        eval("math::factorial(-1)"),
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(-1),
+            Value::Int(0),
+            Value::Int(i64::MAX)
+        ))
+    );
+    assert!(eval("math::factorial(21)").is_err());
+    assert!(eval("math::div_euclid(1, 0)").is_err());
+    assert!(eval("math::rem_euclid(1, 0)").is_err());
+    assert_eq!(
+        eval("math::gcd(1)"),
+        Err(EvalexprError::expected_minimum_len_tuple(2, Value::Int(1)))
+    );
+    assert_eq!(
+        eval("math::gcd(1, 2.0)"),
+        Err(EvalexprError::expected_int(Value::Float(2.0)))
+    );
+    assert_eq!(
+        eval("clamp(2, 3, 1)"),
+        Err(EvalexprError::argument_out_of_range(
+            Value::Int(1),
+            Value::Int(3),
+            Value::Int(i64::MAX)
+        ))
+    );
+    assert_eq!(
+        eval("math::sign(\"a\")"),
+        Err(EvalexprError::expected_number(Value::from("a")))
+    );
+}
+
 #[test]
 fn test_errors() {
     assert_eq!(
@@ -675,8 +988,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        eval_string("3..3"),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        eval_string("3.3.3"),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
     assert_eq!(
         eval_string_with_context("string", &context),
@@ -689,8 +1002,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        eval_string_with_context("3..3", &context),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        eval_string_with_context("3.3.3", &context),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
     assert_eq!(
         eval_string_with_context_mut("string", &mut context),
@@ -703,8 +1016,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        eval_string_with_context_mut("3..3", &mut context),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        eval_string_with_context_mut("3.3.3", &mut context),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
 
     assert_eq!(eval_float("3.3"), Ok(3.3));
@@ -750,7 +1063,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_int("(,);."),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
     assert_eq!(eval_int_with_context("3", &context), Ok(3));
     assert_eq!(
@@ -761,7 +1074,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_int_with_context("(,);.", &context),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
     assert_eq!(eval_int_with_context_mut("3", &mut context), Ok(3));
     assert_eq!(
@@ -772,7 +1085,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_int_with_context_mut("(,);.", &mut context),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
 
     assert_eq!(eval_number("3"), Ok(3.0));
@@ -863,7 +1176,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_tuple("3a3"),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
     assert_eq!(
         eval_tuple_with_context("3,3", &context),
@@ -877,7 +1190,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_tuple_with_context("3a3", &context),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
     assert_eq!(
         eval_tuple_with_context_mut("3,3", &mut context),
@@ -891,7 +1204,7 @@ fn test_shortcut_functions() {
     );
     assert_eq!(
         eval_tuple_with_context_mut("3a3", &mut context),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
 
     assert_eq!(eval_empty(""), Ok(EMPTY_VALUE));
@@ -954,10 +1267,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree::<DefaultNumericTypes>("3..3")
-            .unwrap()
-            .eval_string(),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        build_operator_tree::<DefaultNumericTypes>("3.3.3").and_then(|tree| tree.eval_string()),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
     assert_eq!(
         build_operator_tree("string")
@@ -974,10 +1285,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("3..3")
-            .unwrap()
-            .eval_string_with_context(&context),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        build_operator_tree("3.3.3").and_then(|tree| tree.eval_string_with_context(&context)),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
     assert_eq!(
         build_operator_tree("string")
@@ -994,10 +1303,9 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("3..3")
-            .unwrap()
-            .eval_string_with_context_mut(&mut context),
-        Err(EvalexprError::VariableIdentifierNotFound("3..3".to_owned()))
+        build_operator_tree("3.3.3")
+            .and_then(|tree| tree.eval_string_with_context_mut(&mut context)),
+        Err(EvalexprError::IllegalIdentifier("3.3.3".to_owned()))
     );
 
     assert_eq!(
@@ -1076,10 +1384,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree::<DefaultNumericTypes>("(,);.")
-            .unwrap()
-            .eval_int(),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        build_operator_tree::<DefaultNumericTypes>("(,);.").and_then(|tree| tree.eval_int()),
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
     assert_eq!(
         build_operator_tree("3")
@@ -1096,10 +1402,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("(,);.")
-            .unwrap()
-            .eval_int_with_context(&context),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        build_operator_tree("(,);.").and_then(|tree| tree.eval_int_with_context(&context)),
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
     assert_eq!(
         build_operator_tree("3")
@@ -1116,10 +1420,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("(,);.")
-            .unwrap()
-            .eval_int_with_context_mut(&mut context),
-        Err(EvalexprError::VariableIdentifierNotFound(".".to_owned()))
+        build_operator_tree("(,);.").and_then(|tree| tree.eval_int_with_context_mut(&mut context)),
+        Err(EvalexprError::IllegalIdentifier(".".to_owned()))
     );
 
     assert_eq!(
@@ -1265,10 +1567,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree::<DefaultNumericTypes>("3a3")
-            .unwrap()
-            .eval_tuple(),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        build_operator_tree::<DefaultNumericTypes>("3a3").and_then(|tree| tree.eval_tuple()),
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
     assert_eq!(
         build_operator_tree("3,3")
@@ -1285,10 +1585,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("3a3")
-            .unwrap()
-            .eval_tuple_with_context(&context),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        build_operator_tree("3a3").and_then(|tree| tree.eval_tuple_with_context(&context)),
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
     assert_eq!(
         build_operator_tree("3,3")
@@ -1305,10 +1603,8 @@ fn test_shortcut_functions() {
         })
     );
     assert_eq!(
-        build_operator_tree("3a3")
-            .unwrap()
-            .eval_tuple_with_context_mut(&mut context),
-        Err(EvalexprError::VariableIdentifierNotFound("3a3".to_owned()))
+        build_operator_tree("3a3").and_then(|tree| tree.eval_tuple_with_context_mut(&mut context)),
+        Err(EvalexprError::IllegalIdentifier("3a3".to_owned()))
     );
 
     assert_eq!(
@@ -1858,9 +2154,9 @@ fn test_error_constructors() {
     );
     assert_eq!(
         eval("&"),
-        Err(EvalexprError::UnmatchedPartialToken {
-            first: PartialToken::Ampersand,
-            second: None
+        Err(EvalexprError::WrongOperatorArgumentAmount {
+            expected: 2,
+            actual: 0
         })
     );
 
@@ -2410,12 +2706,55 @@ fn test_hex() {
     assert_eq!(eval("-0xFF"), Ok(Value::Int(-255)));
     assert_eq!(
         eval("0x"),
-        // The "VariableIdentifierNotFound" error is what evalexpr currently returns,
-        // but ideally it would return more specific errors for "illegal" literals.
-        Err(EvalexprError::VariableIdentifierNotFound("0x".into()))
+        Err(EvalexprError::IllegalIdentifier("0x".into()))
+    );
+}
+
+#[test]
+fn test_binary_and_octal() {
+    assert_eq!(eval("0b1010"), Ok(Value::Int(10)));
+    assert_eq!(eval("-0b1"), Ok(Value::Int(-1)));
+    assert_eq!(eval("0o17"), Ok(Value::Int(15)));
+    assert_eq!(eval("0o777 & 0b111"), Ok(Value::Int(7)));
+    assert_eq!(
+        eval("0b"),
+        Err(EvalexprError::IllegalIdentifier("0b".into()))
+    );
+    assert_eq!(
+        eval("0b102"),
+        Err(EvalexprError::IllegalIdentifier("0b102".into()))
+    );
+    assert_eq!(
+        eval("0o8"),
+        Err(EvalexprError::IllegalIdentifier("0o8".into()))
     );
 }
 
+#[test]
+fn test_digit_separators() {
+    assert_eq!(eval("1_000_000"), Ok(Value::Int(1_000_000)));
+    assert_eq!(eval("0b1010_0101"), Ok(Value::Int(0b1010_0101)));
+    assert_eq!(eval("0xFF_FF"), Ok(Value::Int(0xFFFF)));
+    assert_eq!(eval("0o7_7"), Ok(Value::Int(0o77)));
+    assert_eq!(eval("1_000.5"), Ok(Value::Float(1000.5)));
+    assert_eq!(eval("1_0e-1_0"), Ok(Value::Float(10e-10)));
+
+    // Identifiers may still contain and start with underscores.
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_value("_1".into(), Value::Int(3)).unwrap();
+    context.set_value("a_1".into(), Value::Int(4)).unwrap();
+    assert_eq!(eval_with_context("_1 + a_1", &context), Ok(Value::Int(7)));
+}
+
+#[test]
+fn test_scientific_notation() {
+    assert_eq!(eval("1e-9"), Ok(Value::Float(1e-9)));
+    assert_eq!(eval("6.02E23"), Ok(Value::Float(6.02e23)));
+    assert_eq!(eval("6.02e+23"), Ok(Value::Float(6.02e23)));
+    assert_eq!(eval("2E-3 * 1000"), Ok(Value::Float(2.0)));
+    assert_eq!(eval("1e3 - 1"), Ok(Value::Float(999.0)));
+}
+
 #[test]
 fn test_broken_string() {
     assert_eq!(
@@ -2555,22 +2894,1082 @@ fn test_escape_sequences() {
         eval("\"\\"),
         Err(EvalexprError::IllegalEscapeSequence("\\".to_string()))
     );
+
+    // Unicode escape sequences
+    assert_eq!(
+        eval("\"\\u{41}\\u{e9}\\u{1F600}\""),
+        Ok(Value::from("A\u{e9}\u{1F600}"))
+    );
+    assert_eq!(eval("'\\u{10FFFF}'"), Ok(Value::from("\u{10FFFF}")));
+    assert_eq!(
+        eval("\"\\u{110000}\""),
+        Err(EvalexprError::IllegalEscapeSequence(
+            "\\u{110000}".to_string()
+        ))
+    );
+    assert_eq!(
+        eval("\"\\u{D800}\""),
+        Err(EvalexprError::IllegalEscapeSequence(
+            "\\u{D800}".to_string()
+        ))
+    );
+    assert_eq!(
+        eval("\"\\u{}\""),
+        Err(EvalexprError::IllegalEscapeSequence("\\u{}".to_string()))
+    );
+    assert_eq!(
+        eval("\"\\u41\""),
+        Err(EvalexprError::IllegalEscapeSequence("\\u4".to_string()))
+    );
+    assert_eq!(
+        eval("\"\\u{1234567}\""),
+        Err(EvalexprError::IllegalEscapeSequence(
+            "\\u{1234567".to_string()
+        ))
+    );
+    assert_eq!(
+        eval("\"\\u{4g}\""),
+        Err(EvalexprError::IllegalEscapeSequence("\\u{4g".to_string()))
+    );
+}
+
+#[test]
+fn test_single_quoted_and_raw_strings() {
+    // Single-quoted strings
+    assert_eq!(eval("'abc'"), Ok(Value::from("abc")));
+    assert_eq!(eval("'a\"b' + \"c'd\""), Ok(Value::from("a\"bc'd")));
+    assert_eq!(eval("'it\\'s'"), Ok(Value::from("it's")));
+    assert_eq!(eval("\"it\\'s\""), Ok(Value::from("it's")));
+    assert_eq!(eval("''"), Ok(Value::from("")));
+    assert_eq!(eval("len 'abc'"), Ok(Value::from_int(3)));
+    assert_eq!(eval("'abc"), Err(EvalexprError::UnmatchedSingleQuote));
+
+    // Raw strings
+    assert_eq!(eval(r#"r"\d+\.\d*""#), Ok(Value::from(r"\d+\.\d*")));
+    assert_eq!(eval(r#"r"""#), Ok(Value::from("")));
+    assert_eq!(eval(r##"r#"say "hi""#"##), Ok(Value::from(r#"say "hi""#)));
+    assert_eq!(eval(r###"r##"a"#b"##"###), Ok(Value::from(r##"a"#b"##)));
+    assert_eq!(eval(r#"len(r"\d\d")"#), Ok(Value::from_int(4)));
+    assert_eq!(eval("r = 'x'; r"), Ok(Value::from("x")));
+    assert_eq!(eval(r#"r"abc"#), Err(EvalexprError::UnmatchedDoubleQuote));
+    assert_eq!(
+        eval(r##"r#"abc""##),
+        Err(EvalexprError::UnmatchedDoubleQuote)
+    );
+
+    // Format strings
+    assert_eq!(eval("f'{1}: {\"a\"}'"), Ok(Value::from("1: a")));
+    assert_eq!(eval("f\"{'}'}\""), Ok(Value::from("}")));
+    assert_eq!(eval("f'{1}"), Err(EvalexprError::UnmatchedSingleQuote));
+}
+
+#[test]
+fn test_unicode_and_quoted_identifiers() {
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_value("größe".into(), Value::Int(3)).unwrap();
+    context.set_value("π_2".into(), Value::Int(4)).unwrap();
+    context
+        .set_value("order total".into(), Value::Int(100))
+        .unwrap();
+    context
+        .set_value("price-eur".into(), Value::Int(20))
+        .unwrap();
+    context
+        .set_function(
+            "net price".into(),
+            Function::new(|argument| Ok(Value::Int(argument.as_int()? * 5 / 6))),
+        )
+        .unwrap();
+
+    // Unicode identifiers
+    assert_eq!(
+        eval_with_context("größe * π_2", &context),
+        Ok(Value::Int(12))
+    );
+    assert_eq!(eval("日本 = 2; 日本"), Ok(Value::Int(2)));
+    assert_eq!(
+        eval("a.b"),
+        Err(EvalexprError::VariableIdentifierNotFound("a.b".into()))
+    );
+    assert_eq!(eval("order.total = 2; order.total"), Ok(Value::Int(2)));
+    assert_eq!(
+        eval("a."),
+        Err(EvalexprError::IllegalIdentifier("a.".into()))
+    );
+    assert_eq!(eval("€"), Err(EvalexprError::IllegalIdentifier("€".into())));
+    assert_eq!(
+        eval("1abc"),
+        Err(EvalexprError::IllegalIdentifier("1abc".into()))
+    );
+    assert_eq!(eval("math::ln 1"), Ok(Value::Float(0.0)));
+    assert_eq!(
+        eval("math::"),
+        Err(EvalexprError::IllegalIdentifier("math::".into()))
+    );
+
+    // Quoted identifiers
+    assert_eq!(
+        eval_with_context("`order total` - `price-eur`", &context),
+        Ok(Value::Int(80))
+    );
+    assert_eq!(
+        eval_with_context("`net price`(`order total` + 20)", &context),
+        Ok(Value::Int(100))
+    );
+    assert_eq!(eval_with_context("`größe`", &context), Ok(Value::Int(3)));
+    assert_eq!(
+        eval_with_context_mut("`order total` += 1; `order total`", &mut context),
+        Ok(Value::Int(101))
+    );
+    assert_eq!(eval("`in` = 1; `in` + 1"), Ok(Value::Int(2)));
+    assert_eq!(eval("`a\\`b` = 1; `a\\`b`"), Ok(Value::Int(1)));
+    assert_eq!(
+        eval("`missing column`"),
+        Err(EvalexprError::VariableIdentifierNotFound(
+            "missing column".into()
+        ))
+    );
+    assert_eq!(eval("`abc"), Err(EvalexprError::UnmatchedBacktick));
+    assert_eq!(
+        eval("``"),
+        Err(EvalexprError::IllegalIdentifier("``".into()))
+    );
+
+    // Quoted identifiers are written back with backticks
+    let tree = build_operator_tree::<DefaultNumericTypes>("`order total` + `in` * a").unwrap();
+    assert_eq!(
+        tree.iter_read_variable_identifiers().collect::<Vec<_>>(),
+        vec!["order total", "in", "a"]
+    );
+    let display = tree.to_string();
+    assert!(display.contains("`order total`") && display.contains("`in`"));
+}
+
+#[test]
+fn test_membership_operators() {
+    assert_eq!(eval("2 in (1, 2, 3)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("4 in (1, 2, 3)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("4 not in (1, 2, 3)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("\"AT\" in (\"DE\", \"AT\")"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("\"ell\" in \"hello\""), Ok(Value::Boolean(true)));
+    assert_eq!(eval("\"\" in \"hello\""), Ok(Value::Boolean(true)));
+    assert_eq!(eval("\"x\" not   in \"hello\""), Ok(Value::Boolean(true)));
+    assert_eq!(
+        eval("age = 30; country = \"DE\"; age in 18..=65 && country in (\"DE\", \"AT\")"),
+        Ok(Value::Boolean(true))
+    );
+
+    // Ranges
+    assert_eq!(
+        eval("1..4"),
+        Ok(Value::Tuple(vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3)
+        ]))
+    );
+    assert_eq!(
+        eval("-1..=1"),
+        Ok(Value::Tuple(vec![
+            Value::Int(-1),
+            Value::Int(0),
+            Value::Int(1)
+        ]))
+    );
+    assert_eq!(eval("3..1"), Ok(Value::Tuple(vec![])));
+    assert_eq!(eval("1 + 1..2 * 2"), eval("(2, 3)"));
+    assert_eq!(eval("4 in 1..4"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("4 in 1..=4"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("0 not in 1..=4"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("3 in (1..4)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("r = 1..4; 3 in r"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("3.0 in 1..4"), Ok(Value::Boolean(false)));
+    assert_eq!(
+        eval("123456789 in 0..9223372036854775807"),
+        Ok(Value::Boolean(true))
+    );
+
+    // Errors
+    assert_eq!(
+        eval("1 in 5"),
+        Err(EvalexprError::type_error(
+            Value::Int(5),
+            vec![ValueType::Tuple, ValueType::String]
+        ))
+    );
+    assert_eq!(
+        eval("1 in \"1\""),
+        Err(EvalexprError::expected_string(Value::Int(1)))
+    );
+    assert_eq!(
+        eval("(1, 2) in (1, 2)"),
+        Err(EvalexprError::type_error(
+            Value::from(vec![Value::Int(1), Value::Int(2)]),
+            vec![
+                ValueType::String,
+                ValueType::Int,
+                ValueType::Float,
+                ValueType::Boolean
+            ]
+        ))
+    );
+    assert_eq!(
+        eval("1 in 0.5..2"),
+        Err(EvalexprError::expected_int(Value::Float(0.5)))
+    );
+    assert_eq!(
+        eval("0.5..2"),
+        Err(EvalexprError::expected_int(Value::Float(0.5)))
+    );
+    assert_eq!(
+        eval("0..9223372036854775807"),
+        Err(EvalexprError::LoopIterationBudgetExceeded)
+    );
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_loop_iteration_budget(3);
+    assert_eq!(eval_with_context("0..3", &context), eval("(0, 1, 2)"));
+    assert_eq!(
+        eval_with_context("(0..2, 0..2)", &context),
+        Err(EvalexprError::LoopIterationBudgetExceeded)
+    );
 }
 
 #[test]
 fn test_unmatched_partial_tokens() {
+    // `|` is no longer a partial token, but a bitwise or without operands.
     assert_eq!(
         eval("|"),
-        Err(EvalexprError::UnmatchedPartialToken {
-            first: PartialToken::VerticalBar,
-            second: None
+        Err(EvalexprError::WrongOperatorArgumentAmount {
+            expected: 2,
+            actual: 0
         })
     );
 }
 
 #[test]
-fn test_node_mutable_access() {
-    let mut node = build_operator_tree::<DefaultNumericTypes>("5").unwrap();
-    assert_eq!(node.children_mut().len(), 1);
+fn test_bitwise_operators() {
+    assert_eq!(eval("6 & 3"), Ok(Value::Int(2)));
+    assert_eq!(eval("6 | 3"), Ok(Value::Int(7)));
+    assert_eq!(eval("6 xor 3"), Ok(Value::Int(5)));
+    assert_eq!(eval("~5"), Ok(Value::Int(-6)));
+    assert_eq!(eval("~-1 & 7"), Ok(Value::Int(0)));
+    assert_eq!(eval("1 << 4"), Ok(Value::Int(16)));
+    assert_eq!(eval("256 >> 2"), Ok(Value::Int(64)));
+    assert_eq!(eval("-8 >> 1"), Ok(Value::Int(-4)));
+
+    // Precedence
+    assert_eq!(eval("2 + 1 << 1"), Ok(Value::Int(6)));
+    assert_eq!(eval("1 << 2 & 6"), Ok(Value::Int(4)));
+    assert_eq!(eval("1 | 2 xor 3 & 4"), Ok(Value::Int(3)));
+    assert_eq!(eval("(1 | 2) xor 3"), Ok(Value::Int(0)));
+    assert_eq!(eval("(6 & 3) == 2"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("(5 & 4) != 0 && true"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("bitand(6, 3) == (6 & 3)"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        eval("1 << 2 == 4 | 8"),
+        Err(EvalexprError::ExpectedInt {
+            actual: Value::Boolean(true)
+        })
+    );
+    assert_eq!(
+        eval("2 xor 3 < 4"),
+        Err(EvalexprError::ExpectedInt {
+            actual: Value::Boolean(true)
+        })
+    );
+
+    // Compound assignments
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    assert_eq!(eval_int_with_context_mut("a = 12; a", &mut context), Ok(12));
+    assert_eq!(eval_int_with_context_mut("a &= 10; a", &mut context), Ok(8));
+    assert_eq!(eval_int_with_context_mut("a |= 1; a", &mut context), Ok(9));
+    assert_eq!(
+        eval_int_with_context_mut("a xor= 3; a", &mut context),
+        Ok(10)
+    );
+    assert_eq!(
+        eval_int_with_context_mut("a <<= 2; a", &mut context),
+        Ok(40)
+    );
+    assert_eq!(
+        eval_int_with_context_mut("a >>= 1; a", &mut context),
+        Ok(20)
+    );
+
+    // Errors
+    assert_eq!(
+        eval("1.0 & 1"),
+        Err(EvalexprError::expected_int(Value::Float(1.0)))
+    );
+    assert_eq!(
+        eval("~true"),
+        Err(EvalexprError::expected_int(Value::Boolean(true)))
+    );
+    assert_eq!(
+        eval("1 << 64"),
+        Err(EvalexprError::ShiftError {
+            value: Value::Int(1),
+            shift: Value::Int(64)
+        })
+    );
+    assert_eq!(
+        eval("1 >> -1"),
+        Err(EvalexprError::ShiftError {
+            value: Value::Int(1),
+            shift: Value::Int(-1)
+        })
+    );
+    assert_eq!(
+        eval("shl(1, 64)"),
+        Err(EvalexprError::ShiftError {
+            value: Value::Int(1),
+            shift: Value::Int(64)
+        })
+    );
+}
+
+#[test]
+fn test_node_mutable_access() {
+    let mut node = build_operator_tree::<DefaultNumericTypes>("5").unwrap();
+    assert_eq!(node.children_mut().len(), 1);
     assert_eq!(*node.operator_mut(), Operator::RootNode);
 }
+
+#[test]
+fn test_comparison_chains() {
+    assert_eq!(eval("0 < 5 <= 10"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("0 < 10 <= 10"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("0 < 11 <= 10"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("0 < 0 <= 10"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("10 > 5 >= 5 > 4"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("1 < 2 < 3 < 2"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("0.5 < 1 < 1.5"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("\"a\" < \"b\" < \"c\""), Ok(Value::Boolean(true)));
+    assert_eq!(eval("x = 7; 0 < x + 1 <= 2 * 4"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        eval("x = 5; 0 <= x < 10 && 0 <= x * 2 < 10"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(eval("!(0 < 5 < 3)"), Ok(Value::Boolean(true)));
+
+    // Each operand is evaluated once, and evaluation stops at the first failing comparison
+    assert_eq!(eval("x = 0; 0 < (x += 1; x) <= 5; x"), Ok(Value::Int(1)));
+    assert_eq!(eval("x = 0; 5 < 1 < (x = 7; x); x"), Ok(Value::Int(0)));
+    assert_eq!(eval("5 < 1 < true"), Ok(Value::Boolean(false)));
+
+    // Parentheses and other comparators do not chain
+    assert_eq!(
+        eval("(0 < 5) <= 10"),
+        Err(EvalexprError::expected_number_or_string(Value::Boolean(
+            true
+        )))
+    );
+    assert_eq!(eval("1 < 2 == true"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("1 == 1 == true"), Ok(Value::Boolean(true)));
+
+    // Errors
+    assert_eq!(
+        eval("1 < 2 < true"),
+        Err(EvalexprError::expected_number_or_string(Value::Boolean(
+            true
+        )))
+    );
+    assert_eq!(
+        eval("1 < 2 <"),
+        Err(EvalexprError::wrong_operator_argument_amount(2, 3))
+    );
+}
+
+#[test]
+fn test_null_coalescing() {
+    assert_eq!(eval("a ?? 0"), Ok(Value::Int(0)));
+    assert_eq!(eval("a = 5; a ?? 0"), Ok(Value::Int(5)));
+    assert_eq!(eval("a = (); a ?? 0"), Ok(Value::Int(0)));
+    assert_eq!(eval("a = false; a ?? true"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("() ?? \"x\""), Ok(Value::from("x")));
+    assert_eq!(eval("(a) ?? 1"), Ok(Value::Int(1)));
+    assert_eq!(eval("a ?? b ?? 3"), Ok(Value::Int(3)));
+    assert_eq!(eval("b = 2; a ?? b ?? 3"), Ok(Value::Int(2)));
+    assert_eq!(eval("a ?? 1 + 2"), Ok(Value::Int(3)));
+    assert_eq!(eval("a ?? false || true"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("b = a ?? 1; b"), Ok(Value::Int(1)));
+
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_value("price".into(), Value::Int(10)).unwrap();
+    assert_eq!(
+        eval_with_context("price * (quantity ?? 1)", &context),
+        Ok(Value::Int(10))
+    );
+    context.set_value("quantity".into(), Value::Int(3)).unwrap();
+    assert_eq!(
+        eval_with_context("price * (quantity ?? 1)", &context),
+        Ok(Value::Int(30))
+    );
+
+    // The fallback is only evaluated if it is needed
+    assert_eq!(eval("x = 1; 2 ?? (x = 5); x"), Ok(Value::Int(1)));
+    assert_eq!(eval("x = 1; a ?? (x = 5); x"), Ok(Value::Int(5)));
+
+    // Only reading the unset variable directly falls back
+    assert_eq!(
+        eval("a + 1 ?? 0"),
+        Err(EvalexprError::VariableIdentifierNotFound("a".to_string()))
+    );
+    assert_eq!(
+        eval("a ?? b"),
+        Err(EvalexprError::VariableIdentifierNotFound("b".to_string()))
+    );
+    assert_eq!(
+        eval("f(1) ?? 0"),
+        Err(EvalexprError::FunctionIdentifierNotFound("f".to_string()))
+    );
+}
+
+#[test]
+fn test_optional_variable_builtin_functions() {
+    assert_eq!(eval("is_set(a)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("a = 1; is_set(a)"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("a = (); is_set(a)"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("is_set(())"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("is_set(\"\")"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("defined(\"a\")"), Ok(Value::Boolean(false)));
+    assert_eq!(eval("a = 1; defined(\"a\")"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("a = (); defined(\"a\")"), Ok(Value::Boolean(true)));
+
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_value("a".into(), Value::Int(1)).unwrap();
+    assert_eq!(
+        eval_with_context("is_set(a) && defined(\"a\") && !is_set(b)", &context),
+        Ok(Value::Boolean(true))
+    );
+    context.set_builtin_functions_disabled(true).unwrap();
+    assert_eq!(
+        eval_with_context("defined(\"a\")", &context),
+        Err(EvalexprError::FunctionIdentifierNotFound(
+            "defined".to_string()
+        ))
+    );
+    assert_eq!(
+        eval_with_context("is_set(b)", &context),
+        Err(EvalexprError::VariableIdentifierNotFound("b".to_string()))
+    );
+
+    // Functions of the context shadow the builtin functions
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context
+        .set_function(
+            "is_set".into(),
+            Function::new(|argument| Ok(Value::Boolean(argument.as_int()? > 0))),
+        )
+        .unwrap();
+    context
+        .set_function(
+            "defined".into(),
+            Function::new(|argument| Ok(argument.clone())),
+        )
+        .unwrap();
+    assert_eq!(
+        eval_with_context("is_set(1)", &context),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval_with_context("is_set(a)", &context),
+        Err(EvalexprError::VariableIdentifierNotFound("a".to_string()))
+    );
+    assert_eq!(
+        eval_with_context("defined(\"a\")", &context),
+        Ok(Value::from("a"))
+    );
+
+    // Errors
+    assert_eq!(
+        eval("is_set(a + 1)"),
+        Err(EvalexprError::VariableIdentifierNotFound("a".to_string()))
+    );
+    assert_eq!(
+        eval("defined(a)"),
+        Err(EvalexprError::VariableIdentifierNotFound("a".to_string()))
+    );
+    assert_eq!(
+        eval("defined(1)"),
+        Err(EvalexprError::expected_string(Value::Int(1)))
+    );
+}
+
+#[test]
+fn test_conditional_operator() {
+    assert_eq!(eval("true ? 1 : 2"), Ok(Value::Int(1)));
+    assert_eq!(eval("false ? 1 : 2"), Ok(Value::Int(2)));
+    assert_eq!(eval("3 > 2 ? \"yes\" : \"no\""), Ok(Value::from("yes")));
+    assert_eq!(
+        eval("x = 5; x < 0 ? -1 : x == 0 ? 0 : 1"),
+        Ok(Value::Int(1))
+    );
+    assert_eq!(
+        eval("x = 0; x < 0 ? -1 : x == 0 ? 0 : 1"),
+        Ok(Value::Int(0))
+    );
+    assert_eq!(eval("a = 1; a > 0 ? a < 5 ? 1 : 2 : 3"), Ok(Value::Int(1)));
+    assert_eq!(eval("a = 7; a > 0 ? a < 5 ? 1 : 2 : 3"), Ok(Value::Int(2)));
+    assert_eq!(eval("a = -1; a > 0 ? a < 5 ? 1 : 2 : 3"), Ok(Value::Int(3)));
+    assert_eq!(eval("true?1:2"), Ok(Value::Int(1)));
+    assert_eq!(
+        eval("false ? math::ln(1) : math::abs(-2)"),
+        Ok(Value::Int(2))
+    );
+
+    // Precedence
+    assert_eq!(eval("y = true ? 1 : 2; y"), Ok(Value::Int(1)));
+    assert_eq!(eval("false ? 1 : 2 + 3"), Ok(Value::Int(5)));
+    assert_eq!(eval("1 + (true ? 1 : 2)"), Ok(Value::Int(2)));
+    assert_eq!(eval("true || false ? 1 : 2"), Ok(Value::Int(1)));
+    assert_eq!(eval("a ?? false ? 1 : 2"), Ok(Value::Int(2)));
+    assert_eq!(
+        eval("true ? 1, 2 : 3"),
+        Ok(Value::Tuple(vec![Value::Int(1), Value::Int(2)]))
+    );
+    assert_eq!(
+        eval("false ? 1 : 2, 3"),
+        Ok(Value::Tuple(vec![Value::Int(2), Value::Int(3)]))
+    );
+    assert_eq!(eval("x = 1; true ? x = 2 : (); x"), Ok(Value::Int(2)));
+
+    // Only the chosen branch is evaluated
+    assert_eq!(eval("x = 1; true ? 2 : (x = 5); x"), Ok(Value::Int(1)));
+    assert_eq!(eval("false ? unknown : 3"), Ok(Value::Int(3)));
+    assert_eq!(eval("true ? 1 : 1 / 0"), Ok(Value::Int(1)));
+
+    // Errors
+    assert_eq!(
+        eval("1 ? 2 : 3"),
+        Err(EvalexprError::expected_boolean(Value::Int(1)))
+    );
+    assert_eq!(eval("1 ? 2"), Err(EvalexprError::UnmatchedQuestionMark));
+    assert_eq!(
+        eval("(true ? 2) : 3"),
+        Err(EvalexprError::UnmatchedQuestionMark)
+    );
+    assert_eq!(eval("1 : 2"), Err(EvalexprError::UnmatchedColon));
+    assert_eq!(eval("a:b = 1"), Err(EvalexprError::UnmatchedColon));
+    assert_eq!(eval("`a:b` = 1; `a:b` + 1"), Ok(Value::Int(2)));
+    assert_eq!(eval("a::b = 1; a::b"), Ok(Value::Int(1)));
+    assert_eq!(eval("true ? (1 : 2)"), Err(EvalexprError::UnmatchedLBrace));
+    assert_eq!(eval("(true ? 1 : 2"), Err(EvalexprError::UnmatchedLBrace));
+}
+
+#[test]
+fn test_loops_and_blocks() {
+    // Blocks
+    assert_eq!(eval("{ 1 + 2 }"), Ok(Value::Int(3)));
+    assert_eq!(eval("{ a = 1; a + 1 }"), Ok(Value::Int(2)));
+    assert_eq!(eval("{}"), Ok(Value::Empty));
+    assert_eq!(eval("{ 2 } * { 3 }"), Ok(Value::Int(6)));
+    assert_eq!(eval("x = { 1; 2 }; x"), Ok(Value::Int(2)));
+    assert_eq!(eval("true ? { a = 1; a } : 0"), Ok(Value::Int(1)));
+
+    // While loops
+    assert_eq!(
+        eval("i = 0; sum = 0; while i < 5 { i += 1; sum += i }; sum"),
+        Ok(Value::Int(15))
+    );
+    assert_eq!(eval("while false { 1 }"), Ok(Value::Empty));
+    assert_eq!(
+        eval("i = 0; while (i < 3) { i += 1 }; i"),
+        Ok(Value::Int(3))
+    );
+    assert_eq!(
+        eval("i = 0; while true { i += 1; i == 10 ? break : () }; i"),
+        Ok(Value::Int(10))
+    );
+    assert_eq!(
+        eval("i = 0; odd = 0; while i < 10 { i += 1; i % 2 == 0 ? continue : (); odd += 1 }; odd"),
+        Ok(Value::Int(5))
+    );
+
+    // For loops
+    assert_eq!(
+        eval("sum = 0; for x in (1, 2, 3) { sum += x }; sum"),
+        Ok(Value::Int(6))
+    );
+    assert_eq!(
+        eval("sum = 0; for x in 1..=100 { sum += x }; sum"),
+        Ok(Value::Int(5050))
+    );
+    assert_eq!(
+        eval("n = 0; for i in 0..9223372036854775807 { i == 3 ? break : (); n += 1 }; n"),
+        Ok(Value::Int(3))
+    );
+    assert_eq!(
+        eval("s = \"\"; for w in (\"a\", \"b\") { s += w }; s"),
+        Ok(Value::from("ab"))
+    );
+    assert_eq!(eval("for x in () { 1 }"), Ok(Value::Empty));
+    assert_eq!(
+        eval("for x in (1, 2) {}; x"),
+        Err(EvalexprError::VariableIdentifierNotFound("x".to_string()))
+    );
+    assert_eq!(
+        eval("x = \"outer\"; for x in 1..=3 {}; x"),
+        Ok(Value::from("outer"))
+    );
+    assert_eq!(
+        eval("s = 0.0; for v in (1, 2.5) { s = s + v }; s"),
+        Ok(Value::Float(3.5))
+    );
+    assert_eq!(
+        eval("n = 0; for v in (1, \"a\", ()) { n += 1 }; n"),
+        Ok(Value::Int(3))
+    );
+    assert_eq!(eval("i = 0; for i in (1, \"a\") {}; i"), Ok(Value::Int(0)));
+    assert_eq!(
+        eval("n = 0; for i in 0..3 { for j in 0..3 { j == 1 ? break : (); n += 1 } }; n"),
+        Ok(Value::Int(3))
+    );
+    assert_eq!(
+        eval("n = 0; for i in 0..4 { i % 2 == 0 ? continue : (); n += i }; n"),
+        Ok(Value::Int(4))
+    );
+
+    // Evaluation budget
+    assert_eq!(
+        eval("while true {}"),
+        Err(EvalexprError::LoopIterationBudgetExceeded)
+    );
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_loop_iteration_budget(10);
+    assert_eq!(
+        eval_with_context_mut("n = 0; for i in 0..10 { n += 1 }; n", &mut context),
+        Ok(Value::Int(10))
+    );
+    assert_eq!(
+        eval_with_context_mut("for i in 0..5 {}; for i in 0..6 {}", &mut context),
+        Err(EvalexprError::LoopIterationBudgetExceeded)
+    );
+    assert_eq!(
+        eval_with_context_mut("for i in 0..4 { for j in 0..3 {} }", &mut context),
+        Err(EvalexprError::LoopIterationBudgetExceeded)
+    );
+    context.set_loop_iteration_budget(0);
+    assert_eq!(
+        eval_with_context_mut("while false {}; 1", &mut context),
+        Ok(Value::Int(1))
+    );
+
+    // Immutable contexts
+    assert_eq!(
+        eval_with_context(
+            "n = 0; while false {}",
+            &HashMapContext::<DefaultNumericTypes>::new()
+        ),
+        Err(EvalexprError::ContextNotMutable)
+    );
+    assert_eq!(
+        eval_with_context(
+            "while false {}",
+            &HashMapContext::<DefaultNumericTypes>::new()
+        ),
+        Ok(Value::Empty)
+    );
+    assert_eq!(
+        eval_with_context(
+            "for x in (1, 2) {}",
+            &HashMapContext::<DefaultNumericTypes>::new()
+        ),
+        Err(EvalexprError::ContextNotMutable)
+    );
+
+    // Identifiers
+    let tree = build_operator_tree::<DefaultNumericTypes>("for x in xs { y += x }").unwrap();
+    assert_eq!(
+        tree.iter_write_variable_identifiers().collect::<Vec<_>>(),
+        vec!["x", "y"]
+    );
+    assert_eq!(
+        tree.iter_read_variable_identifiers().collect::<Vec<_>>(),
+        vec!["xs", "x"]
+    );
+
+    // Errors
+    assert_eq!(eval("break"), Err(EvalexprError::BreakOutsideOfLoop));
+    assert_eq!(
+        eval("1; continue"),
+        Err(EvalexprError::ContinueOutsideOfLoop)
+    );
+    assert_eq!(
+        eval("while 1 {}"),
+        Err(EvalexprError::expected_boolean(Value::Int(1)))
+    );
+    assert_eq!(
+        eval("for x in 1 {}"),
+        Err(EvalexprError::expected_tuple(Value::Int(1)))
+    );
+    assert_eq!(eval("while true"), Err(EvalexprError::MissingLoopBody));
+    assert_eq!(eval("while (true {})"), Err(EvalexprError::MissingLoopBody));
+    assert_eq!(
+        eval("for 1 in (1, 2) {}"),
+        Err(EvalexprError::MalformedForLoopHeader)
+    );
+    assert_eq!(eval("for x {}"), Err(EvalexprError::MalformedForLoopHeader));
+    assert_eq!(eval("{ 1"), Err(EvalexprError::UnmatchedLCurlyBrace));
+    assert_eq!(eval("1 }"), Err(EvalexprError::UnmatchedRCurlyBrace));
+    assert_eq!(eval("{ (1 }"), Err(EvalexprError::UnmatchedLBrace));
+}
+
+#[test]
+fn test_let_bindings() {
+    assert_eq!(eval("let x = 2; x * 3"), Ok(Value::Int(6)));
+    assert_eq!(eval("let x = 2"), Ok(Value::Empty));
+    assert_eq!(eval("let x = 1; x = 2; x"), Ok(Value::Int(2)));
+    assert_eq!(eval("let x = 1; x += 2; x"), Ok(Value::Int(3)));
+    assert_eq!(eval("let x = 1; let x = x + 1; x"), Ok(Value::Int(2)));
+    assert_eq!(eval("let x = 1; { let x = 2; x } + x"), Ok(Value::Int(3)));
+    assert_eq!(eval("let x = 1; { x = 2 }; x"), Ok(Value::Int(2)));
+    assert_eq!(eval("let x = 1; (let x = 2; x) + x"), Ok(Value::Int(3)));
+    assert_eq!(eval("let x = 1; defined(\"x\")"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        eval("sum = 0; for i in 1..=3 { let square = i * i; sum += square }; sum"),
+        Ok(Value::Int(14))
+    );
+
+    // Bindings do not outlive their chain or block
+    assert_eq!(
+        eval("{ let x = 1 }; x"),
+        Err(EvalexprError::VariableIdentifierNotFound("x".to_string()))
+    );
+    assert_eq!(
+        eval("(let x = 1; x); x"),
+        Err(EvalexprError::VariableIdentifierNotFound("x".to_string()))
+    );
+    assert_eq!(
+        eval("for i in 0..2 { is_set(x) ? break : (); let x = i }; x ?? \"unset\""),
+        Ok(Value::from("unset"))
+    );
+
+    // Bindings shadow the context without modifying it
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context.set_value("x".to_string(), Value::Int(10)).unwrap();
+    assert_eq!(
+        eval_with_context_mut("let x = 1; let y = 2; x + y", &mut context),
+        Ok(Value::Int(3))
+    );
+    assert_eq!(
+        eval_with_context_mut("let x = \"shadow\"; x = \"changed\"; x", &mut context),
+        Ok(Value::from("changed"))
+    );
+    assert_eq!(context.get_value("x"), Some(&Value::Int(10)));
+    assert_eq!(context.get_value("y"), None);
+    assert_eq!(
+        eval_with_context_mut("{ let y = 1 }; x = 20; z = 30", &mut context),
+        Ok(Value::Empty)
+    );
+    assert_eq!(context.get_value("x"), Some(&Value::Int(20)));
+    assert_eq!(context.get_value("z"), Some(&Value::Int(30)));
+
+    // Immutable contexts
+    assert_eq!(
+        eval_with_context(
+            "let x = 2; x * x",
+            &HashMapContext::<DefaultNumericTypes>::new()
+        ),
+        Ok(Value::Int(4))
+    );
+    assert_eq!(
+        eval_with_context(
+            "let x = 2; x = 3",
+            &HashMapContext::<DefaultNumericTypes>::new()
+        ),
+        Err(EvalexprError::ContextNotMutable)
+    );
+
+    // Identifiers
+    let tree = build_operator_tree::<DefaultNumericTypes>("let x = y; x").unwrap();
+    assert_eq!(
+        tree.iter_write_variable_identifiers().collect::<Vec<_>>(),
+        vec!["x"]
+    );
+    assert_eq!(
+        tree.iter_read_variable_identifiers().collect::<Vec<_>>(),
+        vec!["y", "x"]
+    );
+
+    // Errors
+    assert_eq!(eval("let x"), Err(EvalexprError::MalformedLetBinding));
+    assert_eq!(eval("let x += 1"), Err(EvalexprError::MalformedLetBinding));
+    assert_eq!(eval("let 1 = 1"), Err(EvalexprError::MalformedLetBinding));
+}
+
+#[test]
+fn test_tuple_destructuring_assignment() {
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context
+        .set_function(
+            "minmax".into(),
+            Function::new(|argument| {
+                let values = argument.as_tuple()?;
+                let mut min: i64 = values[0].as_int()?;
+                let mut max = min;
+                for value in &values[1..] {
+                    min = min.min(value.as_int()?);
+                    max = max.max(value.as_int()?);
+                }
+                Ok(Value::from(vec![Value::Int(min), Value::Int(max)]))
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        eval_with_context_mut("(lo, hi) = minmax(3, 1, 4, 1, 5); hi - lo", &mut context),
+        Ok(Value::Int(4))
+    );
+    assert_eq!(context.get_value("lo"), Some(&Value::Int(1)));
+    assert_eq!(context.get_value("hi"), Some(&Value::Int(5)));
+    assert_eq!(
+        eval_with_context_mut("a = 1; b = 2; a, b = b, a", &mut context),
+        Ok(Value::Empty)
+    );
+    assert_eq!(context.get_value("a"), Some(&Value::Int(2)));
+    assert_eq!(context.get_value("b"), Some(&Value::Int(1)));
+
+    assert_eq!(eval("a, b = 1, 2; a - b"), Ok(Value::Int(-1)));
+    assert_eq!(eval("(a, b) = 1, 2; a - b"), Ok(Value::Int(-1)));
+    assert_eq!(eval("a, b = (1, 2); a - b"), Ok(Value::Int(-1)));
+    assert_eq!(eval("t = (1, 2); a, b = t; a - b"), Ok(Value::Int(-1)));
+    assert_eq!(eval("a, (b, c) = 1, (2, 3); a + b * c"), Ok(Value::Int(7)));
+    assert_eq!(eval("(a) = 1; a"), Ok(Value::Int(1)));
+    assert_eq!(eval("let (a, b) = 1, 2; a - b"), Ok(Value::Int(-1)));
+    assert_eq!(
+        eval("x, y = 1, 2; { let (x, y) = y, x; x - y } + x - y"),
+        Ok(Value::Int(0))
+    );
+
+    // Single variables keep the precedence of the assignment over the aggregation
+    assert_eq!(
+        eval("x = 1, 2"),
+        Ok(Value::from(vec![Value::Empty, Value::Int(2)]))
+    );
+    assert_eq!(
+        eval("1, x = 2"),
+        Ok(Value::from(vec![Value::Int(1), Value::Empty]))
+    );
+
+    // Tuples can be followed by further expressions in a chain
+    assert_eq!(eval("1, 2; 3"), Ok(Value::Int(3)));
+    assert_eq!(eval("1; 2, 3; 4"), Ok(Value::Int(4)));
+    assert_eq!(
+        eval("1, 2; 3, 4"),
+        Ok(Value::from(vec![Value::Int(3), Value::Int(4)]))
+    );
+
+    // Identifiers
+    let tree = build_operator_tree::<DefaultNumericTypes>("(a, b) = b, a").unwrap();
+    assert_eq!(
+        tree.iter_write_variable_identifiers().collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert_eq!(
+        tree.iter_read_variable_identifiers().collect::<Vec<_>>(),
+        vec!["b", "a"]
+    );
+
+    // Errors
+    assert_eq!(
+        eval("a, b = 1, 2, 3"),
+        Err(EvalexprError::expected_fixed_len_tuple(
+            2,
+            Value::from(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+        ))
+    );
+    assert_eq!(
+        eval("(a, b) = 1"),
+        Err(EvalexprError::expected_fixed_len_tuple(2, Value::Int(1)))
+    );
+    assert_eq!(
+        eval("a, (b, c) = 1, (2, 3, 4)"),
+        Err(EvalexprError::expected_fixed_len_tuple(
+            2,
+            Value::from(vec![Value::Int(2), Value::Int(3), Value::Int(4)])
+        ))
+    );
+    assert_eq!(
+        eval_with_context("a, b = 1, 2", &HashMapContext::<DefaultNumericTypes>::new()),
+        Err(EvalexprError::ContextNotMutable)
+    );
+}
+
+#[test]
+fn test_format_strings() {
+    assert_eq!(
+        eval("a = 3; b = 2.0 / 3; f\"{a} of {b:.2}\""),
+        Ok(Value::from("3 of 0.67"))
+    );
+    assert_eq!(eval("f\"\""), Ok(Value::from("")));
+    assert_eq!(eval("f\"plain\""), Ok(Value::from("plain")));
+    assert_eq!(eval("f\"{1 + 2}\" + \"!\""), Ok(Value::from("3!")));
+    assert_eq!(
+        eval("name = \"evalexpr\"; f\"Hello, {name}!\""),
+        Ok(Value::from("Hello, evalexpr!"))
+    );
+    assert_eq!(
+        eval("f\"{true} {()} {(1, \"a\")}\""),
+        Ok(Value::from("true () (1, \"a\")"))
+    );
+
+    // Format specifications
+    assert_eq!(eval("f\"[{1:5}]\""), Ok(Value::from("[    1]")));
+    assert_eq!(eval("f\"[{\"ab\":5}]\""), Ok(Value::from("[ab   ]")));
+    assert_eq!(eval("f\"[{1:<5}]\""), Ok(Value::from("[1    ]")));
+    assert_eq!(eval("f\"[{\"ab\":>5}]\""), Ok(Value::from("[   ab]")));
+    assert_eq!(eval("f\"[{\"ab\":^5}]\""), Ok(Value::from("[ ab  ]")));
+    assert_eq!(eval("f\"[{7:0>3}]\""), Ok(Value::from("[007]")));
+    assert_eq!(eval("f\"[{\"x\":*^5}]\""), Ok(Value::from("[**x**]")));
+    assert_eq!(eval("f\"[{3.14159:8.3}]\""), Ok(Value::from("[   3.142]")));
+    assert_eq!(eval("f\"{5:.2}\""), Ok(Value::from("5.00")));
+    assert_eq!(eval("f\"{-3:.1}\""), Ok(Value::from("-3.0")));
+    assert_eq!(
+        eval("f\"{9007199254740993:.0}\""),
+        Ok(Value::from("9007199254740993"))
+    );
+    assert_eq!(eval("f\"{1:05}\""), Ok(Value::from("00001")));
+    assert_eq!(eval("f\"{-1.5:06.2}\""), Ok(Value::from("-01.50")));
+    assert_eq!(eval("f\"{\"ab\":05}\""), Ok(Value::from("ab   ")));
+    assert_eq!(eval("f\"{1:0}\""), Ok(Value::from("1")));
+    assert_eq!(eval("f\"{\"abcdef\":.3}\""), Ok(Value::from("abc")));
+    assert_eq!(eval("f\"[{\"toolong\":3}]\""), Ok(Value::from("[toolong]")));
+    assert_eq!(eval("f\"{1:}\""), Ok(Value::from("1")));
+
+    // Braces, colons, quotes and escapes
+    assert_eq!(eval("f\"{{{1}}}\""), Ok(Value::from("{1}")));
+    assert_eq!(eval("f\"\\\"{1}\\\\\""), Ok(Value::from("\"1\\")));
+    assert_eq!(eval("f\"{math::abs(-2)}\""), Ok(Value::from("2")));
+    assert_eq!(eval("f\"{(true ? 1 : 2)}\""), Ok(Value::from("1")));
+    assert_eq!(eval("f\"{ {1; 2} }\""), Ok(Value::from("2")));
+    assert_eq!(eval("f\"{\"}:{\"}\""), Ok(Value::from("}:{")));
+    assert_eq!(eval("f\"{f\"{1}\" + \"2\"}\""), Ok(Value::from("12")));
+
+    // A space between `f` and the string keeps the string a function argument
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context
+        .set_function(
+            "f".into(),
+            Function::new(|argument| Ok(Value::Int(argument.as_string()?.len() as i64))),
+        )
+        .unwrap();
+    assert_eq!(eval_with_context("f \"{1}\"", &context), Ok(Value::Int(3)));
+
+    // Identifiers
+    let tree = build_operator_tree::<DefaultNumericTypes>("f\"{a}-{b(c):>3}\"").unwrap();
+    assert_eq!(
+        tree.iter_read_variable_identifiers().collect::<Vec<_>>(),
+        vec!["a", "c"]
+    );
+    assert_eq!(
+        tree.iter_function_identifiers().collect::<Vec<_>>(),
+        vec!["b"]
+    );
+
+    // Errors
+    assert_eq!(eval("f\"{1\""), Err(EvalexprError::UnmatchedDoubleQuote));
+    assert_eq!(eval("f\"{1"), Err(EvalexprError::UnmatchedLCurlyBrace));
+    assert_eq!(eval("f\"1}\""), Err(EvalexprError::UnmatchedRCurlyBrace));
+    assert_eq!(eval("f\"abc"), Err(EvalexprError::UnmatchedDoubleQuote));
+    assert_eq!(
+        eval("f\"{1:q}\""),
+        Err(EvalexprError::IllegalFormatSpecification("q".to_string()))
+    );
+    assert_eq!(
+        eval("f\"{1:.}\""),
+        Err(EvalexprError::IllegalFormatSpecification(".".to_string()))
+    );
+    assert_eq!(
+        eval("f\"{1:99999999999}\""),
+        Err(EvalexprError::IllegalFormatSpecification(
+            "99999999999".to_string()
+        ))
+    );
+    assert_eq!(
+        eval("f\"{1:.1025}\""),
+        Err(EvalexprError::IllegalFormatSpecification(
+            ".1025".to_string()
+        ))
+    );
+    assert_eq!(
+        eval("f\"{1 +}\""),
+        Err(EvalexprError::wrong_operator_argument_amount(1, 2))
+    );
+    assert_eq!(
+        eval("f\"{x}\""),
+        Err(EvalexprError::VariableIdentifierNotFound("x".to_string()))
+    );
+}
+
+#[test]
+fn test_int_overflow_policy() {
+    let max = i64::MAX.to_string();
+    let min = format!("(-{max} - 1)");
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    assert_eq!(
+        eval_with_context(&format!("{max} + 1"), &context),
+        Err(EvalexprError::AdditionError {
+            augend: Value::Int(i64::MAX),
+            addend: Value::Int(1),
+        })
+    );
+
+    context.set_int_overflow_policy(IntOverflowPolicy::Wrap);
+    assert_eq!(
+        eval_with_context(&format!("{max} + 1"), &context),
+        Ok(Value::Int(i64::MIN))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{max} * 3"), &context),
+        Ok(Value::Int(i64::MAX.wrapping_mul(3)))
+    );
+    assert_eq!(
+        eval_with_context_mut(&format!("x = {min}; x -= 1; x"), &mut context),
+        Ok(Value::Int(i64::MAX))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{min} / -1"), &context),
+        Ok(Value::Int(i64::MIN))
+    );
+    assert_eq!(
+        eval_with_context("1 / 0", &context),
+        Err(EvalexprError::DivisionError {
+            dividend: Value::Int(1),
+            divisor: Value::Int(0),
+        })
+    );
+
+    context.set_int_overflow_policy(IntOverflowPolicy::Saturate);
+    assert_eq!(
+        eval_with_context(&format!("{max} + 1"), &context),
+        Ok(Value::Int(i64::MAX))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{min} - 10"), &context),
+        Ok(Value::Int(i64::MIN))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{max} * -2"), &context),
+        Ok(Value::Int(i64::MIN))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{min} % -1"), &context),
+        Ok(Value::Int(0))
+    );
+
+    // The policy can be overridden for a single evaluation.
+    let promoting = IntOverflowPolicyContext::new(&context, IntOverflowPolicy::PromoteToFloat);
+    assert_eq!(
+        eval_with_context(&format!("{max} + 1"), &promoting),
+        Ok(Value::Float(i64::MAX as f64 + 1.0))
+    );
+    assert_eq!(
+        eval_with_context(&format!("{max} * 2 / 4"), &promoting),
+        Ok(Value::Float(i64::MAX as f64 / 2.0))
+    );
+    assert_eq!(eval_with_context("2 + 3", &promoting), Ok(Value::Int(5)));
+    assert_eq!(
+        eval_with_context(&format!("{max} + 1"), &context),
+        Ok(Value::Int(i64::MAX))
+    );
+}
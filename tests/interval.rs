@@ -0,0 +1,179 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "interval", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    eval_with_context, eval_with_context_mut, Context, EvalexprError, HashMapContext, Interval,
+    IntervalNumericTypes, Value,
+};
+
+fn eval(string: &str) -> Result<Value<IntervalNumericTypes>, EvalexprError<IntervalNumericTypes>> {
+    eval_with_context(string, &HashMapContext::<IntervalNumericTypes>::new())
+}
+
+fn interval(lo: f64, hi: f64) -> Value<IntervalNumericTypes> {
+    Value::Float(Interval::new(lo, hi))
+}
+
+fn eval_interval(string: &str) -> Interval {
+    let Ok(Value::Float(interval)) = eval(string) else {
+        panic!("{string} did not evaluate to an interval");
+    };
+    interval
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    assert_eq!(eval("interval(1, 2)"), Ok(interval(1.0, 2.0)));
+    assert_eq!(
+        eval("interval(1, 2) + interval(3, 4)"),
+        Ok(interval(4.0, 6.0))
+    );
+    assert_eq!(
+        eval("interval(1, 2) - interval(3, 4)"),
+        Ok(interval(-3.0, -1.0))
+    );
+    assert_eq!(
+        eval("interval(-1, 2) * interval(3, 4)"),
+        Ok(interval(-4.0, 8.0))
+    );
+    assert_eq!(
+        eval("interval(1, 2) / interval(4, 8)"),
+        Ok(interval(0.125, 0.5))
+    );
+    assert_eq!(eval("-interval(1, 2)"), Ok(interval(-2.0, -1.0)));
+    assert_eq!(eval("interval(-2, 1) ^ 2"), Ok(interval(0.0, 4.0)));
+    assert_eq!(eval("interval(-2, 1) ^ 3"), Ok(interval(-8.0, 1.0)));
+    assert_eq!(eval("math::sqrt(interval(4, 9))"), Ok(interval(2.0, 3.0)));
+    assert_eq!(eval("math::abs(interval(-3, 1))"), Ok(interval(0.0, 3.0)));
+    assert_eq!(eval("interval::around(10, 0.5)"), Ok(interval(9.5, 10.5)));
+    assert_eq!(eval("0.25 + 0.5"), Ok(interval(0.75, 0.75)));
+    assert_eq!(eval("7 / 2"), Ok(Value::Int(3)));
+
+    // A formula evaluated over intervals bounds all possible results.
+    let mut context = HashMapContext::<IntervalNumericTypes>::new();
+    eval_with_context_mut(
+        "a = interval(1.9, 2.1); b = interval(2.9, 3.1); c = interval::around(1, 0.01)",
+        &mut context,
+    )
+    .unwrap();
+    let Ok(Value::Float(result)) = eval_with_context("a * b - c", &context) else {
+        panic!("expected an interval");
+    };
+    assert!(result.lo() <= 1.9 * 2.9 - 1.01 && 2.1 * 3.1 - 0.99 <= result.hi());
+    assert!(result.width() < 1.22);
+    assert!(matches!(context.get_value("a"), Some(Value::Float(_))));
+}
+
+#[test]
+fn test_interval_outward_rounding() {
+    // 0.1 is not a float, so its literal is the smallest interval around it.
+    let tenth = eval_interval("0.1");
+    assert!(tenth.lo() < 0.1 && 0.1 < tenth.hi());
+    assert_eq!(tenth.hi().to_bits() - tenth.lo().to_bits(), 2);
+
+    let sum = eval_interval("0.1 + 0.2");
+    assert!(sum.contains(0.30000000000000004) && sum.contains(0.3));
+    let third = eval_interval("1 / 3.0");
+    assert!(third.lo() < third.hi() && third.contains(1.0 / 3.0));
+    assert!(eval_interval("(1 / 3.0) * 3").contains(1.0));
+
+    let sine = eval_interval("math::sin(interval(0, 3.2))");
+    assert_eq!(sine.hi(), 1.0);
+    assert!(sine.lo() < -0.05 && sine.lo() > -0.06);
+    assert_eq!(
+        eval("math::cos(interval(-10, 10))"),
+        Ok(interval(-1.0, 1.0))
+    );
+    assert_eq!(
+        eval("math::tan(interval(1, 2))"),
+        Ok(interval(f64::NEG_INFINITY, f64::INFINITY))
+    );
+    let exp = eval_interval("math::exp(interval(0, 1))");
+    assert!(exp.lo() <= 1.0 && std::f64::consts::E <= exp.hi());
+}
+
+#[test]
+fn test_interval_builtins_and_errors() {
+    assert_eq!(eval("interval::lo(interval(1, 2))"), Ok(interval(1.0, 1.0)));
+    assert_eq!(eval("interval::hi(interval(1, 2))"), Ok(interval(2.0, 2.0)));
+    assert_eq!(
+        eval("interval::width(interval(1, 2.5))"),
+        Ok(interval(1.5, 1.5))
+    );
+    assert_eq!(
+        eval("interval::midpoint(interval(1, 2))"),
+        Ok(interval(1.5, 1.5))
+    );
+    assert_eq!(eval("int(interval(1, 4))"), Ok(Value::Int(2)));
+    assert_eq!(
+        eval("interval(1, 2) < interval(3, 4)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(eval("interval(1, 2) > 0"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        eval("interval(1, 2) == interval(1, 2)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(eval("1.5 >= 1.5"), Ok(Value::Boolean(true)));
+
+    assert_eq!(eval("interval(1, 2.5)").unwrap().to_string(), "[1, 2.5]");
+    assert_eq!(
+        eval(r#"f"{interval(1, 2) / 3:.3}""#),
+        Ok(Value::from("[0.333, 0.667]"))
+    );
+    assert_eq!("[1, 2.5]".parse(), Ok(Interval::new(1.0, 2.5)));
+
+    // Overlapping intervals could be in either order.
+    assert_eq!(
+        eval("interval(1, 3) < interval(2, 4)"),
+        Err(EvalexprError::ComparisonError {
+            a: interval(1.0, 3.0),
+            b: interval(2.0, 4.0),
+        })
+    );
+    assert!(matches!(
+        eval("max(interval(1, 3), interval(2, 4)) > 2"),
+        Err(EvalexprError::ComparisonError { .. })
+    ));
+    assert_eq!(
+        eval("interval(2, 1)"),
+        Err(EvalexprError::InvertedInterval {
+            lo: Value::Int(2),
+            hi: Value::Int(1),
+        })
+    );
+    assert_eq!(
+        eval("interval(interval(1, 3), interval(0, 2))"),
+        Ok(interval(1.0, 2.0))
+    );
+    assert_eq!(
+        eval("interval(interval(1, 3), interval(0, 0.5))"),
+        Err(EvalexprError::InvertedInterval {
+            lo: interval(1.0, 3.0),
+            hi: interval(0.0, 0.5),
+        })
+    );
+    assert!(matches!(
+        eval("1.0 / interval(-1, 1)"),
+        Err(EvalexprError::DivisionError { .. })
+    ));
+    assert!(matches!(
+        eval("1.0 % interval(0, 1)"),
+        Err(EvalexprError::ModulationError { .. })
+    ));
+}
+
+#[test]
+fn test_interval_min_max_with_ints() {
+    // An int inside an interval cannot be ordered against it, so both are combined as intervals.
+    assert_eq!(eval("min(interval(1, 3), 2)"), Ok(interval(1.0, 2.0)));
+    assert_eq!(eval("max(interval(1, 3), 2)"), Ok(interval(2.0, 3.0)));
+    assert_eq!(eval("min(2, interval(1, 3), 5)"), Ok(interval(1.0, 2.0)));
+    // Ints outside of the interval are ordered as usual.
+    assert_eq!(eval("min(interval(4, 5), 2)"), Ok(Value::Int(2)));
+    assert_eq!(eval("max(interval(4, 5), 2)"), Ok(interval(4.0, 5.0)));
+    assert_eq!(
+        eval("max(interval(1, 3), interval(2, 4))"),
+        Ok(interval(2.0, 4.0))
+    );
+}
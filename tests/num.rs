@@ -1,11 +1,40 @@
 #![cfg(not(tarpaulin_include))]
 #![cfg(feature = "num")]
 
-use evalexpr::{ContextWithMutableVariables, ConvertibleContext, DefaultNumericTypes, HashMapContext, Value};
+use evalexpr::{
+    context_map, Context, ContextWithMutableVariables, ConvertibleContext, DefaultNumericTypes,
+    EvalexprError, HashMapContext, Value,
+};
 
 #[test]
 fn test_convert() {
     let mut m: HashMapContext = HashMapContext::default();
     m.set_value("a".to_string(), Value::Float(1.0)).unwrap();
     m.try_convert_to::<HashMapContext<DefaultNumericTypes>>().expect("The conversion failed!");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_context_map_cast_int() {
+    let context: HashMapContext = context_map! {
+        "a" => cast int 3u8,
+        "b" => cast int 2.9f32,
+    }
+    .unwrap();
+    assert_eq!(context.get_value("a"), Some(&Value::Int(3)));
+    assert_eq!(context.get_value("b"), Some(&Value::Int(2)));
+    assert_eq!(
+        context_map! { "a" => cast int u64::MAX }.map(|_: HashMapContext| ()),
+        Err(EvalexprError::IntCastError)
+    );
+}
+
+#[test]
+fn test_context_map_cast_float() {
+    let context: HashMapContext = context_map! {
+        "a" => cast float 3u8,
+        "b" => cast float 0.5f32,
+    }
+    .unwrap();
+    assert_eq!(context.get_value("a"), Some(&Value::Float(3.0)));
+    assert_eq!(context.get_value("b"), Some(&Value::Float(0.5)));
+}
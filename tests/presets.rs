@@ -0,0 +1,130 @@
+#![cfg(not(tarpaulin_include))]
+
+use evalexpr::{error::*, *};
+
+#[test]
+fn test_i32_f32_preset() {
+    let context: HashMapContext<I32F32NumericTypes> = context_map! {
+        "x" => int 7,
+        "y" => float 0.5,
+        "f" => Function::new(|argument| Ok(Value::Int(argument.as_int()? * 2)))
+    }
+    .unwrap();
+
+    assert_eq!(eval_with_context("f(x) + 1", &context), Ok(Value::Int(15)));
+    assert_eq!(eval_with_context("x * y", &context), Ok(Value::Float(3.5)));
+    assert_eq!(
+        eval_with_context("math::sqrt(y * 8)", &context),
+        Ok(Value::Float(2.0))
+    );
+    assert_eq!(
+        eval_with_context("2147483647 + x", &context),
+        Err(EvalexprError::AdditionError {
+            augend: Value::Int(i32::MAX),
+            addend: Value::Int(7)
+        })
+    );
+    assert_eq!(
+        eval_with_context("bitand(x, 1) << 31", &context),
+        Ok(Value::Int(i32::MIN))
+    );
+    assert_eq!(
+        eval_with_context("math::abs(-2147483647 - 1)", &context),
+        Err(EvalexprError::NegationError {
+            argument: Value::Int(i32::MIN)
+        })
+    );
+}
+
+#[test]
+fn test_u64_f64_preset() {
+    let context: HashMapContext<U64F64NumericTypes> = context_map! {
+        "x" => int 5u64,
+        "big" => int u64::MAX
+    }
+    .unwrap();
+
+    assert_eq!(eval_with_context("x * 3 % 4", &context), Ok(Value::Int(3)));
+    assert_eq!(
+        eval_with_context("big / x", &context),
+        Ok(Value::Int(u64::MAX / 5))
+    );
+    assert_eq!(
+        eval_with_context("math::abs(x)", &context),
+        Ok(Value::Int(5))
+    );
+    assert_eq!(eval_with_context("-0", &context), Ok(Value::Int(0)));
+    assert_eq!(
+        eval_with_context("-x", &context),
+        Err(EvalexprError::NegationError {
+            argument: Value::Int(5)
+        })
+    );
+    assert_eq!(
+        eval_with_context("x - 6", &context),
+        Err(EvalexprError::SubtractionError {
+            minuend: Value::Int(5),
+            subtrahend: Value::Int(6)
+        })
+    );
+    assert_eq!(eval_with_context("-1.5", &context), Ok(Value::Float(-1.5)));
+    assert!(matches!(
+        eval_with_context("int(-1.5)", &context),
+        Err(EvalexprError::FloatToNum { .. })
+    ));
+
+    let mut wrapping = context.clone();
+    wrapping.set_int_overflow_policy(IntOverflowPolicy::Wrap);
+    assert_eq!(
+        eval_with_context("-x", &wrapping),
+        Ok(Value::Int(u64::MAX - 4))
+    );
+    let mut saturating = context;
+    saturating.set_int_overflow_policy(IntOverflowPolicy::Saturate);
+    assert_eq!(eval_with_context("-x", &saturating), Ok(Value::Int(0)));
+    assert_eq!(
+        eval_with_context("math::is_prime(18446744073709551557)", &saturating),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(eval_with_context("x - 6", &saturating), Ok(Value::Int(0)));
+}
+
+#[test]
+fn test_i128_f64_preset() {
+    let context: HashMapContext<I128F64NumericTypes> = context_map! {
+        "x" => int i64::MAX,
+        "y" => float 2.5
+    }
+    .unwrap();
+
+    assert_eq!(
+        eval_with_context("x * x", &context),
+        Ok(Value::Int(i64::MAX as i128 * i64::MAX as i128))
+    );
+    assert_eq!(
+        eval_with_context("170141183460469231731687303715884105727", &context),
+        Ok(Value::Int(i128::MAX))
+    );
+    assert_eq!(
+        eval_with_context("-x - 1 < 0", &context),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(eval_with_context("y * 2", &context), Ok(Value::Float(5.0)));
+    assert_eq!(eval_with_context("0x7f >> 3", &context), Ok(Value::Int(15)));
+    assert_eq!(
+        eval_with_context("math::is_prime(18446744073709551629)", &context),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        eval_with_context("math::is_prime(147573952589676412927)", &context),
+        Ok(Value::Boolean(false))
+    );
+    // The products of the Miller-Rabin test overflow for the largest prime `i128::MAX`.
+    assert_eq!(
+        eval_with_context(
+            "math::is_prime(170141183460469231731687303715884105727)",
+            &context
+        ),
+        Ok(Value::Boolean(true))
+    );
+}
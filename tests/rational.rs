@@ -0,0 +1,138 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(all(feature = "rational", not(feature = "num_primitive")))]
+
+use evalexpr::{
+    eval_with_context, eval_with_context_mut, Context, EvalexprError, HashMapContext, Rational,
+    RationalNumericTypes, Value,
+};
+
+fn eval(string: &str) -> Result<Value<RationalNumericTypes>, EvalexprError<RationalNumericTypes>> {
+    eval_with_context(string, &HashMapContext::<RationalNumericTypes>::new())
+}
+
+fn fraction(numer: i64, denom: i64) -> Value<RationalNumericTypes> {
+    Value::Float(Rational::new(numer, denom))
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    assert_eq!(eval("1/3 + 1/6"), Ok(fraction(1, 2)));
+    assert_eq!(eval("1/3 + 1/6 == 1/2"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("6 / 3"), Ok(Value::Int(2)));
+    assert_eq!(eval("-7 / 2"), Ok(fraction(-7, 2)));
+    assert_eq!(eval("1/3 * 3"), Ok(fraction(1, 1)));
+    assert_eq!(eval("0.1 + 0.2 == 0.3"), Ok(Value::Boolean(true)));
+    assert_eq!(eval("0.25"), Ok(fraction(1, 4)));
+    assert_eq!(eval("1.5e-3"), Ok(fraction(3, 2000)));
+    assert_eq!(eval("7 % 2"), Ok(Value::Int(1)));
+    assert_eq!(eval("7.5 % 2"), Ok(fraction(3, 2)));
+    assert_eq!(eval("-(1/3)"), Ok(fraction(-1, 3)));
+    assert_eq!(eval("(2/3) ^ 3"), Ok(fraction(8, 27)));
+    assert_eq!(eval("2 ^ -2"), Ok(fraction(1, 4)));
+    assert_eq!(eval("math::sqrt(9/4)"), Ok(fraction(3, 2)));
+    assert_eq!(eval("floor(-7/2)"), Ok(fraction(-4, 1)));
+    assert_eq!(eval("round(5/2)"), Ok(fraction(3, 1)));
+    assert_eq!(eval("int(7/2)"), Ok(Value::Int(3)));
+    assert_eq!(eval("math::abs(-1/3)"), Ok(fraction(1, 3)));
+    assert_eq!(eval("max(1/3, 1/4)"), Ok(fraction(1, 3)));
+
+    let mut context = HashMapContext::<RationalNumericTypes>::new();
+    eval_with_context_mut("a = 1.0; a /= 3; a += 1/6", &mut context).unwrap();
+    assert_eq!(context.get_value("a"), Some(&fraction(1, 2)));
+}
+
+#[test]
+fn test_rational_display() {
+    assert_eq!(eval("1/3 + 1/6").unwrap().to_string(), "1/2");
+    assert_eq!(eval("-2/3").unwrap().to_string(), "-2/3");
+    assert_eq!(eval("str::from(4/2.0)"), Ok(Value::from("2")));
+    assert_eq!(eval(r#"f"{2/3:.4}""#), Ok(Value::from("0.6667")));
+    assert_eq!(eval(r#"f"{-1/8:.2}""#), Ok(Value::from("-0.12")));
+    assert_eq!(eval(r#"f"{19/2:.0}""#), Ok(Value::from("10")));
+    assert_eq!(eval(r#"f"{0.999:.2}""#), Ok(Value::from("1.00")));
+    assert_eq!(eval(r#"f"{1/4:>8.3}""#), Ok(Value::from("   0.250")));
+    assert_eq!("5/10".parse(), Ok(Rational::new(1, 2)));
+}
+
+#[test]
+fn test_rational_approximations_and_errors() {
+    // Transcendental functions fall back to an approximation.
+    let Ok(Value::Float(ln)) = eval("math::ln(2)") else {
+        panic!("expected a float");
+    };
+    assert!((ln.numer() as f64 / ln.denom() as f64 - 2f64.ln()).abs() < 1e-12);
+    assert_eq!(
+        eval("math::sqrt(-1.0)"),
+        Err(EvalexprError::MathFunctionError {
+            function: "math::sqrt".to_string(),
+            argument: fraction(-1, 1),
+        })
+    );
+
+    assert_eq!(
+        eval("1 / 0"),
+        Err(EvalexprError::DivisionError {
+            dividend: Value::Int(1),
+            divisor: Value::Int(0),
+        })
+    );
+    assert!(matches!(
+        eval("1.5 / 0"),
+        Err(EvalexprError::DivisionError { .. })
+    ));
+    assert!(matches!(
+        eval("1.5 % 0"),
+        Err(EvalexprError::ModulationError { .. })
+    ));
+    assert!(matches!(
+        eval("1/9223372036854775807 + 1/9223372036854775806"),
+        Err(EvalexprError::AdditionError { .. })
+    ));
+    assert!(matches!(
+        eval("1/3 * 9223372036854775807 * 9223372036854775807"),
+        Err(EvalexprError::MultiplicationError { .. })
+    ));
+}
+
+#[test]
+fn test_rational_approximations_without_result() {
+    for (expression, function, argument) in [
+        ("math::ln(-1.0)", "math::ln", fraction(-1, 1)),
+        ("math::ln(0)", "math::ln", fraction(0, 1)),
+        ("math::exp(100)", "math::exp", fraction(100, 1)),
+        ("math::exp2(70)", "math::exp2", fraction(70, 1)),
+        ("math::atanh(1)", "math::atanh", fraction(1, 1)),
+        ("math::asin(3/2)", "math::asin", fraction(3, 2)),
+    ] {
+        assert_eq!(
+            eval(expression),
+            Err(EvalexprError::MathFunctionError {
+                function: function.to_string(),
+                argument,
+            }),
+            "{expression}"
+        );
+    }
+    assert_eq!(
+        eval("(-8) ^ (1/3)"),
+        Err(EvalexprError::MathFunctionError {
+            function: "math::pow".to_string(),
+            argument: Value::Tuple(vec![fraction(-8, 1), fraction(1, 3)]),
+        })
+    );
+    assert_eq!(
+        eval("0 ^ -1"),
+        Err(EvalexprError::MathFunctionError {
+            function: "math::pow".to_string(),
+            argument: Value::Tuple(vec![fraction(0, 1), fraction(-1, 1)]),
+        })
+    );
+    // Results too small to be represented become zero.
+    assert_eq!(eval("math::exp(-100)"), Ok(fraction(0, 1)));
+}
+
+#[test]
+fn test_rational_exact_hypot() {
+    assert_eq!(eval("math::hypot(3/5, 4/5)"), Ok(fraction(1, 1)));
+    assert_eq!(eval("math::hypot(-3, 4)"), Ok(fraction(5, 1)));
+}
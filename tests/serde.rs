@@ -1,7 +1,7 @@
 #![cfg(not(tarpaulin_include))]
 #![cfg(feature = "serde")]
 
-use evalexpr::{build_operator_tree, Node};
+use evalexpr::{build_operator_tree, EvalexprError, Node};
 
 #[test]
 fn test_serde() {
@@ -23,13 +23,18 @@ fn test_serde_errors() {
             position: ron::de::Position { col: 1, line: 1 }
         })
     );
+    // A lone `&` is a bitwise and without operands, which only fails when evaluated.
     assert_eq!(
-        ron::de::from_str::<Node>("\"&\""),
+        ron::de::from_str::<Node>("\"&\"").unwrap().eval(),
+        Err(EvalexprError::WrongOperatorArgumentAmount {
+            expected: 2,
+            actual: 0
+        })
+    );
+    assert_eq!(
+        ron::de::from_str::<Node>("\")\""),
         Err(ron::de::SpannedError {
-            code: ron::Error::Message(
-                "Found a partial token '&' that should be followed by another partial token."
-                    .to_owned()
-            ),
+            code: ron::Error::Message("Found an unmatched closing parenthesis ')'.".to_owned()),
             position: ron::de::Position { line: 1, col: 4 }
         })
     );
@@ -0,0 +1,154 @@
+#![cfg(not(tarpaulin_include))]
+#![cfg(feature = "units")]
+
+use evalexpr::{error::*, *};
+
+fn unit(string: &str) -> Unit {
+    string.parse().unwrap()
+}
+
+#[test]
+fn test_quantity_literals() {
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    eval_with_context_mut("m = 2; tolerances = (1, 5)", &mut context).unwrap();
+    let eval = |string| eval_with_context(string, &context).map(|value| value.to_string());
+
+    assert_eq!(eval("5 m"), Ok("5 m".into()));
+    assert_eq!(eval("5[km/h]"), Ok("5 km/h".into()));
+    assert_eq!(eval("2.5 [kg*m/s^2]"), Ok("2.5 kg*m/s^2".into()));
+    assert_eq!(eval("1e-3 min"), Ok("0.001 min".into()));
+    assert_eq!(eval("-3 Ω"), Ok("-3 Ω".into()));
+    assert_eq!(eval("str::from(9.81[m/s^2])"), Ok("\"9.81 m/s^2\"".into()));
+    assert_eq!(eval("typeof(5 m)"), Ok("\"quantity\"".into()));
+    assert_eq!(eval("unit(5[1/s])"), Ok("\"1/s\"".into()));
+    assert_eq!(eval("magnitude(5[N*m])"), Ok("5".into()));
+    assert_eq!(eval("f\"{5 m:.2}\""), Ok("\"5.00 m\"".into()));
+
+    // A power directly after a unit symbol belongs to the unit.
+    assert_eq!(eval("4 m^2"), Ok("4 m^2".into()));
+    assert_eq!(eval("4 m^2 == 4[m^2]"), Ok("true".into()));
+    assert_eq!(eval("2 s^-1"), Ok("2 1/s".into()));
+    assert_eq!(eval("(4 m)^2"), Ok("16 m^2".into()));
+
+    // Identifiers that are not preceded by a number are still variables, and `in` stays a keyword.
+    assert_eq!(eval("m * 3"), Ok("6".into()));
+    assert_eq!(eval("5 in tolerances"), Ok("true".into()));
+
+    assert_eq!(
+        eval("5[furlong]"),
+        Err(EvalexprError::UnknownUnit("furlong".into()))
+    );
+    assert_eq!(eval("4 m^x"), Err(EvalexprError::UnknownUnit("m^x".into())));
+    assert_eq!(
+        eval("4 m^2.5"),
+        Err(EvalexprError::UnknownUnit("m^2.5".into()))
+    );
+    assert_eq!(
+        eval("5[m/s"),
+        Err(EvalexprError::UnknownUnit("[m/s".into()))
+    );
+    assert!(matches!(
+        eval("[m]"),
+        Err(EvalexprError::UnmatchedPartialToken { .. })
+    ));
+    assert!(matches!(
+        eval("magnitude(5)"),
+        Err(EvalexprError::TypeError { .. })
+    ));
+}
+
+#[test]
+fn test_quantity_arithmetic() {
+    let eval = |string| eval(string).map(|value| value.to_string());
+
+    assert_eq!(eval("100 km / 2 h"), Ok("50 km/h".into()));
+    assert_eq!(eval("1 km + 500 m"), Ok("1.5 km".into()));
+    assert_eq!(eval("2 h - 30 min"), Ok("1.5 h".into()));
+    assert_eq!(eval("3 * 4 s"), Ok("12 s".into()));
+    assert_eq!(eval("-(2 N) * 3 m"), Ok("-6 N*m".into()));
+    assert_eq!(eval("(3 m)^2"), Ok("9 m^2".into()));
+    assert_eq!(eval("(2 s)^-1"), Ok("0.5 1/s".into()));
+    assert_eq!(eval("10 m / 4 m"), Ok("2.5".into()));
+    assert_eq!(eval("1 km / 1 m"), Ok("1000".into()));
+    assert_eq!(eval("1 km == 1000 m"), Ok("true".into()));
+    assert_eq!(eval("1 mi > 1.5 km"), Ok("true".into()));
+    assert_eq!(eval("5 m == 5"), Ok("false".into()));
+
+    assert_eq!(
+        eval("5 m + 3 s"),
+        Err(EvalexprError::IncompatibleUnits {
+            a: unit("m"),
+            b: unit("s")
+        })
+    );
+    assert_eq!(
+        eval("5 m - 1"),
+        Err(EvalexprError::IncompatibleUnits {
+            a: unit("m"),
+            b: Unit::dimensionless()
+        })
+    );
+    assert_eq!(eval("1 kg < 1 lb"), Ok("false".into()));
+    assert!(matches!(
+        eval("1 h < 1 m"),
+        Err(EvalexprError::IncompatibleUnits { .. })
+    ));
+    assert!(matches!(
+        eval("(2 m)^0.5"),
+        Err(EvalexprError::ExpectedInt { .. })
+    ));
+    assert!(matches!(
+        eval("\"a\" + 1 m"),
+        Err(EvalexprError::TypeError { .. })
+    ));
+}
+
+#[test]
+fn test_convert() {
+    let mut context = HashMapContext::<DefaultNumericTypes>::new();
+    context
+        .set_value("distance".into(), Quantity::new(26.2, unit("mi")).into())
+        .unwrap();
+    context
+        .set_value("time".into(), eval("3.5 h").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        eval_with_context("convert(18[km/h], \"m/s\")", &context),
+        Ok(Value::from(Quantity::new(5.0, unit("m/s"))))
+    );
+    assert_eq!(
+        eval_with_context("str::from(convert(2 mi, \"km\"))", &context),
+        Ok(Value::from("3.218688 km"))
+    );
+    assert_eq!(
+        eval_with_context("convert(1[m/km], \"1\")", &context),
+        Ok(Value::from_float(0.001))
+    );
+    assert_eq!(
+        eval_with_context(
+            "round(magnitude(convert(distance / time, \"km/h\")))",
+            &context
+        ),
+        Ok(Value::from_float(12.0))
+    );
+
+    assert_eq!(
+        eval_with_context("convert(distance, \"s\")", &context),
+        Err(EvalexprError::IncompatibleUnits {
+            a: unit("mi"),
+            b: unit("s")
+        })
+    );
+    assert_eq!(
+        eval_with_context("convert(5, \"m\")", &context),
+        Err(EvalexprError::IncompatibleUnits {
+            a: Unit::dimensionless(),
+            b: unit("m")
+        })
+    );
+    assert_eq!(
+        eval_with_context("convert(time, \"fortnight\")", &context),
+        Err(EvalexprError::UnknownUnit("fortnight".into()))
+    );
+}